@@ -0,0 +1,303 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fs;
+use std::hint::black_box;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use inkwell::context::Context;
+use inkwell::execution_engine::ExecutionEngine;
+use inkwell::OptimizationLevel;
+use inkwell::targets::{InitializationConfig, Target};
+use serde::{Deserialize, Serialize};
+use compiler::ast::Node;
+use compiler::codegen::CodeGenerator;
+use compiler::codegen::target::host_triple;
+use compiler::datatype::DataType;
+use compiler::mir::{lower, MirModule};
+use compiler::optimizer::{optimize, OptimizationOptions};
+use crate::build::lint_registry;
+use crate::project::config_file::Config;
+use crate::project::loader::{collect_source_files, load, load_with_library};
+use crate::test::qualified_name;
+use crate::{BenchArgs, MessageFormat};
+
+const BASELINE_PATH: &str = "build/bench-baseline.json";
+const BASELINE_VERSION: u32 = 1;
+const MINIMUM_SAMPLE_DURATION: Duration = Duration::from_millis(1);
+const MAXIMUM_BATCH_SIZE: u64 = 1 << 24;
+
+struct Benchmark {
+    name: String,
+    function_name: String,
+    returns_value: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+struct Measurement {
+    mean_ns: f64,
+    median_ns: f64,
+    stddev_ns: f64,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Baseline {
+    version: u32,
+    benchmarks: BTreeMap<String, Measurement>,
+}
+
+pub fn bench(bench_args: BenchArgs) -> Result<(), Vec<Box<dyn Error>>> {
+    let BenchArgs { filter, warmup, iterations, save_baseline, baseline, threshold } = bench_args;
+
+    let config = Config::load(Path::new("axiom.toml")).map_err(|error| vec![error])?;
+    let lint_registry = lint_registry(&config.lint_levels().map_err(|error| vec![error])?, false);
+
+    let library = collect_source_files(Path::new("src")).map_err(|error| vec![error])?;
+    let bench_files = collect_source_files(Path::new("benches")).map_err(|error| vec![error])?;
+
+    let mut errors = vec![];
+    let mut programs = vec![];
+
+    let library_files = load(&library, &lint_registry, MessageFormat::Human, &[])?;
+    let mut library_benchmarks = vec![];
+    for (source_file, loaded_file) in library.iter().zip(&library_files) {
+        match collect_benchmarks(&source_file.path, &loaded_file.ast) {
+            Ok(benchmarks) => library_benchmarks.extend(benchmarks),
+            Err(file_errors) => errors.extend(file_errors),
+        }
+    }
+    programs.push((library_files, library_benchmarks));
+
+    for bench_file in &bench_files {
+        let program = load_with_library(&library, bench_file, &lint_registry, MessageFormat::Human)
+            .and_then(|loaded_files| Ok((collect_benchmarks(&bench_file.path, &loaded_files[library.len()].ast)?, loaded_files)));
+
+        match program {
+            Ok((benchmarks, loaded_files)) => programs.push((loaded_files, benchmarks)),
+            Err(file_errors) => errors.extend(file_errors),
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    let previous_baseline = match baseline {
+        true => Some(load_baseline().map_err(|error| vec![error])?),
+        false => None,
+    };
+
+    Target::initialize_native(&InitializationConfig::default()).map_err(|error| vec![Box::from(error)])?;
+
+    let mut measurements = BTreeMap::new();
+    let mut regressions = vec![];
+
+    for (mut loaded_files, benchmarks) in programs {
+        let benchmarks: Vec<Benchmark> = benchmarks.into_iter()
+            .filter(|benchmark| filter.as_ref().is_none_or(|filter| benchmark.name.contains(filter.as_str())))
+            .collect();
+
+        if benchmarks.is_empty() {
+            continue;
+        }
+
+        let runtime_checks = config.profile.release.runtime_checks;
+
+        let optimization_options = OptimizationOptions { runtime_checks, ..OptimizationOptions::default() };
+        let mir_modules: Vec<MirModule> = loaded_files.iter_mut()
+            .map(|loaded_file| {
+                optimize(&mut loaded_file.ast, &optimization_options);
+                lower(&loaded_file.ast, &loaded_file.name, &loaded_file.source, runtime_checks)
+            })
+            .collect();
+
+        let context = Context::create();
+        let mut codegen = CodeGenerator::new(&context);
+        codegen.add_build_in_functions(&host_triple());
+        codegen.build_all(&mir_modules);
+
+        let target_machine = codegen.create_target_machine(&host_triple(), OptimizationLevel::Aggressive).map_err(|error| vec![error])?;
+        codegen.run_optimization_passes(&target_machine, 3).map_err(|error| vec![error])?;
+
+        let execution_engine = codegen.module.create_jit_execution_engine(OptimizationLevel::Aggressive).map_err(|error| vec![Box::from(error.to_string())])?;
+
+        for benchmark in benchmarks {
+            let measurement = measure(&execution_engine, &benchmark, warmup, iterations).map_err(|error| vec![error])?;
+
+            print!("bench {} ... mean {}, median {}, stddev {}", benchmark.name, format_duration(measurement.mean_ns), format_duration(measurement.median_ns), format_duration(measurement.stddev_ns));
+
+            if let Some(previous) = previous_baseline.as_ref().and_then(|previous_baseline| previous_baseline.benchmarks.get(&benchmark.name)) {
+                if previous.median_ns > 0.0 {
+                    let change = (measurement.median_ns - previous.median_ns) / previous.median_ns * 100.0;
+
+                    let verdict = if change > threshold {
+                        regressions.push(benchmark.name.clone());
+                        "regressed"
+                    } else if change < -threshold {
+                        "improved"
+                    } else {
+                        "no change"
+                    };
+
+                    print!(" ({:+.2}% against the baseline, {})", change, verdict);
+                } else {
+                    print!(" (not compared, the baseline median is 0 ns)");
+                }
+            }
+
+            println!();
+
+            measurements.insert(benchmark.name, measurement);
+        }
+    }
+
+    if measurements.is_empty() {
+        println!("no benchmarks found");
+    }
+
+    if save_baseline {
+        write_baseline(measurements).map_err(|error| vec![error])?;
+        println!("baseline saved to {}", BASELINE_PATH);
+    }
+
+    if !regressions.is_empty() {
+        return Err(vec![Box::from(format!("{} {} regressed by more than {}%: {}", regressions.len(), if regressions.len() == 1 { "benchmark" } else { "benchmarks" }, threshold, regressions.join(", ")))]);
+    }
+
+    Ok(())
+}
+
+/// Benchmarks are called through `fn() -> i32` or `fn()`, so any other return type is rejected.
+fn collect_benchmarks(source_path: &Path, ast: &Node) -> Result<Vec<Benchmark>, Vec<Box<dyn Error>>> {
+    let file_node = match ast {
+        Node::File(file_node) => file_node,
+        _ => return Ok(vec![]),
+    };
+
+    let mut benchmarks = vec![];
+    let mut errors: Vec<Box<dyn Error>> = vec![];
+
+    for function in file_node.functions.iter().filter(|function| function.identifier_node.identifier_token.name.starts_with("bench_") && function.parameters.is_empty()) {
+        let function_name = function.identifier_node.identifier_token.name.clone();
+        let name = qualified_name(source_path, &function_name);
+
+        let returns_value = match &function.data_type {
+            DataType::Function(_, return_type) if **return_type == DataType::I32 => true,
+            DataType::Function(_, return_type) if **return_type == DataType::None => false,
+            DataType::Function(_, return_type) => {
+                errors.push(format!("Benchmark '{}' must return i32 or nothing, found {}", name, return_type).into());
+                continue;
+            }
+            _ => continue,
+        };
+
+        benchmarks.push(Benchmark {
+            name,
+            function_name,
+            returns_value,
+        });
+    }
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    Ok(benchmarks)
+}
+
+fn measure(execution_engine: &ExecutionEngine, benchmark: &Benchmark, warmup: u64, iterations: u64) -> Result<Measurement, Box<dyn Error>> {
+    let run_batch: Box<dyn Fn(u64) -> Duration> = if benchmark.returns_value {
+        let function = unsafe { execution_engine.get_function::<unsafe extern "C" fn() -> i32>(&benchmark.function_name) }?;
+
+        Box::from(move |batch_size: u64| {
+            let start = Instant::now();
+            for _ in 0..batch_size {
+                black_box(unsafe { function.call() });
+            }
+            start.elapsed()
+        })
+    } else {
+        let function = unsafe { execution_engine.get_function::<unsafe extern "C" fn()>(&benchmark.function_name) }?;
+
+        Box::from(move |batch_size: u64| {
+            let start = Instant::now();
+            for _ in 0..batch_size {
+                unsafe { function.call() };
+            }
+            start.elapsed()
+        })
+    };
+
+    let mut batch_size = 1;
+    while batch_size < MAXIMUM_BATCH_SIZE && run_batch(batch_size) < MINIMUM_SAMPLE_DURATION {
+        batch_size *= 2;
+    }
+
+    for _ in 0..warmup {
+        run_batch(batch_size);
+    }
+
+    let mut samples: Vec<f64> = (0..iterations.max(1)).map(|_| run_batch(batch_size).as_nanos() as f64 / batch_size as f64).collect();
+    samples.sort_by(|left, right| left.total_cmp(right));
+
+    let mean = samples.iter().sum::<f64>() / samples.len() as f64;
+    let median = match samples.len() % 2 {
+        0 => (samples[samples.len() / 2 - 1] + samples[samples.len() / 2]) / 2.0,
+        _ => samples[samples.len() / 2],
+    };
+    let variance = match samples.len() {
+        1 => 0.0,
+        count => samples.iter().map(|sample| (sample - mean).powi(2)).sum::<f64>() / (count - 1) as f64,
+    };
+
+    Ok(Measurement {
+        mean_ns: mean,
+        median_ns: median,
+        stddev_ns: variance.sqrt(),
+    })
+}
+
+fn format_duration(nanoseconds: f64) -> String {
+    if nanoseconds >= 1_000_000_000.0 {
+        format!("{:.3} s", nanoseconds / 1_000_000_000.0)
+    } else if nanoseconds >= 1_000_000.0 {
+        format!("{:.3} ms", nanoseconds / 1_000_000.0)
+    } else if nanoseconds >= 1_000.0 {
+        format!("{:.3} µs", nanoseconds / 1_000.0)
+    } else {
+        format!("{:.3} ns", nanoseconds)
+    }
+}
+
+fn load_baseline() -> Result<Baseline, Box<dyn Error>> {
+    let content = fs::read_to_string(BASELINE_PATH).map_err(|error| format!("Unable to read the baseline '{}': {}", BASELINE_PATH, error))?;
+    let baseline: Baseline = serde_json::from_str(&content).map_err(|error| format!("Invalid baseline '{}': {}", BASELINE_PATH, error))?;
+
+    if baseline.version != BASELINE_VERSION {
+        return Err(format!("Unsupported baseline version {} in '{}'", baseline.version, BASELINE_PATH).into());
+    }
+
+    Ok(baseline)
+}
+
+fn write_baseline(measurements: BTreeMap<String, Measurement>) -> Result<(), Box<dyn Error>> {
+    let baseline_path = PathBuf::from(BASELINE_PATH);
+
+    let mut benchmarks = match baseline_path.exists() {
+        true => load_baseline()?.benchmarks,
+        false => BTreeMap::new(),
+    };
+    benchmarks.extend(measurements);
+
+    if let Some(directory) = baseline_path.parent() {
+        fs::create_dir_all(directory)?;
+    }
+
+    let baseline = Baseline {
+        version: BASELINE_VERSION,
+        benchmarks,
+    };
+
+    fs::write(&baseline_path, serde_json::to_string_pretty(&baseline)?)?;
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,234 @@
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use inkwell::context::Context;
+use compiler::ast::Node;
+use compiler::c_codegen::CCodeGenerator;
+use compiler::codegen::CodeGenerator;
+use compiler::codegen::target::{host_triple, is_host_triple, is_wasi_triple, optimization_level};
+use compiler::lint::{Lint, LintLevel, LintRegistry};
+use compiler::mir::{lower, MirModule};
+use compiler::optimizer::{optimize, OptimizationOptions};
+use crate::project::loader::{load, LoadedFile, Project, SourceFile};
+use crate::{Backend, BuildArgs, Emit, OptimizationPass, ProjectType};
+
+struct BuildPlan {
+    source_files: Vec<SourceFile>,
+    output_path: PathBuf,
+    target: String,
+    link: bool,
+    runtime_checks: bool,
+    lint_levels: Vec<(Lint, LintLevel)>,
+}
+
+impl BuildPlan {
+    fn from_file(path: PathBuf, target: Option<String>, output: Option<PathBuf>, release: bool) -> BuildPlan {
+        let output_path = output.unwrap_or_else(|| PathBuf::from(path.file_stem().unwrap_or_default()));
+
+        BuildPlan {
+            source_files: vec![SourceFile::new(&path)],
+            output_path,
+            target: target.unwrap_or_else(host_triple),
+            link: true,
+            runtime_checks: !release,
+            lint_levels: vec![],
+        }
+    }
+
+    fn from_project(target: Option<String>, output: Option<PathBuf>, release: bool) -> Result<BuildPlan, Box<dyn Error>> {
+        let project = Project::find()?;
+        project.entry_point()?;
+
+        let config = &project.config;
+        let profile = if release { &config.profile.release } else { &config.profile.debug };
+
+        let output_path = match output {
+            Some(output) => output,
+            None => {
+                let build_directory = project.directory.join("build");
+                fs::create_dir_all(&build_directory)?;
+
+                build_directory.join(&config.package.name)
+            }
+        };
+
+        Ok(BuildPlan {
+            source_files: project.sources()?,
+            output_path,
+            target: target.unwrap_or(config.build.target.clone()),
+            link: matches!(config.package.r#type, ProjectType::Bin),
+            runtime_checks: profile.runtime_checks,
+            lint_levels: config.lint_levels()?,
+        })
+    }
+}
+
+pub fn build(build_args: BuildArgs) -> Result<(), Vec<Box<dyn Error>>> {
+    let BuildArgs { path, target, output, emit, backend, optimization_level: level, disabled_passes, release, deny_warnings, message_format } = build_args;
+
+    let build_plan = match path {
+        Some(path) => BuildPlan::from_file(path, target, output, release),
+        None => BuildPlan::from_project(target, output, release).map_err(|error| vec![error])?,
+    };
+
+    let is_wasi = is_wasi_triple(&build_plan.target);
+
+    if matches!(emit, Some(Emit::Wat)) && (!is_wasi || matches!(backend, Backend::C)) {
+        return Err(vec![format!("Emitting '.wat' is only supported for WASI targets with the LLVM backend, but target is '{}'", build_plan.target).into()]);
+    }
+
+    let lint_registry = lint_registry(&build_plan.lint_levels, deny_warnings);
+
+    let mut files = load(&build_plan.source_files, &lint_registry, message_format, &[])?;
+
+    let optimization_options = optimization_options(&disabled_passes, build_plan.runtime_checks);
+    for file in &mut files {
+        optimize(&mut file.ast, &optimization_options);
+    }
+
+    if matches!(emit, Some(Emit::Mir)) {
+        let mir = lower_files(&build_plan, &files).iter().map(|mir_module| mir_module.to_string()).collect::<Vec<_>>().join("\n");
+
+        return fs::write(build_plan.output_path.with_extension("mir"), mir).map_err(|error| vec![Box::from(error)]);
+    }
+
+    if matches!(backend, Backend::C) {
+        return build_c(&build_plan, &files, emit).map_err(|error| vec![error]);
+    }
+
+    let mir_modules = lower_files(&build_plan, &files);
+
+    let context = Context::create();
+    let mut codegen = CodeGenerator::new(&context);
+    codegen.add_build_in_functions(&build_plan.target);
+    codegen.build_all(&mir_modules);
+
+    let link = build_plan.link && !matches!(emit, Some(Emit::Obj) | Some(Emit::LlvmIr));
+
+    if link && is_wasi {
+        codegen.add_wasi_start().map_err(|error| vec![error])?;
+    }
+
+    if link && !is_wasi {
+        codegen.add_native_start().map_err(|error| vec![error])?;
+    }
+
+    let target_machine = codegen.create_target_machine(&build_plan.target, optimization_level(level)).map_err(|error| vec![error])?;
+    codegen.run_optimization_passes(&target_machine, level).map_err(|error| vec![error])?;
+
+    if matches!(emit, Some(Emit::LlvmIr)) {
+        return codegen.write_llvm_ir_file(&build_plan.output_path.with_extension("ll")).map_err(|error| vec![error]);
+    }
+
+    let object_path = build_plan.output_path.with_extension("o");
+    codegen.write_object_file(&target_machine, &object_path).map_err(|error| vec![error])?;
+
+    if !link {
+        return Ok(());
+    }
+
+    if is_wasi {
+        let wasm_path = build_plan.output_path.with_extension("wasm");
+        link_wasm(&object_path, &wasm_path).map_err(|error| vec![error])?;
+
+        if matches!(emit, Some(Emit::Wat)) {
+            write_wat(&wasm_path, &build_plan.output_path.with_extension("wat")).map_err(|error| vec![error])?;
+        }
+
+        return Ok(());
+    }
+
+    if !is_host_triple(&build_plan.target) {
+        eprintln!("Linking for target '{}' is only supported on a matching host, object file written to {}", build_plan.target, object_path.display());
+        return Ok(());
+    }
+
+    link_native(&object_path, &build_plan.output_path).map_err(|error| vec![error])
+}
+
+fn lower_files(build_plan: &BuildPlan, files: &[LoadedFile]) -> Vec<MirModule> {
+    files.iter().map(|file| lower(&file.ast, &file.name, &file.source, build_plan.runtime_checks)).collect()
+}
+
+fn optimization_options(disabled_passes: &[OptimizationPass], runtime_checks: bool) -> OptimizationOptions {
+    OptimizationOptions {
+        constant_folding: !disabled_passes.contains(&OptimizationPass::ConstantFolding),
+        dead_branch_elimination: !disabled_passes.contains(&OptimizationPass::DeadBranchElimination),
+        inlining: !disabled_passes.contains(&OptimizationPass::Inlining),
+        common_subexpression_elimination: !disabled_passes.contains(&OptimizationPass::CommonSubexpressionElimination),
+        runtime_checks,
+    }
+}
+
+pub fn lint_registry(lint_levels: &[(Lint, LintLevel)], deny_warnings: bool) -> LintRegistry {
+    let mut lint_registry = LintRegistry::new();
+    for (lint, level) in lint_levels {
+        lint_registry.set_level(*lint, *level);
+    }
+    lint_registry.set_deny_warnings(deny_warnings);
+
+    lint_registry
+}
+
+fn build_c(build_plan: &BuildPlan, files: &[LoadedFile], emit: Option<Emit>) -> Result<(), Box<dyn Error>> {
+    let file_nodes: Vec<_> = files.iter()
+        .filter_map(|file| match file.ast.as_ref() {
+            Node::File(file_node) => Some((file.name.as_str(), file.source.as_str(), file_node)),
+            _ => None,
+        })
+        .collect();
+
+    let mut c_code_generator = CCodeGenerator::new("", "");
+    c_code_generator.emit_files(&file_nodes);
+
+    let c_path = build_plan.output_path.with_extension("c");
+    fs::write(&c_path, c_code_generator.source()?)?;
+
+    let compile_only = matches!(emit, Some(Emit::Obj));
+
+    if !compile_only && !build_plan.link {
+        return Ok(());
+    }
+
+    if !is_host_triple(&build_plan.target) {
+        eprintln!("Compiling C for target '{}' is only supported on a matching host, C source written to {}", build_plan.target, c_path.display());
+        return Ok(());
+    }
+
+    if compile_only {
+        run_tool("cc", &[Path::new("-std=c99"), Path::new("-c"), &c_path, Path::new("-o"), &build_plan.output_path.with_extension("o")])
+    } else {
+        run_tool("cc", &[Path::new("-std=c99"), &c_path, Path::new("-o"), &build_plan.output_path])
+    }
+}
+
+fn run_tool(tool: &str, arguments: &[&Path]) -> Result<(), Box<dyn Error>> {
+    let status = Command::new(tool)
+        .args(arguments)
+        .status()
+        .map_err(|error| format!("Unable to run '{}': {}", tool, error))?;
+
+    if !status.success() {
+        return Err(format!("'{}' exited with {}", tool, status).into());
+    }
+
+    Ok(())
+}
+
+fn link_native(object_path: &Path, output_path: &Path) -> Result<(), Box<dyn Error>> {
+    run_tool("cc", &[object_path, Path::new("-o"), output_path])
+}
+
+fn link_wasm(object_path: &Path, output_path: &Path) -> Result<(), Box<dyn Error>> {
+    run_tool("wasm-ld", &[object_path, Path::new("-o"), output_path])
+}
+
+fn write_wat(wasm_path: &Path, wat_path: &Path) -> Result<(), Box<dyn Error>> {
+    let wasm = fs::read(wasm_path)?;
+    let wat = wasmprinter::print_bytes(wasm)?;
+
+    fs::write(wat_path, wat)?;
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,37 @@
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+use crate::build::lint_registry;
+use crate::project::loader::{load, Project, SourceFile};
+use crate::CheckArgs;
+
+pub fn check(check_args: CheckArgs) -> Result<(), Vec<Box<dyn Error>>> {
+    let CheckArgs { path, deny_warnings, message_format } = check_args;
+
+    let (source_files, lint_levels) = match path {
+        Some(path) => (vec![SourceFile::new(&path)], vec![]),
+        None => {
+            let project = Project::find().map_err(|error| vec![error])?;
+
+            (project.sources().map_err(|error| vec![error])?, project.config.lint_levels().map_err(|error| vec![error])?)
+        }
+    };
+
+    load(&source_files, &lint_registry(&lint_levels, deny_warnings), message_format, &[])?;
+
+    Ok(())
+}
+
+pub fn collect_sources(directory: &Path, source_paths: &mut Vec<PathBuf>) -> Result<(), Box<dyn Error>> {
+    for entry in fs::read_dir(directory)? {
+        let path = entry?.path();
+
+        if path.is_dir() {
+            collect_sources(&path, source_paths)?;
+        } else if path.extension().is_some_and(|extension| extension == "axiom") {
+            source_paths.push(path);
+        }
+    }
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,227 @@
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+use compiler::ast::{FunctionNode, Node};
+use crate::build::lint_registry;
+use crate::project::config_file::Config;
+use crate::project::loader::{collect_source_files, load};
+use crate::{DocArgs, DocFormat, MessageFormat};
+
+const STYLESHEET: &str = "body { font-family: sans-serif; margin: 0 auto; max-width: 60rem; padding: 1rem 2rem; line-height: 1.5; }
+nav { border-bottom: 1px solid #ccc; margin-bottom: 1rem; padding-bottom: 0.5rem; }
+code, pre { font-family: monospace; background: #f4f4f4; padding: 0.1rem 0.3rem; }
+pre { padding: 0.5rem; overflow-x: auto; }
+section { border-top: 1px solid #eee; padding-top: 0.5rem; }
+#search { width: 100%; font-size: 1rem; padding: 0.4rem; margin-bottom: 1rem; }
+#functions li { margin-bottom: 0.3rem; }
+.summary { color: #555; }";
+
+const SEARCH_SCRIPT: &str = "const search = document.getElementById('search');
+search.addEventListener('input', () => {
+    const query = search.value.trim().toLowerCase();
+    for (const item of document.querySelectorAll('#functions li')) {
+        item.hidden = !item.dataset.name.toLowerCase().includes(query);
+    }
+});";
+
+struct DocumentedFunction {
+    name: String,
+    declaration: String,
+    signature: String,
+    documentation: String,
+}
+
+impl DocumentedFunction {
+    fn new(function_node: &FunctionNode) -> DocumentedFunction {
+        let name = function_node.identifier_node.identifier_token.name.clone();
+
+        let parameters: Vec<String> = function_node.parameters.iter()
+            .map(|parameter| format!("{}: {}", parameter.identifier_node.identifier_token.name, parameter.type_node.identifier_token.name))
+            .collect();
+
+        let mut declaration = format!("function {}({})", name, parameters.join(", "));
+        if let Some(type_node) = &function_node.type_node {
+            declaration.push_str(&format!(": {}", type_node.identifier_token.name));
+        }
+
+        DocumentedFunction {
+            name,
+            declaration,
+            signature: function_node.data_type.to_string(),
+            documentation: function_node.documentation(),
+        }
+    }
+
+    fn summary(&self) -> &str {
+        self.documentation.lines().next().unwrap_or_default()
+    }
+}
+
+struct DocumentedModule {
+    name: String,
+    functions: Vec<DocumentedFunction>,
+}
+
+impl DocumentedModule {
+    fn file_name(&self, extension: &str) -> String {
+        format!("{}.{}", self.name.replace("::", "."), extension)
+    }
+}
+
+pub fn doc(doc_args: DocArgs) -> Result<(), Vec<Box<dyn Error>>> {
+    let DocArgs { format, output } = doc_args;
+
+    let config = Config::load(Path::new("axiom.toml")).map_err(|error| vec![error])?;
+    let lint_registry = lint_registry(&config.lint_levels().map_err(|error| vec![error])?, false);
+
+    let source_files = collect_source_files(Path::new("src")).map_err(|error| vec![error])?;
+    let loaded_files = load(&source_files, &lint_registry, MessageFormat::Human, &[])?;
+
+    let modules: Vec<DocumentedModule> = source_files.iter().zip(&loaded_files)
+        .map(|(source_file, loaded_file)| collect_module(&source_file.path, &loaded_file.ast))
+        .collect();
+
+    let output_directory = output.unwrap_or_else(|| PathBuf::from("docs"));
+    fs::create_dir_all(&output_directory).map_err(|error| vec![Box::from(error)])?;
+
+    let index_path = match format {
+        DocFormat::Html => write_html(&config.package.name, &modules, &output_directory),
+        DocFormat::Markdown => write_markdown(&config.package.name, &modules, &output_directory),
+    }.map_err(|error| vec![error])?;
+
+    println!("Documentation written to {}", index_path.display());
+
+    Ok(())
+}
+
+fn collect_module(source_path: &Path, ast: &Node) -> DocumentedModule {
+    let module_path = source_path.strip_prefix("src").unwrap_or(source_path).with_extension("");
+    let name = module_path.display().to_string().replace(['/', '\\'], "::");
+
+    let functions = match ast {
+        Node::File(file_node) => file_node.functions.iter()
+            .filter(|function| !function.is_test())
+            .map(|function| DocumentedFunction::new(function))
+            .collect(),
+        _ => vec![],
+    };
+
+    DocumentedModule {
+        name,
+        functions,
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+fn html_page(title: &str, body: &str, script: Option<&str>) -> String {
+    let script = script.map(|script| format!("<script>\n{}\n</script>\n", script)).unwrap_or_default();
+
+    format!("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<link rel=\"stylesheet\" href=\"style.css\">\n</head>\n<body>\n{}{}</body>\n</html>\n", escape_html(title), body, script)
+}
+
+fn html_documentation(documentation: &str) -> String {
+    documentation.split("\n\n")
+        .filter(|paragraph| !paragraph.trim().is_empty())
+        .map(|paragraph| format!("<p>{}</p>\n", escape_html(paragraph)))
+        .collect()
+}
+
+fn write_html(package_name: &str, modules: &[DocumentedModule], output_directory: &Path) -> Result<PathBuf, Box<dyn Error>> {
+    fs::write(output_directory.join("style.css"), format!("{}\n", STYLESHEET))?;
+
+    let mut index = format!("<nav><a href=\"index.html\">{}</a></nav>\n<main>\n<h1>{}</h1>\n", escape_html(package_name), escape_html(package_name));
+
+    index.push_str("<h2>Modules</h2>\n<ul>\n");
+    for module in modules {
+        index.push_str(&format!("<li><a href=\"{}\">{}</a></li>\n", module.file_name("html"), escape_html(&module.name)));
+    }
+    index.push_str("</ul>\n");
+
+    index.push_str("<h2>Functions</h2>\n<input id=\"search\" type=\"search\" placeholder=\"Search functions by name\" autocomplete=\"off\">\n<ul id=\"functions\">\n");
+    for module in modules {
+        for function in &module.functions {
+            index.push_str(&format!(
+                "<li data-name=\"{}\"><a href=\"{}#{}\">{}::{}</a> <code>{}</code>",
+                escape_html(&function.name),
+                module.file_name("html"),
+                escape_html(&function.name),
+                escape_html(&module.name),
+                escape_html(&function.name),
+                escape_html(&function.signature),
+            ));
+
+            if !function.summary().is_empty() {
+                index.push_str(&format!(" <span class=\"summary\">{}</span>", escape_html(function.summary())));
+            }
+
+            index.push_str("</li>\n");
+        }
+    }
+    index.push_str("</ul>\n</main>\n");
+
+    for module in modules {
+        let mut page = format!("<nav><a href=\"index.html\">{}</a> / {}</nav>\n<main>\n<h1>Module {}</h1>\n", escape_html(package_name), escape_html(&module.name), escape_html(&module.name));
+
+        for function in &module.functions {
+            page.push_str(&format!(
+                "<section id=\"{}\">\n<h2>{}</h2>\n<pre><code>{}</code></pre>\n<p>Type: <code>{}</code></p>\n{}</section>\n",
+                escape_html(&function.name),
+                escape_html(&function.name),
+                escape_html(&function.declaration),
+                escape_html(&function.signature),
+                html_documentation(&function.documentation),
+            ));
+        }
+
+        page.push_str("</main>\n");
+
+        fs::write(output_directory.join(module.file_name("html")), html_page(&format!("{} - {}", module.name, package_name), &page, None))?;
+    }
+
+    let index_path = output_directory.join("index.html");
+    fs::write(&index_path, html_page(package_name, &index, Some(SEARCH_SCRIPT)))?;
+
+    Ok(index_path)
+}
+
+fn write_markdown(package_name: &str, modules: &[DocumentedModule], output_directory: &Path) -> Result<PathBuf, Box<dyn Error>> {
+    let mut index = format!("# {}\n\n## Modules\n\n", package_name);
+    for module in modules {
+        index.push_str(&format!("- [{}]({})\n", module.name, module.file_name("md")));
+    }
+
+    index.push_str("\n## Functions\n\n");
+    for module in modules {
+        for function in &module.functions {
+            index.push_str(&format!("- [`{}::{}`]({}#{}) `{}`", module.name, function.name, module.file_name("md"), function.name.to_lowercase(), function.signature));
+
+            if !function.summary().is_empty() {
+                index.push_str(&format!(" - {}", function.summary()));
+            }
+
+            index.push('\n');
+        }
+    }
+
+    for module in modules {
+        let mut page = format!("# Module {}\n\n[Back to {}](index.md)\n", module.name, package_name);
+
+        for function in &module.functions {
+            page.push_str(&format!("\n## {}\n\n```axiom\n{}\n```\n\nType: `{}`\n", function.name, function.declaration, function.signature));
+
+            if !function.documentation.is_empty() {
+                page.push_str(&format!("\n{}\n", function.documentation));
+            }
+        }
+
+        fs::write(output_directory.join(module.file_name("md")), page)?;
+    }
+
+    let index_path = output_directory.join("index.md");
+    fs::write(&index_path, index)?;
+
+    Ok(index_path)
+}
@@ -0,0 +1,136 @@
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+use compiler::formatter::{format, FormatOptions};
+use crate::check::collect_sources;
+use crate::message::render_errors;
+use crate::project::config_file::Config;
+use crate::{FmtArgs, MessageFormat};
+
+pub fn fmt(fmt_args: FmtArgs) -> Result<(), Vec<Box<dyn Error>>> {
+    let FmtArgs { paths, check } = fmt_args;
+
+    let config_path = Path::new("axiom.toml");
+    let format_options = match config_path.is_file() {
+        true => Config::load(config_path).map_err(|error| vec![error])?.fmt.format_options(),
+        false => FormatOptions::default(),
+    };
+
+    let source_paths = match paths.is_empty() {
+        true => project_sources(config_path).map_err(|error| vec![error])?,
+        false => explicit_sources(paths).map_err(|error| vec![error])?,
+    };
+
+    let mut errors = vec![];
+    let mut unformatted_count = 0;
+
+    for source_path in &source_paths {
+        let source_name = source_path.display().to_string();
+
+        let source = match fs::read_to_string(source_path) {
+            Ok(source) => source,
+            Err(error) => {
+                errors.push(Box::from(format!("Unable to read '{}': {}", source_name, error)));
+                continue;
+            }
+        };
+
+        let formatted = match format(&source, &format_options) {
+            Ok(formatted) => formatted,
+            Err(file_errors) => {
+                errors.extend(render_errors(&source_name, &source, file_errors, MessageFormat::Human));
+                continue;
+            }
+        };
+
+        if formatted == source {
+            continue;
+        }
+
+        if check {
+            unformatted_count += 1;
+            print_diff(&source_name, &source, &formatted);
+        } else if let Err(error) = fs::write(source_path, formatted) {
+            errors.push(Box::from(format!("Unable to write '{}': {}", source_name, error)));
+        }
+    }
+
+    if unformatted_count > 0 {
+        errors.push(Box::from(format!("{} {} would be reformatted", unformatted_count, if unformatted_count == 1 { "file" } else { "files" })));
+    }
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    Ok(())
+}
+
+fn project_sources(config_path: &Path) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+    if !config_path.is_file() {
+        return Err("No 'axiom.toml' found, pass the files to format explicitly".into());
+    }
+
+    let mut source_paths = vec![];
+    for directory in ["src", "tests", "benches"] {
+        if Path::new(directory).is_dir() {
+            collect_sources(Path::new(directory), &mut source_paths)?;
+        }
+    }
+    source_paths.sort();
+
+    Ok(source_paths)
+}
+
+fn explicit_sources(paths: Vec<PathBuf>) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+    let mut source_paths = vec![];
+
+    for path in paths {
+        if path.is_dir() {
+            let mut directory_paths = vec![];
+            collect_sources(&path, &mut directory_paths)?;
+            directory_paths.sort();
+
+            source_paths.extend(directory_paths);
+        } else {
+            source_paths.push(path);
+        }
+    }
+
+    Ok(source_paths)
+}
+
+fn print_diff(source_name: &str, original: &str, formatted: &str) {
+    let original_lines: Vec<&str> = original.lines().collect();
+    let formatted_lines: Vec<&str> = formatted.lines().collect();
+
+    let mut common_lengths = vec![vec![0; formatted_lines.len() + 1]; original_lines.len() + 1];
+    for original_index in (0..original_lines.len()).rev() {
+        for formatted_index in (0..formatted_lines.len()).rev() {
+            common_lengths[original_index][formatted_index] = match original_lines[original_index] == formatted_lines[formatted_index] {
+                true => common_lengths[original_index + 1][formatted_index + 1] + 1,
+                false => common_lengths[original_index + 1][formatted_index].max(common_lengths[original_index][formatted_index + 1]),
+            };
+        }
+    }
+
+    println!("Diff in {}:", source_name);
+
+    let (mut original_index, mut formatted_index) = (0, 0);
+    while original_index < original_lines.len() || formatted_index < formatted_lines.len() {
+        let is_common = original_index < original_lines.len() && formatted_index < formatted_lines.len() && original_lines[original_index] == formatted_lines[formatted_index];
+
+        if is_common {
+            original_index += 1;
+            formatted_index += 1;
+        } else if formatted_index == formatted_lines.len() || (original_index < original_lines.len() && common_lengths[original_index + 1][formatted_index] >= common_lengths[original_index][formatted_index + 1]) {
+            println!("{:>5} -{}", original_index + 1, original_lines[original_index]);
+            original_index += 1;
+        } else {
+            println!("{:>5} +{}", formatted_index + 1, formatted_lines[formatted_index]);
+            formatted_index += 1;
+        }
+    }
+
+    println!();
+}
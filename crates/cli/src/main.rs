@@ -1,18 +1,33 @@
 mod project;
+mod build;
+mod message;
+mod check;
+mod test;
+mod bench;
+mod fmt;
+mod doc;
 
 use std::error::Error;
-use std::fs;
 use std::path::PathBuf;
+use std::process;
 use clap::{Args, Parser as ArgsParser, Subcommand, ValueEnum};
 use inkwell::context::Context;
 use inkwell::OptimizationLevel;
 use inkwell::targets::{InitializationConfig, Target};
 use serde::{Deserialize, Serialize};
-use compiler::analyzer::Analyzer;
-use compiler::codegen::{CodeGen, CodeGenerator};
-use compiler::lexer::Lexer;
-use compiler::parser::Parser;
-use compiler::utils::SymbolTable;
+use compiler::codegen::CodeGenerator;
+use compiler::codegen::target::{host_triple, is_host_triple};
+use compiler::error::code::ErrorCode;
+use compiler::mir::lower;
+use compiler::lint::{Lint, LintLevel};
+use crate::build::{build, lint_registry};
+use crate::check::check;
+use crate::test::test;
+use crate::bench::bench;
+use crate::fmt::fmt;
+use crate::doc::doc;
+use crate::message::report;
+use crate::project::loader::{load, Project, SourceFile};
 use crate::project::init;
 
 #[derive(ArgsParser, Debug)]
@@ -25,18 +40,96 @@ struct Options {
 enum Commands {
     Run(RunArgs),
     Build(BuildArgs),
+    Check(CheckArgs),
+    Test(TestArgs),
+    Bench(BenchArgs),
+    Fmt(FmtArgs),
+    Doc(DocArgs),
     Lsp(LSPArgs),
-    Init(InitArgs)
+    Init(InitArgs),
+    Explain(ExplainArgs)
 }
 
 #[derive(Args, Debug)]
 struct RunArgs {
-    path: PathBuf
+    path: Option<PathBuf>,
+    #[arg(long, value_enum, value_delimiter = ',')]
+    dump: Vec<Dump>,
+    #[arg(long)]
+    deny_warnings: bool,
+    #[arg(long, value_enum, default_value_t = MessageFormat::Human)]
+    message_format: MessageFormat,
+    #[arg(last = true)]
+    args: Vec<String>
 }
 
 #[derive(Args, Debug)]
 struct BuildArgs {
+    path: Option<PathBuf>,
+    #[arg(long)]
+    target: Option<String>,
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+    #[arg(long, value_enum)]
+    emit: Option<Emit>,
+    #[arg(long, value_enum, default_value_t = Backend::Llvm)]
+    backend: Backend,
+    #[arg(short = 'O', default_value_t = 0, value_parser = clap::value_parser!(u8).range(0..=3))]
+    optimization_level: u8,
+    #[arg(long = "disable-pass", value_enum)]
+    disabled_passes: Vec<OptimizationPass>,
+    #[arg(long)]
+    release: bool,
+    #[arg(long)]
+    deny_warnings: bool,
+    #[arg(long, value_enum, default_value_t = MessageFormat::Human)]
+    message_format: MessageFormat
+}
+
+#[derive(Args, Debug)]
+struct CheckArgs {
+    path: Option<PathBuf>,
+    #[arg(long)]
+    deny_warnings: bool,
+    #[arg(long, value_enum, default_value_t = MessageFormat::Human)]
+    message_format: MessageFormat
+}
+
+#[derive(Args, Debug)]
+struct TestArgs {
+    filter: Option<String>,
+    #[arg(long, hide = true)]
+    run_test: Option<String>
+}
+
+#[derive(Args, Debug)]
+struct BenchArgs {
+    filter: Option<String>,
+    #[arg(long, default_value_t = 10)]
+    warmup: u64,
+    #[arg(long, default_value_t = 100)]
+    iterations: u64,
+    #[arg(long)]
+    save_baseline: bool,
+    #[arg(long)]
+    baseline: bool,
+    #[arg(long, default_value_t = 5.0)]
+    threshold: f64
+}
 
+#[derive(Args, Debug)]
+struct FmtArgs {
+    paths: Vec<PathBuf>,
+    #[arg(long)]
+    check: bool
+}
+
+#[derive(Args, Debug)]
+struct DocArgs {
+    #[arg(long, value_enum, default_value_t = DocFormat::Html)]
+    format: DocFormat,
+    #[arg(short, long)]
+    output: Option<PathBuf>
 }
 
 #[derive(Args, Debug)]
@@ -50,23 +143,118 @@ struct InitArgs {
     project_type: ProjectType
 }
 
+#[derive(Args, Debug)]
+struct ExplainArgs {
+    code: String
+}
+
 #[derive(ValueEnum, Debug, Clone, Serialize, Deserialize)]
 enum ProjectType {
     Bin,
     Lib
 }
 
+#[derive(ValueEnum, Debug, Clone)]
+enum Backend {
+    Llvm,
+    C
+}
+
+#[derive(ValueEnum, Debug, Clone)]
+enum Emit {
+    Obj,
+    Wat,
+    Mir,
+    LlvmIr
+}
+
+#[derive(ValueEnum, Debug, Clone, PartialEq)]
+enum Dump {
+    Tokens,
+    Ast,
+    Mir,
+    Ir
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy)]
+enum DocFormat {
+    Html,
+    Markdown
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy)]
+enum MessageFormat {
+    Human,
+    /// One JSON object per line on stderr, so stdout only carries the output of the program under `axiom run`
+    Json
+}
+
+#[derive(ValueEnum, Debug, Clone, PartialEq)]
+enum OptimizationPass {
+    ConstantFolding,
+    DeadBranchElimination,
+    Inlining,
+    CommonSubexpressionElimination
+}
+
 fn main() {
     let options = Options::parse();
 
     match options.command {
         Commands::Run(run_args) => {
-            let errors = run(run_args.path).err();
+            let message_format = run_args.message_format;
+            match run(run_args) {
+                Ok(exit_code) => process::exit(exit_code),
+                Err(errors) => {
+                    report(&errors, message_format);
+                    process::exit(1);
+                }
+            }
+        }
+        Commands::Build(build_args) => {
+            let message_format = build_args.message_format;
+            let errors = build(build_args).err();
             if let Some(errors) = errors {
-                errors.iter().for_each(|error| eprintln!("{}", error));
+                report(&errors, message_format);
+                process::exit(1);
+            }
+        }
+        Commands::Check(check_args) => {
+            let message_format = check_args.message_format;
+            let errors = check(check_args).err();
+            if let Some(errors) = errors {
+                report(&errors, message_format);
+                process::exit(1);
+            }
+        }
+        Commands::Test(test_args) => {
+            let errors = test(test_args).err();
+            if let Some(errors) = errors {
+                report(&errors, MessageFormat::Human);
+                process::exit(1);
+            }
+        }
+        Commands::Bench(bench_args) => {
+            let errors = bench(bench_args).err();
+            if let Some(errors) = errors {
+                report(&errors, MessageFormat::Human);
+                process::exit(1);
+            }
+        }
+        Commands::Fmt(fmt_args) => {
+            let errors = fmt(fmt_args).err();
+            if let Some(errors) = errors {
+                report(&errors, MessageFormat::Human);
+                process::exit(1);
+            }
+        }
+        Commands::Doc(doc_args) => {
+            let errors = doc(doc_args).err();
+            if let Some(errors) = errors {
+                report(&errors, MessageFormat::Human);
+                process::exit(1);
             }
         }
-        Commands::Build(_) => {}
         Commands::Lsp(_) => {
             if let Err(error) = lsp::start() {
                 eprintln!("{}", error);
@@ -77,47 +265,95 @@ fn main() {
                 eprintln!("{}", error.to_string());
             }
         }
+        Commands::Explain(explain_args) => {
+            match ErrorCode::parse(&explain_args.code) {
+                Some(code) => println!("{}", code.explanation().trim_end()),
+                None => {
+                    eprintln!("'{}' is not a known error code", explain_args.code);
+                    process::exit(1);
+                }
+            }
+        }
     }
 }
 
-fn run(path_buf: PathBuf) -> Result<(), Vec<Box<dyn Error>>> {
-    let file_content = match fs::read_to_string(path_buf) {
-        Ok(file_content) => file_content,
-        Err(error) => return Err(vec![Box::from(error)])
-    };
+struct RunPlan {
+    program_name: String,
+    source_files: Vec<SourceFile>,
+    target: String,
+    runtime_checks: bool,
+    lint_levels: Vec<(Lint, LintLevel)>,
+}
 
-    let tokens = match Lexer::new(&file_content).parse() {
-        Ok(tokens) => tokens,
-        Err(error) => return Err(vec![Box::from(error)])
-    };
+impl RunPlan {
+    fn from_file(path: PathBuf) -> RunPlan {
+        RunPlan {
+            program_name: path.display().to_string(),
+            source_files: vec![SourceFile::new(&path)],
+            target: host_triple(),
+            runtime_checks: true,
+            lint_levels: vec![],
+        }
+    }
 
-    println!("Tokens: ");
-    tokens.iter().for_each(|token| {println!("{:?}", token)});
+    fn from_project() -> Result<RunPlan, Box<dyn Error>> {
+        let project = Project::find().map_err(|error| format!("{}, pass the file to run explicitly", error))?;
+        let config = &project.config;
 
-    let mut ast = match Parser::new(tokens).parse() {
-        Ok(ast) => ast,
-        Err(error) => return Err(vec![Box::from(error)])
+        if matches!(config.package.r#type, ProjectType::Lib) {
+            return Err(format!("Cannot run '{}' because it is a library project, only binary projects have a 'main' to run", config.package.name).into());
+        }
+
+        project.entry_point()?;
+
+        Ok(RunPlan {
+            program_name: config.package.name.clone(),
+            source_files: project.sources()?,
+            runtime_checks: config.profile.debug.runtime_checks,
+            lint_levels: config.lint_levels()?,
+            target: config.build.target.clone(),
+        })
+    }
+}
+
+fn run(run_args: RunArgs) -> Result<i32, Vec<Box<dyn Error>>> {
+    let RunArgs { path, dump, deny_warnings, message_format, args } = run_args;
+
+    let run_plan = match path {
+        Some(path) => RunPlan::from_file(path),
+        None => RunPlan::from_project().map_err(|error| vec![error])?,
     };
 
-    println!("AST: ");
-    ast.display(0);
+    if !is_host_triple(&run_plan.target) {
+        return Err(vec![format!("Cannot run a program for target '{}' on this host, use 'axiom build' instead", run_plan.target).into()]);
+    }
+
+    let lint_registry = lint_registry(&run_plan.lint_levels, deny_warnings);
 
-    let mut symbol_table = SymbolTable::new();
-    symbol_table.add_build_in_types();
+    let files = load(&run_plan.source_files, &lint_registry, message_format, &dump)?;
 
-    let mut errors = vec![];
+    let mut mir_modules = vec![];
+    for file in &files {
+        let mir_module = lower(&file.ast, &file.name, &file.source, run_plan.runtime_checks);
+
+        if dump.contains(&Dump::Mir) {
+            println!("MIR: ");
+            println!("{}", mir_module);
+        }
 
-    ast.analyze(&mut symbol_table, &mut errors);
-    if errors.len() > 0 {
-        return Err(errors.into_iter().map(|error| Box::from(error)).collect());
+        mir_modules.push(mir_module);
     }
 
     let context = Context::create();
     let mut codegen = CodeGenerator::new(&context);
-    ast.build(&mut codegen);
+    codegen.add_build_in_functions(&run_plan.target);
+    codegen.build_all(&mir_modules);
+    codegen.add_native_start().map_err(|error| vec![error])?;
 
-    println!("LLVM IR: ");
-    println!("{}", codegen.to_string());
+    if dump.contains(&Dump::Ir) {
+        println!("LLVM IR: ");
+        println!("{}", codegen.to_string());
+    }
 
     if let Err(error) = Target::initialize_native(&InitializationConfig::default()) {
         return Err(vec![Box::from(error)])
@@ -126,15 +362,15 @@ fn run(path_buf: PathBuf) -> Result<(), Vec<Box<dyn Error>>> {
         Ok(execution_engine) => execution_engine,
         Err(error) => return Err(vec![Box::from(error)])
     };
-    let main_func = match unsafe {
-        execution_engine.get_function::<unsafe extern "C" fn() -> i32>("main")
-    }.map_err(|e| format!("Function 'main' not found in JIT engine: {:?}", e)) {
-        Ok(main_func) => main_func,
-        Err(error) => return Err(vec![Box::from(error)])
+    let main_function = match codegen.module.get_function("main") {
+        Some(main_function) => main_function,
+        None => return Err(vec![Box::from("Function 'main' not found")])
     };
-    let result = unsafe { main_func.call() };
 
-    println!("Result: {}", result);
+    let mut program_args = vec![run_plan.program_name.as_str()];
+    program_args.extend(args.iter().map(|arg| arg.as_str()));
 
-    Ok(())
-}
\ No newline at end of file
+    let exit_code = unsafe { execution_engine.run_function_as_main(main_function, &program_args) };
+
+    Ok(exit_code)
+}
@@ -0,0 +1,188 @@
+use std::env;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io::{self, IsTerminal};
+use serde::Serialize;
+use compiler::error::AxiomError;
+use compiler::error::diagnostic::{Diagnostic, DiagnosticRenderer};
+use compiler::error::location::Range;
+use crate::MessageFormat;
+
+pub const SCHEMA_VERSION: u32 = 1;
+
+#[derive(Debug)]
+pub struct RenderedDiagnostic(String);
+
+impl Display for RenderedDiagnostic {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Error for RenderedDiagnostic {}
+
+#[derive(Serialize)]
+struct JsonMessage {
+    version: u32,
+    kind: &'static str,
+    severity: String,
+    code: Option<String>,
+    message: String,
+    file: Option<String>,
+    spans: Vec<JsonSpan>,
+    notes: Vec<String>,
+    help: Option<String>,
+}
+
+#[derive(Serialize)]
+struct JsonSpan {
+    line_start: usize,
+    column_start: usize,
+    line_end: usize,
+    column_end: usize,
+    is_primary: bool,
+    label: Option<String>,
+}
+
+impl JsonSpan {
+    fn new(location: &Range, is_primary: bool, label: Option<String>) -> JsonSpan {
+        JsonSpan {
+            line_start: location.start.line + 1,
+            column_start: location.start.column + 1,
+            line_end: location.end.line + 1,
+            column_end: location.end.column + 2,
+            is_primary,
+            label,
+        }
+    }
+}
+
+impl JsonMessage {
+    fn from_diagnostic(source_name: &str, diagnostic: &Diagnostic) -> JsonMessage {
+        let mut spans = vec![JsonSpan::new(&diagnostic.location, true, diagnostic.label.clone())];
+        spans.extend(diagnostic.secondary_labels.iter().map(|label| JsonSpan::new(&label.location, false, Some(label.message.clone()))));
+
+        let (kind, code) = match (&diagnostic.code, &diagnostic.lint) {
+            (_, Some(lint)) => ("lint", Some(lint.to_string())),
+            (Some(code), None) => ("diagnostic", Some(code.to_string())),
+            (None, None) => ("diagnostic", None),
+        };
+
+        JsonMessage {
+            version: SCHEMA_VERSION,
+            kind,
+            severity: diagnostic.severity.to_string(),
+            code,
+            message: diagnostic.message.clone(),
+            file: Some(source_name.to_string()),
+            spans,
+            notes: diagnostic.notes.clone(),
+            help: diagnostic.help.clone(),
+        }
+    }
+
+    fn from_failure(message: String) -> JsonMessage {
+        JsonMessage {
+            version: SCHEMA_VERSION,
+            kind: "failure",
+            severity: "error".to_string(),
+            code: None,
+            message,
+            file: None,
+            spans: vec![],
+            notes: vec![],
+            help: None,
+        }
+    }
+
+    fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+}
+
+pub fn render_errors(source_name: &str, source: &str, errors: Vec<AxiomError>, message_format: MessageFormat) -> Vec<Box<dyn Error>> {
+    let diagnostics: Vec<Diagnostic> = errors.iter().map(|error| error.diagnostic()).collect();
+
+    render_diagnostics(source_name, source, &diagnostics, message_format)
+}
+
+pub fn render_diagnostics(source_name: &str, source: &str, diagnostics: &[Diagnostic], message_format: MessageFormat) -> Vec<Box<dyn Error>> {
+    match message_format {
+        MessageFormat::Human => {
+            let colored = io::stderr().is_terminal() && env::var_os("NO_COLOR").is_none();
+            let renderer = DiagnosticRenderer::new(source_name, source, colored);
+
+            diagnostics.iter().map(|diagnostic| Box::from(RenderedDiagnostic(renderer.render(diagnostic))) as Box<dyn Error>).collect()
+        }
+        MessageFormat::Json => {
+            diagnostics.iter().map(|diagnostic| Box::from(RenderedDiagnostic(JsonMessage::from_diagnostic(source_name, diagnostic).to_json())) as Box<dyn Error>).collect()
+        }
+    }
+}
+
+pub fn report(errors: &[Box<dyn Error>], message_format: MessageFormat) {
+    for error in errors {
+        match message_format {
+            MessageFormat::Human => eprintln!("{}", error),
+            MessageFormat::Json => match error.downcast_ref::<RenderedDiagnostic>() {
+                Some(rendered_diagnostic) => eprintln!("{}", rendered_diagnostic),
+                None => eprintln!("{}", JsonMessage::from_failure(error.to_string()).to_json()),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use compiler::error::AxiomError;
+    use compiler::error::diagnostic::{Diagnostic, Severity};
+    use compiler::error::location::{Position, Range};
+    use compiler::lint::Lint;
+    use crate::message::{render_diagnostics, render_errors, JsonMessage};
+    use crate::MessageFormat;
+
+    fn range(line: usize, start_column: usize, end_column: usize) -> Range {
+        Range::new(Position::new(line, start_column), Position::new(line, end_column))
+    }
+
+    #[test]
+    fn serializes_diagnostic() {
+        let error = AxiomError::MismatchedNumberOfParameters(range(5, 11, 16), "add".to_string(), 2, 1);
+        let messages = render_errors("main.axiom", "", vec![error], MessageFormat::Json);
+
+        assert_eq!(messages[0].to_string(), concat!(
+            r#"{"version":1,"kind":"diagnostic","severity":"error","code":"E0007","#,
+            r#""message":"Mismatched number of parameters, function add takes 2 parameters, but given 1","file":"main.axiom","#,
+            r#""spans":[{"line_start":6,"column_start":12,"line_end":6,"column_end":18,"is_primary":true,"label":"expected 2 arguments, given 1"}],"#,
+            r#""notes":[],"help":null}"#,
+        ));
+    }
+
+    #[test]
+    fn serializes_lint() {
+        let diagnostic = Diagnostic::new("Unused parameter: value".to_string(), range(0, 14, 18))
+            .with_severity(Severity::Warning)
+            .with_lint(Lint::UnusedParameters)
+            .with_label("never read")
+            .with_secondary_label(range(0, 9, 12), "in this function")
+            .with_note("prefix it with '_' to silence this warning");
+        let messages = render_diagnostics("lib.axiom", "", &[diagnostic], MessageFormat::Json);
+
+        assert_eq!(messages[0].to_string(), concat!(
+            r#"{"version":1,"kind":"lint","severity":"warning","code":"unused_parameters","message":"Unused parameter: value","file":"lib.axiom","#,
+            r#""spans":[{"line_start":1,"column_start":15,"line_end":1,"column_end":20,"is_primary":true,"label":"never read"},"#,
+            r#"{"line_start":1,"column_start":10,"line_end":1,"column_end":14,"is_primary":false,"label":"in this function"}],"#,
+            r#""notes":["prefix it with '_' to silence this warning"],"help":null}"#,
+        ));
+    }
+
+    #[test]
+    fn serializes_failure() {
+        let message = JsonMessage::from_failure("Failed to read 'main.axiom'".to_string());
+
+        assert_eq!(message.to_json(), concat!(
+            r#"{"version":1,"kind":"failure","severity":"error","code":null,"message":"Failed to read 'main.axiom'","#,
+            r#""file":null,"spans":[],"notes":[],"help":null}"#,
+        ));
+    }
+}
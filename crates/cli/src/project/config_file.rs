@@ -1,10 +1,24 @@
-use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde::de::Error as _;
+use compiler::codegen::target::host_triple;
+use compiler::formatter::{BraceStyle, FormatOptions};
+use compiler::lint::{Lint, LintLevel};
 use crate::ProjectType;
 
 #[derive(Serialize, Deserialize)]
 pub struct Config {
     pub package: Package,
-    pub build: Build
+    pub build: Build,
+    #[serde(default)]
+    pub profile: Profiles,
+    #[serde(default)]
+    pub fmt: FormatSettings,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub lints: BTreeMap<String, LintLevelSetting>
 }
 
 #[derive(Serialize, Deserialize)]
@@ -16,11 +30,170 @@ pub struct Package {
 
 #[derive(Serialize, Deserialize)]
 pub struct Build {
-    pub target: Target,
+    #[serde(deserialize_with = "deserialize_target")]
+    pub target: String,
+}
+
+const LEGACY_LINUX_TARGET: &str = "Linux";
+
+fn deserialize_target<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    let target = String::deserialize(deserializer)?;
+
+    if target != LEGACY_LINUX_TARGET {
+        return Ok(target);
+    }
+
+    let host_triple = host_triple();
+    if host_triple.contains("linux") {
+        return Ok(host_triple);
+    }
+
+    Err(D::Error::custom(format!("target '{}' is no longer supported, write an LLVM target triple instead, e.g. target = \"x86_64-unknown-linux-gnu\"", LEGACY_LINUX_TARGET)))
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Profiles {
+    #[serde(default = "Profile::debug")]
+    pub debug: Profile,
+    #[serde(default = "Profile::release")]
+    pub release: Profile
+}
+
+impl Default for Profiles {
+    fn default() -> Self {
+        Profiles {
+            debug: Profile::debug(),
+            release: Profile::release()
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize)]
-pub enum Target {
-    Linux,
-    //Windows
-}
\ No newline at end of file
+#[serde(rename_all = "kebab-case")]
+pub struct Profile {
+    pub runtime_checks: bool
+}
+
+impl Profile {
+    pub fn debug() -> Profile {
+        Profile {
+            runtime_checks: true
+        }
+    }
+
+    pub fn release() -> Profile {
+        Profile {
+            runtime_checks: false
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy)]
+#[serde(rename_all = "kebab-case")]
+pub enum LintLevelSetting {
+    Allow,
+    Warn,
+    Deny
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct FormatSettings {
+    pub indent_width: usize,
+    pub hard_tabs: bool,
+    pub blank_lines_between_functions: usize,
+    pub brace_style: BraceStyleSetting
+}
+
+impl Default for FormatSettings {
+    fn default() -> Self {
+        let format_options = FormatOptions::default();
+
+        FormatSettings {
+            indent_width: format_options.indent_width,
+            hard_tabs: format_options.hard_tabs,
+            blank_lines_between_functions: format_options.blank_lines_between_functions,
+            brace_style: BraceStyleSetting::SameLine
+        }
+    }
+}
+
+impl FormatSettings {
+    pub fn format_options(&self) -> FormatOptions {
+        FormatOptions {
+            indent_width: self.indent_width,
+            hard_tabs: self.hard_tabs,
+            blank_lines_between_functions: self.blank_lines_between_functions,
+            brace_style: match self.brace_style {
+                BraceStyleSetting::SameLine => BraceStyle::SameLine,
+                BraceStyleSetting::NextLine => BraceStyle::NextLine,
+            }
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy)]
+#[serde(rename_all = "kebab-case")]
+pub enum BraceStyleSetting {
+    SameLine,
+    NextLine
+}
+
+impl Config {
+    pub fn load(path: &Path) -> Result<Config, Box<dyn Error>> {
+        let config_file_content = fs::read_to_string(path)?;
+
+        Ok(toml::from_str(&config_file_content)?)
+    }
+
+    pub fn find(directory: &Path) -> Option<PathBuf> {
+        directory.ancestors()
+            .map(|ancestor| ancestor.join("axiom.toml"))
+            .find(|config_path| config_path.is_file())
+    }
+
+    pub fn lint_levels(&self) -> Result<Vec<(Lint, LintLevel)>, Box<dyn Error>> {
+        let mut lint_levels = vec![];
+
+        for (name, setting) in &self.lints {
+            let lint = Lint::parse(name).ok_or(format!("Unknown lint '{}' in axiom.toml", name))?;
+
+            let level = match setting {
+                LintLevelSetting::Allow => LintLevel::Allow,
+                LintLevelSetting::Warn => LintLevel::Warn,
+                LintLevelSetting::Deny => LintLevel::Deny,
+            };
+
+            lint_levels.push((lint, level));
+        }
+
+        Ok(lint_levels)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use compiler::codegen::target::host_triple;
+    use crate::project::config_file::Config;
+
+    fn config_with_target(target: &str) -> String {
+        format!("[package]\nname = \"example\"\nversion = \"0.1.0\"\ntype = \"Bin\"\n\n[build]\ntarget = \"{}\"\n", target)
+    }
+
+    #[test]
+    fn keeps_target_triples() {
+        let config: Config = toml::from_str(&config_with_target("aarch64-unknown-linux-gnu")).unwrap();
+
+        assert_eq!(config.build.target, "aarch64-unknown-linux-gnu");
+    }
+
+    #[test]
+    fn maps_legacy_linux_target() {
+        let config = toml::from_str::<Config>(&config_with_target("Linux"));
+
+        match host_triple().contains("linux") {
+            true => assert_eq!(config.ok().map(|config| config.build.target), Some(host_triple())),
+            false => assert!(config.err().is_some_and(|error| error.to_string().contains("x86_64-unknown-linux-gnu"))),
+        }
+    }
+}
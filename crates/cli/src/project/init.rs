@@ -1,9 +1,11 @@
+use std::collections::BTreeMap;
 use std::error::Error;
 use std::fs;
 use std::fs::{DirBuilder, File};
 use std::io::Write;
 use std::path::PathBuf;
-use crate::project::config_file::{Build, Config, Package, Target};
+use compiler::codegen::target::host_triple;
+use crate::project::config_file::{Build, Config, FormatSettings, Package, Profiles};
 use crate::ProjectType;
 
 enum ProjectItem<'a> {
@@ -87,8 +89,11 @@ pub fn init(name: String, project_type: ProjectType) -> Result<(), Box<dyn Error
             r#type: project_type.clone()
         },
         build: Build {
-            target: Target::Linux
+            target: host_triple()
         },
+        profile: Profiles::default(),
+        fmt: FormatSettings::default(),
+        lints: BTreeMap::new(),
     };
     
     let config_file_content = toml::to_string(&config)?;
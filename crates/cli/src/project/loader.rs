@@ -0,0 +1,193 @@
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+use compiler::ast::Node;
+use compiler::error::diagnostic::{Diagnostic, Severity};
+use compiler::lexer::Lexer;
+use compiler::lint::LintRegistry;
+use compiler::parser::Parser;
+use compiler::utils::SymbolTable;
+use crate::check::collect_sources;
+use crate::message::{render_diagnostics, render_errors, report};
+use crate::project::config_file::Config;
+use crate::{Dump, MessageFormat, ProjectType};
+
+pub struct Project {
+    pub directory: PathBuf,
+    pub config: Config,
+}
+
+impl Project {
+    /// Loads the `axiom.toml` of the current directory or of the closest parent directory that has one.
+    pub fn find() -> Result<Project, Box<dyn Error>> {
+        let current_directory = env::current_dir()?;
+        let config_path = Config::find(&current_directory)
+            .ok_or(format!("No 'axiom.toml' found in '{}' or any parent directory", current_directory.display()))?;
+
+        Ok(Project {
+            directory: config_path.parent().unwrap_or(&current_directory).to_path_buf(),
+            config: Config::load(&config_path)?,
+        })
+    }
+
+    pub fn entry_point(&self) -> Result<PathBuf, Box<dyn Error>> {
+        let entry_point = match self.config.package.r#type {
+            ProjectType::Bin => "bin.axiom",
+            ProjectType::Lib => "lib.axiom",
+        };
+
+        let entry_point = self.directory.join("src").join(entry_point);
+        if !entry_point.is_file() {
+            return Err(format!("No entry point found, expected '{}'", entry_point.display()).into());
+        }
+
+        Ok(entry_point)
+    }
+
+    pub fn sources(&self) -> Result<Vec<SourceFile>, Box<dyn Error>> {
+        let mut source_paths = vec![];
+        collect_sources(&self.directory.join("src"), &mut source_paths)?;
+        source_paths.sort();
+
+        if source_paths.is_empty() {
+            return Err("No '.axiom' files found in 'src'".into());
+        }
+
+        Ok(source_paths.into_iter()
+            .map(|path| {
+                let name = path.strip_prefix(&self.directory).unwrap_or(&path).display().to_string();
+                SourceFile { path, name }
+            })
+            .collect())
+    }
+}
+
+/// Collects the `.axiom` files below `directory` in a stable order, or none if the directory does not exist.
+pub fn collect_source_files(directory: &Path) -> Result<Vec<SourceFile>, Box<dyn Error>> {
+    let mut source_paths = vec![];
+    if directory.is_dir() {
+        collect_sources(directory, &mut source_paths)?;
+    }
+    source_paths.sort();
+
+    Ok(source_paths.iter().map(|path| SourceFile::new(path)).collect())
+}
+
+#[derive(Clone)]
+pub struct SourceFile {
+    pub path: PathBuf,
+    pub name: String,
+}
+
+impl SourceFile {
+    pub fn new(path: &Path) -> SourceFile {
+        SourceFile {
+            path: path.to_path_buf(),
+            name: path.display().to_string(),
+        }
+    }
+}
+
+pub struct LoadedFile {
+    pub name: String,
+    pub source: String,
+    pub ast: Box<Node>,
+}
+
+/// Parses and analyzes the files as one program. Every function signature is declared before any body is
+/// analyzed, so a file can call functions from any other file regardless of the order they are given in.
+pub fn load(source_files: &[SourceFile], lint_registry: &LintRegistry, message_format: MessageFormat, dump: &[Dump]) -> Result<Vec<LoadedFile>, Vec<Box<dyn Error>>> {
+    load_files(source_files, 0, lint_registry, message_format, dump)
+}
+
+/// Loads a test or bench file as one program together with the `src` files it calls into. The library files
+/// come first in the result and are not linted again, they are expected to have been loaded on their own before.
+pub fn load_with_library(library: &[SourceFile], source_file: &SourceFile, lint_registry: &LintRegistry, message_format: MessageFormat) -> Result<Vec<LoadedFile>, Vec<Box<dyn Error>>> {
+    let mut source_files = library.to_vec();
+    source_files.push(source_file.clone());
+
+    load_files(&source_files, library.len(), lint_registry, message_format, &[])
+}
+
+fn load_files(source_files: &[SourceFile], first_linted_file: usize, lint_registry: &LintRegistry, message_format: MessageFormat, dump: &[Dump]) -> Result<Vec<LoadedFile>, Vec<Box<dyn Error>>> {
+    let mut files = vec![];
+    let mut file_errors = vec![];
+
+    for source_file in source_files {
+        let source = fs::read_to_string(&source_file.path).map_err(|error| vec![Box::from(error)])?;
+
+        let (tokens, mut errors) = Lexer::new(&source).parse();
+
+        if dump.contains(&Dump::Tokens) {
+            println!("Tokens: ");
+            tokens.iter().for_each(|token| {println!("{:?}", token)});
+        }
+
+        let (ast, parser_errors) = Parser::new(tokens).parse();
+        errors.extend(parser_errors);
+
+        if dump.contains(&Dump::Ast) {
+            println!("AST: ");
+            ast.display(0);
+        }
+
+        files.push(LoadedFile {
+            name: source_file.name.clone(),
+            source,
+            ast,
+        });
+        file_errors.push(errors);
+    }
+
+    let mut symbol_table = SymbolTable::new();
+    symbol_table.add_build_in_types();
+    symbol_table.add_build_in_functions();
+
+    for (file, errors) in files.iter_mut().zip(&mut file_errors) {
+        if let Node::File(file_node) = file.ast.as_mut() {
+            file_node.declare_functions(&mut symbol_table, errors);
+        }
+    }
+
+    for (file, errors) in files.iter_mut().zip(&mut file_errors) {
+        if let Node::File(file_node) = file.ast.as_mut() {
+            file_node.analyze_functions(&mut symbol_table, errors);
+        }
+    }
+
+    let asts: Vec<&Node> = files.iter().map(|file| file.ast.as_ref()).collect();
+    let file_diagnostics = lint_registry.check_files(&asts);
+
+    let mut rendered_errors = vec![];
+    for (index, ((file, errors), diagnostics)) in files.iter().zip(file_errors).zip(file_diagnostics).enumerate() {
+        if !errors.is_empty() {
+            rendered_errors.extend(render_errors(&file.name, &file.source, errors, message_format));
+            continue;
+        }
+
+        if index < first_linted_file {
+            continue;
+        }
+
+        if let Err(lint_errors) = check_lints(file, &diagnostics, message_format) {
+            rendered_errors.extend(lint_errors);
+        }
+    }
+
+    if !rendered_errors.is_empty() {
+        return Err(rendered_errors);
+    }
+
+    Ok(files)
+}
+
+fn check_lints(file: &LoadedFile, diagnostics: &[Diagnostic], message_format: MessageFormat) -> Result<(), Vec<Box<dyn Error>>> {
+    if diagnostics.iter().any(|diagnostic| diagnostic.severity == Severity::Error) {
+        return Err(render_diagnostics(&file.name, &file.source, diagnostics, message_format));
+    }
+
+    report(&render_diagnostics(&file.name, &file.source, diagnostics, message_format), message_format);
+
+    Ok(())
+}
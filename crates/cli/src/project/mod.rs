@@ -1,4 +1,5 @@
 pub mod init;
-mod config_file;
+pub mod config_file;
+pub mod loader;
 
 pub use init::*;
\ No newline at end of file
@@ -0,0 +1,161 @@
+use std::env;
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use inkwell::context::Context;
+use inkwell::OptimizationLevel;
+use inkwell::targets::{InitializationConfig, Target};
+use compiler::ast::Node;
+use compiler::codegen::CodeGenerator;
+use compiler::codegen::target::host_triple;
+use compiler::lint::{Lint, LintLevel};
+use compiler::mir::{lower, MirModule};
+use crate::build::lint_registry;
+use crate::project::config_file::Config;
+use crate::project::loader::{collect_source_files, load, load_with_library, SourceFile};
+use crate::{MessageFormat, TestArgs};
+
+struct TestCase {
+    name: String,
+    source_path: PathBuf,
+    function_name: String,
+}
+
+struct TestFailure {
+    name: String,
+    output: String,
+}
+
+pub fn test(test_args: TestArgs) -> Result<(), Vec<Box<dyn Error>>> {
+    let TestArgs { filter, run_test } = test_args;
+
+    if let Some(run_test) = run_test {
+        return run_single_test(&run_test);
+    }
+
+    let config = Config::load(Path::new("axiom.toml")).map_err(|error| vec![error])?;
+    let lint_registry = lint_registry(&config.lint_levels().map_err(|error| vec![error])?, false);
+
+    let library = collect_source_files(Path::new("src")).map_err(|error| vec![error])?;
+    let test_files = collect_source_files(Path::new("tests")).map_err(|error| vec![error])?;
+
+    let mut test_cases = vec![];
+    for (source_file, loaded_file) in library.iter().zip(load(&library, &lint_registry, MessageFormat::Human, &[])?) {
+        test_cases.extend(collect_tests(&source_file.path, &loaded_file.ast));
+    }
+
+    let mut errors = vec![];
+    for test_file in &test_files {
+        match load_with_library(&library, test_file, &lint_registry, MessageFormat::Human) {
+            Ok(loaded_files) => test_cases.extend(collect_tests(&test_file.path, &loaded_files[library.len()].ast)),
+            Err(file_errors) => errors.extend(file_errors),
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    let total_count = test_cases.len();
+    test_cases.retain(|test_case| filter.as_ref().is_none_or(|filter| test_case.name.contains(filter.as_str())));
+    let filtered_count = total_count - test_cases.len();
+
+    println!("running {} {}", test_cases.len(), if test_cases.len() == 1 { "test" } else { "tests" });
+
+    let executable = env::current_exe().map_err(|error| vec![Box::from(error)])?;
+
+    let mut failures = vec![];
+    for test_case in &test_cases {
+        let target = format!("{}::{}", test_case.source_path.display(), test_case.function_name);
+        let output = Command::new(&executable).args(["test", "--run-test", &target]).output().map_err(|error| vec![Box::from(error)])?;
+
+        if output.status.success() {
+            println!("test {} ... ok", test_case.name);
+        } else {
+            println!("test {} ... FAILED", test_case.name);
+
+            failures.push(TestFailure {
+                name: test_case.name.clone(),
+                output: format!("{}{}", String::from_utf8_lossy(&output.stdout), String::from_utf8_lossy(&output.stderr)),
+            });
+        }
+    }
+
+    if !failures.is_empty() {
+        println!();
+        println!("failures:");
+
+        for failure in &failures {
+            println!();
+            println!("---- {} ----", failure.name);
+            print!("{}", failure.output);
+        }
+    }
+
+    let result = if failures.is_empty() { "ok" } else { "FAILED" };
+    let passed_count = test_cases.len() - failures.len();
+
+    println!();
+    println!("test result: {}. {} passed; {} failed; {} filtered out", result, passed_count, failures.len(), filtered_count);
+
+    if !failures.is_empty() {
+        return Err(vec![Box::from(format!("{} of {} tests failed", failures.len(), test_cases.len()))]);
+    }
+
+    Ok(())
+}
+
+fn collect_tests(source_path: &Path, ast: &Node) -> Vec<TestCase> {
+    let file_node = match ast {
+        Node::File(file_node) => file_node,
+        _ => return vec![],
+    };
+
+    file_node.functions.iter()
+        .filter(|function| function.is_test())
+        .map(|function| {
+            let function_name = function.identifier_node.identifier_token.name.clone();
+
+            TestCase {
+                name: qualified_name(source_path, &function_name),
+                source_path: source_path.to_path_buf(),
+                function_name,
+            }
+        })
+        .collect()
+}
+
+pub fn qualified_name(source_path: &Path, function_name: &str) -> String {
+    let module_name = source_path.with_extension("").display().to_string().replace(['/', '\\'], "::");
+
+    format!("{}::{}", module_name, function_name)
+}
+
+fn run_single_test(target: &str) -> Result<(), Vec<Box<dyn Error>>> {
+    let (source_path, function_name) = target.rsplit_once("::").ok_or(vec![Box::from(format!("Invalid test '{}'", target)) as Box<dyn Error>])?;
+
+    let lint_levels: Vec<(Lint, LintLevel)> = Lint::ALL.iter().map(|lint| (*lint, LintLevel::Allow)).collect();
+    let lint_registry = lint_registry(&lint_levels, false);
+
+    let library = collect_source_files(Path::new("src")).map_err(|error| vec![error])?;
+    let loaded_files = match library.iter().any(|source_file| source_file.path == Path::new(source_path)) {
+        true => load(&library, &lint_registry, MessageFormat::Human, &[])?,
+        false => load_with_library(&library, &SourceFile::new(Path::new(source_path)), &lint_registry, MessageFormat::Human)?,
+    };
+
+    let mir_modules: Vec<MirModule> = loaded_files.iter().map(|loaded_file| lower(&loaded_file.ast, &loaded_file.name, &loaded_file.source, true)).collect();
+
+    let context = Context::create();
+    let mut codegen = CodeGenerator::new(&context);
+    codegen.add_build_in_functions(&host_triple());
+    codegen.build_all(&mir_modules);
+
+    Target::initialize_native(&InitializationConfig::default()).map_err(|error| vec![Box::from(error)])?;
+    let execution_engine = codegen.module.create_jit_execution_engine(OptimizationLevel::None).map_err(|error| vec![Box::from(error.to_string())])?;
+
+    let test_function = unsafe { execution_engine.get_function::<unsafe extern "C" fn()>(function_name) }.map_err(|error| vec![Box::from(error.to_string())])?;
+
+    unsafe { test_function.call() };
+
+    Ok(())
+}
\ No newline at end of file
@@ -0,0 +1,120 @@
+mod common;
+
+use std::process::Command;
+use crate::common::{has_tool, stderr, stdout, Sandbox};
+
+const MAIN: &str = "function main(arguments: args): i32 {
+    let sum = 0
+    if len(arguments) > 0 {
+        sum += parse_i32(get(arguments, 0))
+    }
+    if arg_count() > 1 {
+        sum += parse_i32(arg(1))
+    }
+    print(sum)
+    return len(arguments)
+}
+";
+
+const ENVIRONMENT: &str = "function main(): i32 {
+    let greeting = env(\"AXIOM_GREETING\")
+    print_str(greeting)
+    print_str(env(\"AXIOM_UNSET\"))
+    print_str(arg(0))
+    return str_eq(greeting, \"héllo \\\"world\\\"\") && str_eq(env(\"AXIOM_UNSET\"), \"\") ? 0 : 1
+}
+";
+
+#[test]
+fn main_receives_arguments() {
+    let sandbox = Sandbox::new("arguments");
+    sandbox.write("main.axiom", MAIN);
+
+    let output = sandbox.axiom(&["run", "main.axiom", "--", "40", "2"]);
+
+    assert_eq!(output.status.code(), Some(2), "{}", stderr(&output));
+    assert_eq!(stdout(&output), "42\n");
+}
+
+#[test]
+fn invalid_argument_panics() {
+    let sandbox = Sandbox::new("arguments-invalid");
+    sandbox.write("main.axiom", MAIN);
+
+    let output = sandbox.axiom(&["run", "main.axiom", "--", "forty"]);
+
+    assert_eq!(output.status.code(), Some(101));
+    assert!(stderr(&output).contains("string is not a valid i32"), "{}", stderr(&output));
+}
+
+#[test]
+fn argument_index_out_of_range_panics() {
+    let sandbox = Sandbox::new("arguments-out-of-range");
+    sandbox.write("main.axiom", "function main(): i32 {
+    print_str(arg(0))
+    return 0
+}
+");
+
+    let output = sandbox.axiom(&["run", "main.axiom"]);
+
+    assert_eq!(output.status.code(), Some(101));
+    assert!(stderr(&output).contains("argument index out of range"), "{}", stderr(&output));
+}
+
+#[test]
+fn main_signatures_are_validated() {
+    let sandbox = Sandbox::new("arguments-signatures");
+
+    for parameters in ["", "argument_count: i32", "arguments: args"] {
+        sandbox.write("main.axiom", &format!("function main({}): i32 {{\n    return 0\n}}\n", parameters));
+
+        let output = sandbox.axiom(&["check", "main.axiom"]);
+        assert!(output.status.success(), "{}: {}", parameters, stderr(&output));
+    }
+
+    for parameters in ["name: str", "arguments: args, count: i32"] {
+        sandbox.write("main.axiom", &format!("function main({}): i32 {{\n    return 0\n}}\n", parameters));
+
+        let output = sandbox.axiom(&["check", "main.axiom"]);
+        assert!(!output.status.success(), "{}", parameters);
+        assert!(stderr(&output).contains("error[E0014]: Invalid signature for main"), "{}: {}", parameters, stderr(&output));
+    }
+}
+
+#[test]
+fn env_reads_environment_variables() {
+    let sandbox = Sandbox::new("environment");
+    sandbox.write("main.axiom", ENVIRONMENT);
+
+    let output = sandbox.axiom_with_environment(&["run", "main.axiom", "--", "raw argument"], &[("AXIOM_GREETING", "héllo \"world\"")]);
+
+    assert_eq!(output.status.code(), Some(0), "{}", stderr(&output));
+    assert_eq!(stdout(&output), "héllo \"world\"\n\nraw argument\n");
+}
+
+#[test]
+fn c_backend_reads_arguments_and_environment() {
+    if !has_tool("cc") {
+        eprintln!("skipping c_backend_reads_arguments_and_environment: 'cc' not found");
+        return;
+    }
+
+    let sandbox = Sandbox::new("arguments-c");
+    sandbox.write("main.axiom", MAIN);
+    sandbox.write("environment.axiom", ENVIRONMENT);
+
+    let output = sandbox.axiom(&["build", "main.axiom", "--backend", "c", "-o", "main"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+
+    let output = Command::new(sandbox.path("main")).args(["40", "2"]).output().unwrap();
+    assert_eq!(output.status.code(), Some(2), "{}", stderr(&output));
+    assert_eq!(stdout(&output), "42\n");
+
+    let output = sandbox.axiom(&["build", "environment.axiom", "--backend", "c", "-o", "environment"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+
+    let output = Command::new(sandbox.path("environment")).arg("raw argument").env("AXIOM_GREETING", "héllo \"world\"").env_remove("AXIOM_UNSET").output().unwrap();
+    assert_eq!(output.status.code(), Some(0), "{}", stderr(&output));
+    assert_eq!(stdout(&output), "héllo \"world\"\n\nraw argument\n");
+}
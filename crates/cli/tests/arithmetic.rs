@@ -0,0 +1,67 @@
+mod common;
+
+use crate::common::{stderr, stdout, Sandbox};
+
+#[test]
+fn checked_arithmetic_returns_flag_and_result() {
+    let sandbox = Sandbox::new("checked");
+    sandbox.write("main.axiom", "function main(): i32 {
+    let (overflowed, sum) = checked_add(2147483647, 1)
+    print(overflowed ? 1 : 0)
+    print(sum)
+    let (overflowed, difference) = checked_sub(10, 3)
+    print(overflowed ? 1 : 0)
+    print(difference)
+    let (_, product) = checked_mul(6, 7)
+    return product
+}
+");
+
+    let output = sandbox.axiom(&["run", "main.axiom"]);
+
+    assert_eq!(output.status.code(), Some(42), "{}", stderr(&output));
+    assert_eq!(stdout(&output), "1\n-2147483648\n0\n7\n");
+}
+
+#[test]
+fn checked_arithmetic_must_be_destructured() {
+    let sandbox = Sandbox::new("checked-binding");
+    sandbox.write("main.axiom", "function main(): i32 {
+    let sum = checked_add(1, 2)
+    return 0
+}
+");
+
+    let output = sandbox.axiom(&["check", "main.axiom"]);
+
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("error[E0016]: Cannot bind a value of type (bool, i32) to 1 name"), "{}", stderr(&output));
+}
+
+const GUARDED_DIVISION: &str = "function above_one(n: i32, d: i32): bool {
+    return d != 0 && n / d > 1
+}
+
+function not_above_one(n: i32, d: i32): bool {
+    return d == 0 || n / d <= 1
+}
+
+function main(): i32 {
+    print(above_one(10, 0) ? 1 : 0)
+    print(not_above_one(10, 0) ? 1 : 0)
+    print(above_one(10, 2) ? 1 : 0)
+    return above_one(1, 0) ? 1 : 10 / (1 - 1)
+}
+";
+
+#[test]
+fn logical_operators_short_circuit() {
+    let sandbox = Sandbox::new("short-circuit");
+    sandbox.write("main.axiom", GUARDED_DIVISION);
+
+    let output = sandbox.axiom(&["run", "main.axiom"]);
+
+    assert_eq!(output.status.code(), Some(101));
+    assert_eq!(stdout(&output), "0\n1\n1\n");
+    assert!(stderr(&output).contains("panicked at main.axiom:13:34: attempt to divide by zero"), "{}", stderr(&output));
+}
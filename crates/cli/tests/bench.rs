@@ -0,0 +1,80 @@
+mod common;
+
+use crate::common::{stderr, stdout, Sandbox};
+
+fn bench_project(name: &str) -> Sandbox {
+    let sandbox = Sandbox::project(name, "Lib");
+    sandbox.write("src/lib.axiom", "function square(value: i32): i32 {
+    return value * value
+}
+");
+    sandbox.write("benches/square.axiom", "function bench_square(): i32 {
+    return square(7)
+}
+");
+
+    sandbox
+}
+
+#[test]
+fn saves_baseline() {
+    let sandbox = bench_project("bench-baseline");
+
+    let output = sandbox.axiom(&["bench", "--warmup", "1", "--iterations", "3", "--save-baseline"]);
+
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(stdout(&output).starts_with("bench benches::square::bench_square ... mean "), "{}", stdout(&output));
+    assert!(stdout(&output).ends_with("baseline saved to build/bench-baseline.json\n"), "{}", stdout(&output));
+
+    let baseline: serde_json::Value = serde_json::from_str(&sandbox.read("build/bench-baseline.json")).unwrap();
+    assert_eq!(baseline["version"], 1);
+    assert!(baseline["benchmarks"]["benches::square::bench_square"]["median_ns"].is_number(), "{}", baseline);
+}
+
+#[test]
+fn fails_on_regression() {
+    let sandbox = bench_project("bench-regression");
+    sandbox.write("build/bench-baseline.json", r#"{"version":1,"benchmarks":{"benches::square::bench_square":{"mean_ns":0.0001,"median_ns":0.0001,"stddev_ns":0.0}}}"#);
+
+    let output = sandbox.axiom(&["bench", "--warmup", "1", "--iterations", "3", "--baseline"]);
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stdout(&output).contains("against the baseline, regressed)"), "{}", stdout(&output));
+    assert_eq!(stderr(&output), "1 benchmark regressed by more than 5%: benches::square::bench_square\n");
+}
+
+#[test]
+fn filter_selects_benchmarks() {
+    let sandbox = bench_project("bench-filter");
+
+    let output = sandbox.axiom(&["bench", "missing"]);
+
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(stdout(&output), "no benchmarks found\n");
+}
+
+#[test]
+fn rejects_benchmarks_returning_other_types() {
+    let sandbox = bench_project("bench-return-type");
+    sandbox.write("benches/flag.axiom", "function bench_flag(): bool {
+    return 1 < 2
+}
+");
+
+    let output = sandbox.axiom(&["bench"]);
+
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(stdout(&output), "");
+    assert_eq!(stderr(&output), "Benchmark 'benches::flag::bench_flag' must return i32 or nothing, found bool\n");
+}
+
+#[test]
+fn zero_baseline_is_not_compared() {
+    let sandbox = bench_project("bench-zero-baseline");
+    sandbox.write("build/bench-baseline.json", r#"{"version":1,"benchmarks":{"benches::square::bench_square":{"mean_ns":0.0,"median_ns":0.0,"stddev_ns":0.0}}}"#);
+
+    let output = sandbox.axiom(&["bench", "--warmup", "1", "--iterations", "3", "--baseline"]);
+
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(stdout(&output).ends_with(" (not compared, the baseline median is 0 ns)\n"), "{}", stdout(&output));
+}
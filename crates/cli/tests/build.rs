@@ -0,0 +1,81 @@
+mod common;
+
+use crate::common::{stderr, Sandbox};
+
+const MAIN: &str = "function main(): i32 {
+    return 0
+}
+";
+
+#[test]
+fn llvm_ir_has_no_startup_shim() {
+    let sandbox = Sandbox::new("emit-llvm-ir");
+    sandbox.write("main.axiom", MAIN);
+
+    let output = sandbox.axiom(&["build", "main.axiom", "--emit", "llvm-ir", "-o", "program"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+
+    let llvm_ir = sandbox.read("program.ll");
+    assert!(llvm_ir.contains("@__axiom_main("), "{}", llvm_ir);
+    assert!(!llvm_ir.contains("@main("), "{}", llvm_ir);
+}
+
+#[test]
+fn linked_executable_has_startup_shim() {
+    let sandbox = Sandbox::new("link");
+    sandbox.write("main.axiom", "function main(): i32 {
+    return arg_count()
+}
+");
+
+    let output = sandbox.axiom(&["build", "main.axiom", "-o", "program"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+
+    let status = std::process::Command::new(sandbox.path("program")).args(["first", "second"]).status().unwrap();
+    assert_eq!(status.code(), Some(2));
+}
+
+const ELF_MAGIC: &[u8] = b"\x7fELF";
+const WASM_MAGIC: &[u8] = b"\0asm";
+
+/// Reads the word size and `e_machine` from an ELF header, which every supported target but WebAssembly produces.
+fn elf_machine(object: &[u8]) -> (u8, u16) {
+    assert_eq!(&object[..4], ELF_MAGIC);
+
+    let bits = if object[4] == 1 { 32 } else { 64 };
+    let machine = [object[18], object[19]];
+    match object[5] {
+        1 => (bits, u16::from_le_bytes(machine)),
+        _ => (bits, u16::from_be_bytes(machine)),
+    }
+}
+
+#[test]
+fn emits_objects_for_cross_targets() {
+    let targets = [
+        ("x86_64-unknown-linux-gnu", (64, 62)),
+        ("i686-unknown-linux-gnu", (32, 3)),
+        ("aarch64-unknown-linux-gnu", (64, 183)),
+        ("armv7-unknown-linux-gnueabihf", (32, 40)),
+        ("thumbv7em-none-eabi", (32, 40)),
+        ("riscv64-unknown-linux-gnu", (64, 243)),
+        ("riscv32-unknown-none-elf", (32, 243)),
+    ];
+
+    let sandbox = Sandbox::new("cross-targets");
+    sandbox.write("main.axiom", MAIN);
+
+    for (target, machine) in targets {
+        let output = sandbox.axiom(&["build", "main.axiom", "--emit", "obj", "--target", target, "-o", target]);
+        assert!(output.status.success(), "{}: {}", target, stderr(&output));
+
+        let object = std::fs::read(sandbox.path(&format!("{}.o", target))).unwrap();
+        assert_eq!(elf_machine(&object), machine, "{}", target);
+    }
+
+    let output = sandbox.axiom(&["build", "main.axiom", "--emit", "obj", "--target", "wasm32-wasip1", "-o", "wasm32-wasip1"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+
+    let object = std::fs::read(sandbox.path("wasm32-wasip1.o")).unwrap();
+    assert_eq!(&object[..4], WASM_MAGIC);
+}
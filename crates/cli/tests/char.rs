@@ -0,0 +1,23 @@
+mod common;
+
+use crate::common::{stderr, stdout, Sandbox};
+
+#[test]
+fn ascii_classifiers_reject_other_characters() {
+    let sandbox = Sandbox::new("ascii-classifiers");
+    sandbox.write("main.axiom", "function main(): i32 {
+    print(is_ascii_alpha('é') ? 1 : 0)
+    print(is_ascii_alpha('Z') ? 1 : 0)
+    print(is_ascii_upper('É') ? 1 : 0)
+    print(is_ascii_lower('q') ? 1 : 0)
+    print(is_ascii_whitespace('\\u{A0}') ? 1 : 0)
+    print(is_ascii_alphanumeric('7') ? 1 : 0)
+    return is_ascii_digit('٣') ? 1 : 0
+}
+");
+
+    let output = sandbox.axiom(&["run", "main.axiom"]);
+
+    assert_eq!(output.status.code(), Some(0), "{}", stderr(&output));
+    assert_eq!(stdout(&output), "0\n1\n0\n1\n0\n1\n");
+}
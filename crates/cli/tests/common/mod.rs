@@ -0,0 +1,90 @@
+#![allow(dead_code)]
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command, Output};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use compiler::codegen::target::host_triple;
+
+static NEXT_SANDBOX: AtomicUsize = AtomicUsize::new(0);
+
+pub struct Sandbox {
+    pub directory: PathBuf,
+}
+
+impl Sandbox {
+    pub fn new(name: &str) -> Sandbox {
+        let index = NEXT_SANDBOX.fetch_add(1, Ordering::Relaxed);
+        let directory = env::temp_dir().join(format!("axiom-{}-{}-{}", name, process::id(), index));
+
+        if directory.exists() {
+            fs::remove_dir_all(&directory).unwrap();
+        }
+        fs::create_dir_all(&directory).unwrap();
+
+        Sandbox {
+            directory,
+        }
+    }
+
+    pub fn project(name: &str, project_type: &str) -> Sandbox {
+        let sandbox = Sandbox::new(name);
+        sandbox.write("axiom.toml", &format!("[package]\nname = \"{}\"\nversion = \"0.1.0\"\ntype = \"{}\"\n\n[build]\ntarget = \"{}\"\n", name, project_type, host_triple()));
+
+        sandbox
+    }
+
+    pub fn path(&self, path: &str) -> PathBuf {
+        self.directory.join(path)
+    }
+
+    pub fn write(&self, path: &str, content: &str) {
+        let path = self.path(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    pub fn read(&self, path: &str) -> String {
+        fs::read_to_string(self.path(path)).unwrap()
+    }
+
+    pub fn axiom(&self, args: &[&str]) -> Output {
+        self.axiom_in(Path::new("."), args)
+    }
+
+    pub fn axiom_in(&self, directory: &Path, args: &[&str]) -> Output {
+        Command::new(env!("CARGO_BIN_EXE_axiom"))
+            .args(args)
+            .current_dir(self.directory.join(directory))
+            .output()
+            .unwrap()
+    }
+
+    pub fn axiom_with_environment(&self, args: &[&str], variables: &[(&str, &str)]) -> Output {
+        Command::new(env!("CARGO_BIN_EXE_axiom"))
+            .args(args)
+            .envs(variables.iter().copied())
+            .current_dir(&self.directory)
+            .output()
+            .unwrap()
+    }
+}
+
+impl Drop for Sandbox {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.directory);
+    }
+}
+
+pub fn has_tool(name: &str) -> bool {
+    Command::new(name).arg("--version").output().is_ok()
+}
+
+pub fn stdout(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+pub fn stderr(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stderr).into_owned()
+}
@@ -0,0 +1,98 @@
+mod common;
+
+use crate::common::{stderr, stdout, Sandbox};
+
+fn documented_project(name: &str) -> Sandbox {
+    let sandbox = Sandbox::project(name, "Lib");
+    sandbox.write("src/lib.axiom", "/// Doubles a value.
+///
+/// Overflow panics when runtime checks are enabled.
+function double(value: i32): i32 {
+    return value * 2
+}
+
+#[test]
+function doubles() {
+    assert_eq(double(2), 4)
+}
+");
+    sandbox.write("src/math/sign.axiom", "function is_negative(value: i32): bool {
+    return double(value) < 0
+}
+");
+
+    sandbox
+}
+
+#[test]
+fn writes_markdown() {
+    let sandbox = documented_project("doc-markdown");
+
+    let output = sandbox.axiom(&["doc", "--format", "markdown"]);
+
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(stdout(&output), "Documentation written to docs/index.md\n");
+
+    assert_eq!(sandbox.read("docs/index.md"), "# doc-markdown
+
+## Modules
+
+- [lib](lib.md)
+- [math::sign](math.sign.md)
+
+## Functions
+
+- [`lib::double`](lib.md#double) `fn(i32) -> i32` - Doubles a value.
+- [`math::sign::is_negative`](math.sign.md#is_negative) `fn(i32) -> bool`
+");
+
+    assert_eq!(sandbox.read("docs/lib.md"), "# Module lib
+
+[Back to doc-markdown](index.md)
+
+## double
+
+```axiom
+function double(value: i32): i32
+```
+
+Type: `fn(i32) -> i32`
+
+Doubles a value.
+
+Overflow panics when runtime checks are enabled.
+");
+
+    assert_eq!(sandbox.read("docs/math.sign.md"), "# Module math::sign
+
+[Back to doc-markdown](index.md)
+
+## is_negative
+
+```axiom
+function is_negative(value: i32): bool
+```
+
+Type: `fn(i32) -> bool`
+");
+}
+
+#[test]
+fn writes_html() {
+    let sandbox = documented_project("doc-html");
+
+    let output = sandbox.axiom(&["doc", "-o", "site"]);
+
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(stdout(&output), "Documentation written to site/index.html\n");
+    assert!(sandbox.path("site/style.css").is_file());
+
+    let index = sandbox.read("site/index.html");
+    assert!(index.contains("<li><a href=\"math.sign.html\">math::sign</a></li>"), "{}", index);
+    assert!(index.contains("<li data-name=\"double\"><a href=\"lib.html#double\">lib::double</a> <code>fn(i32) -&gt; i32</code> <span class=\"summary\">Doubles a value.</span></li>"), "{}", index);
+    assert!(index.contains("<input id=\"search\""), "{}", index);
+    assert!(!index.contains("doubles"), "test functions must not be documented: {}", index);
+
+    let module = sandbox.read("site/lib.html");
+    assert!(module.contains("<section id=\"double\">\n<h2>double</h2>\n<pre><code>function double(value: i32): i32</code></pre>\n<p>Type: <code>fn(i32) -&gt; i32</code></p>\n<p>Doubles a value.</p>\n<p>Overflow panics when runtime checks are enabled.</p>\n</section>"), "{}", module);
+}
@@ -0,0 +1,23 @@
+mod common;
+
+use crate::common::{stderr, stdout, Sandbox};
+
+#[test]
+fn explains_known_code() {
+    let sandbox = Sandbox::new("explain");
+
+    let output = sandbox.axiom(&["explain", "E0007"]);
+
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(stdout(&output).starts_with("A function was called with the wrong number of arguments."), "{}", stdout(&output));
+}
+
+#[test]
+fn rejects_unknown_code() {
+    let sandbox = Sandbox::new("explain-unknown");
+
+    let output = sandbox.axiom(&["explain", "E9999"]);
+
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(stderr(&output), "'E9999' is not a known error code\n");
+}
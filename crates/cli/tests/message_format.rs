@@ -0,0 +1,24 @@
+mod common;
+
+use crate::common::{stderr, stdout, Sandbox};
+
+#[test]
+fn json_messages_stay_out_of_program_output() {
+    let sandbox = Sandbox::new("message-format");
+    sandbox.write("main.axiom", "function main(): i32 {
+    let unused = 1
+    print(42)
+    return 0
+}
+");
+
+    let output = sandbox.axiom(&["run", "main.axiom", "--message-format", "json"]);
+
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(stdout(&output), "42\n");
+
+    let messages: Vec<serde_json::Value> = stderr(&output).lines().map(|line| serde_json::from_str(line).unwrap()).collect();
+    assert_eq!(messages.len(), 1);
+    assert_eq!(messages[0]["kind"], "lint");
+    assert_eq!(messages[0]["code"], "unused_variables");
+}
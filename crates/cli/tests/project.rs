@@ -0,0 +1,137 @@
+mod common;
+
+use std::path::Path;
+use std::process::Command;
+use crate::common::{has_tool, stderr, stdout, Sandbox};
+
+const BIN: &str = "function main(): i32 {
+    print(square(offset()))
+    return square(3)
+}
+
+function offset(): i32 {
+    return 4
+}
+";
+
+const MATH: &str = "function square(n: i32): i32 {
+    return n * n
+}
+";
+
+fn multi_file_project(name: &str) -> Sandbox {
+    let sandbox = Sandbox::project(name, "Bin");
+    sandbox.write("src/bin.axiom", BIN);
+    sandbox.write("src/math/square.axiom", MATH);
+
+    sandbox
+}
+
+#[test]
+fn run_finds_project_from_subdirectory() {
+    let sandbox = multi_file_project("run-subdirectory");
+
+    let output = sandbox.axiom_in(Path::new("src/math"), &["run"]);
+
+    assert_eq!(output.status.code(), Some(9), "{}", stderr(&output));
+    assert_eq!(stdout(&output), "16\n");
+}
+
+#[test]
+fn check_analyzes_files_together() {
+    let sandbox = multi_file_project("check-multiple-files");
+
+    let output = sandbox.axiom_in(Path::new("src"), &["check"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+
+    sandbox.write("src/math/square.axiom", "function square(n: i32): i32 {
+    return offset() * n
+}
+
+function offset(): i32 {
+    return 1
+}
+");
+
+    let output = sandbox.axiom(&["check"]);
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("src/math/square.axiom"), "{}", stderr(&output));
+    assert!(stderr(&output).contains("Duplicated identifier: offset"), "{}", stderr(&output));
+    assert!(stderr(&output).contains("declared in another file"), "{}", stderr(&output));
+}
+
+#[test]
+fn unused_functions_are_found_across_files() {
+    let sandbox = Sandbox::project("unused-across-files", "Bin");
+    sandbox.write("src/bin.axiom", "function main(): i32 {
+    return square(3)
+}
+
+function offset(): i32 {
+    return 4
+}
+");
+    sandbox.write("src/math/square.axiom", "function square(n: i32): i32 {
+    return n * n + offset()
+}
+
+function cube(n: i32): i32 {
+    return n * n * n
+}
+");
+
+    let output = sandbox.axiom(&["check"]);
+
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(stderr(&output).contains("Unused function: cube"), "{}", stderr(&output));
+    assert!(!stderr(&output).contains("Unused function: offset"), "{}", stderr(&output));
+    assert!(!stderr(&output).contains("Unused function: square"), "{}", stderr(&output));
+}
+
+#[test]
+fn parameters_shadow_names_from_outer_scopes() {
+    let sandbox = Sandbox::project("parameter-shadowing", "Bin");
+    sandbox.write("src/bin.axiom", "function main(arg_count: i32): i32 {
+    return scale(arg_count) + value()
+}
+
+function value(): i32 {
+    return 2
+}
+");
+    sandbox.write("src/math/scale.axiom", "function scale(value: i32): i32 {
+    return value * 10
+}
+");
+
+    let output = sandbox.axiom(&["run", "--", "argument"]);
+    assert_eq!(output.status.code(), Some(12), "{}", stderr(&output));
+
+    sandbox.write("src/math/scale.axiom", "function scale(value: i32, value: i32): i32 {
+    return value * 10
+}
+");
+
+    let output = sandbox.axiom(&["check"]);
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("Duplicated identifier: value"), "{}", stderr(&output));
+}
+
+#[test]
+fn build_compiles_every_source_file() {
+    if !has_tool("cc") {
+        eprintln!("skipping build_compiles_every_source_file: 'cc' not found");
+        return;
+    }
+
+    for backend in ["llvm", "c"] {
+        let sandbox = multi_file_project(&format!("build-multiple-files-{}", backend));
+
+        let output = sandbox.axiom_in(Path::new("src/math"), &["build", "--backend", backend]);
+        assert!(output.status.success(), "{}", stderr(&output));
+
+        let output = Command::new(sandbox.path(&format!("build/build-multiple-files-{}", backend))).output().unwrap();
+        assert_eq!(output.status.code(), Some(9));
+        assert_eq!(stdout(&output), "16\n");
+    }
+}
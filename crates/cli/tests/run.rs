@@ -0,0 +1,50 @@
+mod common;
+
+use crate::common::{stderr, stdout, Sandbox};
+
+const PROGRAM: &str = "function main(): i32 {
+    print(3)
+    return 42
+}
+";
+
+#[test]
+fn run_exits_with_the_value_of_main() {
+    let sandbox = Sandbox::new("run-exit-code");
+    sandbox.write("main.axiom", PROGRAM);
+    sandbox.write("zero.axiom", "function main(): i32 {
+    return 0
+}
+");
+
+    let output = sandbox.axiom(&["run", "main.axiom"]);
+
+    assert_eq!(output.status.code(), Some(42), "{}", stderr(&output));
+    assert_eq!(stdout(&output), "3\n");
+    assert_eq!(stderr(&output), "");
+
+    let output = sandbox.axiom(&["run", "zero.axiom"]);
+
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(stdout(&output), "");
+}
+
+#[test]
+fn run_dumps_every_stage_before_the_program_output() {
+    let sandbox = Sandbox::new("run-dump");
+    sandbox.write("main.axiom", PROGRAM);
+
+    let output = sandbox.axiom(&["run", "main.axiom", "--dump", "tokens,ast,mir,ir"]);
+    assert_eq!(output.status.code(), Some(42), "{}", stderr(&output));
+
+    let stdout = stdout(&output);
+    let sections: Vec<usize> = ["Tokens: ", "AST: ", "MIR: ", "LLVM IR: "].iter()
+        .map(|header| stdout.find(&format!("{}\n", header)).unwrap_or_else(|| panic!("missing '{}' in:\n{}", header, stdout)))
+        .collect();
+
+    assert!(sections.windows(2).all(|pair| pair[0] < pair[1]), "{}", stdout);
+    assert!(stdout[sections[0]..sections[1]].contains("Number(NumberToken { value: \"42\""), "{}", stdout);
+    assert!(stdout[sections[2]..sections[3]].contains("function main"), "{}", stdout);
+    assert!(stdout[sections[3]..].contains("define i32 @main"), "{}", stdout);
+    assert!(stdout.ends_with("\n3\n"), "{}", stdout);
+}
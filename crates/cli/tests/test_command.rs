@@ -0,0 +1,101 @@
+mod common;
+
+use crate::common::{stderr, stdout, Sandbox};
+
+const LIBRARY: &str = "function double(value: i32): i32 {
+    return value * 2
+}
+
+#[test]
+function doubles() {
+    assert_eq(double(21), 42)
+}
+";
+
+#[test]
+fn passing_tests_exit_successfully() {
+    let sandbox = Sandbox::project("tests-pass", "Lib");
+    sandbox.write("src/lib.axiom", LIBRARY);
+
+    let output = sandbox.axiom(&["test"]);
+
+    assert!(output.status.success(), "{}{}", stdout(&output), stderr(&output));
+    assert!(stdout(&output).contains("test src::lib::doubles ... ok"), "{}", stdout(&output));
+    assert!(stdout(&output).contains("test result: ok. 1 passed; 0 failed; 0 filtered out"), "{}", stdout(&output));
+}
+
+#[test]
+fn failing_tests_exit_with_failure() {
+    let sandbox = Sandbox::project("tests-fail", "Lib");
+    sandbox.write("src/lib.axiom", LIBRARY);
+    sandbox.write("tests/broken.axiom", "#[test]
+function breaks() {
+    assert(1 > 2)
+}
+");
+
+    let output = sandbox.axiom(&["test"]);
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stdout(&output).contains("test tests::broken::breaks ... FAILED"), "{}", stdout(&output));
+    assert!(stdout(&output).contains("panicked at tests/broken.axiom:3:5: assertion failed: assert(1 > 2)"), "{}", stdout(&output));
+    assert!(stdout(&output).contains("test result: FAILED. 1 passed; 1 failed; 0 filtered out"), "{}", stdout(&output));
+    assert!(stderr(&output).contains("1 of 2 tests failed"), "{}", stderr(&output));
+}
+
+#[test]
+fn filter_selects_tests() {
+    let sandbox = Sandbox::project("tests-filter", "Lib");
+    sandbox.write("src/lib.axiom", LIBRARY);
+    sandbox.write("tests/broken.axiom", "#[test]
+function breaks() {
+    assert(1 > 2)
+}
+");
+
+    let output = sandbox.axiom(&["test", "doubles"]);
+
+    assert!(output.status.success(), "{}{}", stdout(&output), stderr(&output));
+    assert!(stdout(&output).contains("test result: ok. 1 passed; 0 failed; 1 filtered out"), "{}", stdout(&output));
+}
+
+#[test]
+fn test_files_call_into_src() {
+    let sandbox = Sandbox::project("tests-call-src", "Lib");
+    sandbox.write("src/lib.axiom", LIBRARY);
+    sandbox.write("src/math/halve.axiom", "function halve(value: i32): i32 {
+    return double(value) / 4
+}
+");
+    sandbox.write("tests/arithmetic.axiom", "function quadruple(value: i32): i32 {
+    return double(double(value))
+}
+
+#[test]
+function quadruples() {
+    assert_eq(quadruple(3), 12)
+}
+
+#[test]
+function halves() {
+    assert_eq(halve(8), 4)
+}
+");
+    sandbox.write("tests/other.axiom", "function quadruple(value: i32): i32 {
+    return value * 4
+}
+
+#[test]
+function quadruples_again() {
+    assert_eq(quadruple(halve(2)), 4)
+}
+");
+
+    let output = sandbox.axiom(&["test"]);
+
+    assert!(output.status.success(), "{}{}", stdout(&output), stderr(&output));
+    assert!(stdout(&output).contains("test tests::arithmetic::quadruples ... ok"), "{}", stdout(&output));
+    assert!(stdout(&output).contains("test tests::arithmetic::halves ... ok"), "{}", stdout(&output));
+    assert!(stdout(&output).contains("test tests::other::quadruples_again ... ok"), "{}", stdout(&output));
+    assert!(stdout(&output).contains("test result: ok. 4 passed; 0 failed; 0 filtered out"), "{}", stdout(&output));
+}
@@ -0,0 +1,138 @@
+mod common;
+
+use wasmi::{Caller, Engine, Extern, Linker, Memory, Module, Store};
+use crate::common::{has_tool, stderr, Sandbox};
+
+struct Wasi {
+    args: Vec<String>,
+    environment: Vec<String>,
+    stdout: Vec<u8>,
+}
+
+fn memory(caller: &Caller<'_, Wasi>) -> Memory {
+    caller.get_export("memory").and_then(Extern::into_memory).expect("module must export its memory")
+}
+
+fn read_u32(caller: &Caller<'_, Wasi>, memory: Memory, address: i32) -> u32 {
+    let mut bytes = [0; 4];
+    memory.read(caller, address as usize, &mut bytes).unwrap();
+
+    u32::from_le_bytes(bytes)
+}
+
+fn write_u32(caller: &mut Caller<'_, Wasi>, memory: Memory, address: i32, value: u32) {
+    memory.write(caller, address as usize, &value.to_le_bytes()).unwrap();
+}
+
+/// Adds the `<name>_sizes_get` and `<name>_get` pair WASI uses for both arguments and the environment.
+fn add_string_list(linker: &mut Linker<Wasi>, name: &str, strings: fn(&Wasi) -> &Vec<String>) {
+    linker.func_wrap("wasi_snapshot_preview1", &format!("{}_sizes_get", name), move |mut caller: Caller<'_, Wasi>, count_address: i32, buffer_size_address: i32| -> i32 {
+        let memory = memory(&caller);
+        let count = strings(caller.data()).len() as u32;
+        let buffer_size = strings(caller.data()).iter().map(|string| string.len() as u32 + 1).sum();
+
+        write_u32(&mut caller, memory, count_address, count);
+        write_u32(&mut caller, memory, buffer_size_address, buffer_size);
+
+        0
+    }).unwrap();
+
+    linker.func_wrap("wasi_snapshot_preview1", &format!("{}_get", name), move |mut caller: Caller<'_, Wasi>, values_address: i32, buffer_address: i32| -> i32 {
+        let memory = memory(&caller);
+        let values = strings(caller.data()).clone();
+
+        let mut offset = buffer_address;
+        for (index, value) in values.iter().enumerate() {
+            write_u32(&mut caller, memory, values_address + index as i32 * 4, offset as u32);
+
+            let mut bytes = value.as_bytes().to_vec();
+            bytes.push(0);
+            memory.write(&mut caller, offset as usize, &bytes).unwrap();
+
+            offset += bytes.len() as i32;
+        }
+
+        0
+    }).unwrap();
+}
+
+fn add_wasi(linker: &mut Linker<Wasi>) {
+    add_string_list(linker, "args", |wasi| &wasi.args);
+    add_string_list(linker, "environ", |wasi| &wasi.environment);
+
+    linker.func_wrap("wasi_snapshot_preview1", "fd_write", |mut caller: Caller<'_, Wasi>, file_descriptor: i32, iovecs_address: i32, iovecs_length: i32, written_address: i32| -> i32 {
+        let memory = memory(&caller);
+
+        let mut written = vec![];
+        for index in 0..iovecs_length {
+            let buffer_address = read_u32(&caller, memory, iovecs_address + index * 8);
+            let buffer_length = read_u32(&caller, memory, iovecs_address + index * 8 + 4);
+
+            let mut bytes = vec![0; buffer_length as usize];
+            memory.read(&caller, buffer_address as usize, &mut bytes).unwrap();
+            written.extend(bytes);
+        }
+
+        write_u32(&mut caller, memory, written_address, written.len() as u32);
+
+        if file_descriptor == 1 {
+            caller.data_mut().stdout.extend(written);
+        }
+
+        0
+    }).unwrap();
+
+    linker.func_wrap("wasi_snapshot_preview1", "proc_exit", |_: Caller<'_, Wasi>, exit_code: i32| -> Result<(), wasmi::Error> {
+        Err(wasmi::Error::i32_exit(exit_code))
+    }).unwrap();
+}
+
+fn run_wasm(wasm: &[u8], args: &[&str], environment: &[&str]) -> (i32, String) {
+    let engine = Engine::default();
+    let module = Module::new(&engine, wasm).unwrap();
+
+    let mut linker = Linker::new(&engine);
+    add_wasi(&mut linker);
+
+    let mut store = Store::new(&engine, Wasi {
+        args: args.iter().map(|arg| arg.to_string()).collect(),
+        environment: environment.iter().map(|variable| variable.to_string()).collect(),
+        stdout: vec![],
+    });
+
+    let instance = linker.instantiate(&mut store, &module).unwrap().start(&mut store).unwrap();
+    let start = instance.get_typed_func::<(), ()>(&store, "_start").unwrap();
+
+    let error = start.call(&mut store, ()).expect_err("_start must end through proc_exit");
+    let exit_code = error.i32_exit_status().unwrap_or_else(|| panic!("_start trapped: {}", error));
+
+    (exit_code, String::from_utf8(store.into_data().stdout).unwrap())
+}
+
+#[test]
+fn runs_wasi_module() {
+    if !has_tool("wasm-ld") {
+        eprintln!("skipping runs_wasi_module: 'wasm-ld' not found");
+        return;
+    }
+
+    let sandbox = Sandbox::new("wasm");
+    sandbox.write("main.axiom", "function main(arguments: args): i32 {
+    print(parse_i32(get(arguments, 0)) * parse_i32(arg(1)))
+    print(len(arguments))
+    print_str(env(\"GREETING\"))
+    print_str(env(\"GREET\"))
+    print_str(env(\"GREETINGS\"))
+    return str_eq(arg(1), \"7\") ? 3 : 4
+}
+");
+
+    let output = sandbox.axiom(&["build", "main.axiom", "--target", "wasm32-wasi", "-o", "program"]);
+    assert!(output.status.success(), "{}", stderr(&output));
+
+    let wasm = std::fs::read(sandbox.path("program.wasm")).unwrap();
+    let (exit_code, stdout) = run_wasm(&wasm, &["program", "6", "7"], &["GREETINGS=plural", "GREETING=hello=world"]);
+
+    assert_eq!(exit_code, 3);
+    assert_eq!(stdout, "42\n2\nhello=world\n\nplural\n");
+}
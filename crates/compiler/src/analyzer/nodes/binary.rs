@@ -16,8 +16,18 @@ impl Analyzer for BinaryNode {
             errors.push(AxiomError::WrongDataType(self.right.location(), Box::from(left_data_type.clone()), Box::from(right_data_type.clone())))
         }
 
+        if matches!(self.operation_type, BinaryType::Modulo | BinaryType::Exponentiation) && *left_data_type != DataType::I32 {
+            errors.push(AxiomError::WrongDataType(self.left.location(), Box::from(DataType::I32), Box::from(left_data_type.clone())))
+        }
+
+        let is_comparison = matches!(self.operation_type, BinaryType::Equal | BinaryType::NotEqual | BinaryType::GreaterThan | BinaryType::LessThan | BinaryType::GreaterThanOrEqual | BinaryType::LessThanOrEqual);
+
+        if matches!(left_data_type, DataType::Str | DataType::Args) || (!is_comparison && *left_data_type == DataType::Char) {
+            errors.push(AxiomError::WrongDataType(self.left.location(), Box::from(DataType::I32), Box::from(left_data_type.clone())))
+        }
+
         match self.operation_type {
-            BinaryType::Addition | BinaryType::Subtraction | BinaryType::Multiplication | BinaryType::Division | BinaryType::ShiftLeft | BinaryType::ShiftRight | BinaryType::BitwiseAnd | BinaryType::BitwiseOr | BinaryType::Or | BinaryType::And => self.data_type = left_data_type.clone(),
+            BinaryType::Addition | BinaryType::Subtraction | BinaryType::Multiplication | BinaryType::Division | BinaryType::Modulo | BinaryType::Exponentiation | BinaryType::ShiftLeft | BinaryType::ShiftRight | BinaryType::BitwiseAnd | BinaryType::BitwiseOr | BinaryType::BitwiseXor | BinaryType::Or | BinaryType::And => self.data_type = left_data_type.clone(),
 
             BinaryType::Equal | BinaryType::NotEqual | BinaryType::GreaterThan | BinaryType::LessThan | BinaryType::GreaterThanOrEqual | BinaryType::LessThanOrEqual => self.data_type = DataType::Bool
         }
@@ -2,6 +2,7 @@ use crate::analyzer::Analyzer;
 use crate::ast::{DeclarationNode};
 use crate::datatype::DataType;
 use crate::error::AxiomError;
+use crate::error::location::Location;
 use crate::utils::SymbolTable;
 
 impl Analyzer for DeclarationNode {
@@ -10,6 +11,15 @@ impl Analyzer for DeclarationNode {
 
         let expression_data_type = self.expression.data_type();
 
+        if self.identifier_node.identifier_token.is_discard() {
+            self.identifier_node.data_type = expression_data_type.clone();
+            return;
+        }
+
+        if matches!(expression_data_type, DataType::Tuple(_)) {
+            errors.push(AxiomError::InvalidDestructuring(self.location(), Box::from(expression_data_type.clone()), 1));
+        }
+
         symbol_table.add(self.identifier_node.identifier_token.name.clone(), expression_data_type.clone());
         
         self.identifier_node.analyze(symbol_table, errors);
@@ -0,0 +1,37 @@
+use crate::analyzer::Analyzer;
+use crate::ast::{DestructuringNode, Node};
+use crate::datatype::DataType;
+use crate::error::AxiomError;
+use crate::error::location::Location;
+use crate::utils::SymbolTable;
+
+impl Analyzer for DestructuringNode {
+    fn analyze(&mut self, symbol_table: &mut SymbolTable<String, DataType>, errors: &mut Vec<AxiomError>) {
+        self.expression.analyze(symbol_table, errors);
+
+        if !matches!(*self.expression, Node::Call(_)) {
+            errors.push(AxiomError::SyntaxError(self.expression.location(), "Only the result of a call can be destructured".to_string()));
+            return;
+        }
+
+        let element_data_types = match self.expression.data_type() {
+            DataType::Tuple(element_data_types) if element_data_types.len() == self.identifier_nodes.len() => element_data_types.clone(),
+            DataType::ToBeInferred => return,
+            expression_data_type => {
+                errors.push(AxiomError::InvalidDestructuring(self.location(), Box::from(expression_data_type.clone()), self.identifier_nodes.len()));
+                return;
+            }
+        };
+
+        for (identifier_node, element_data_type) in self.identifier_nodes.iter_mut().zip(element_data_types) {
+            if identifier_node.identifier_token.is_discard() {
+                identifier_node.data_type = element_data_type;
+                continue;
+            }
+
+            symbol_table.add(identifier_node.identifier_token.name.clone(), element_data_type);
+
+            identifier_node.analyze(symbol_table, errors);
+        }
+    }
+}
@@ -2,12 +2,36 @@ use crate::analyzer::Analyzer;
 use crate::ast::{FileNode};
 use crate::datatype::DataType;
 use crate::error::AxiomError;
+use crate::error::location::Location;
 use crate::utils::SymbolTable;
 
-impl Analyzer for FileNode {
-    fn analyze(&mut self, symbol_table: &mut SymbolTable<String, DataType>, errors: &mut Vec<AxiomError>) {
+impl FileNode {
+    pub fn declare_functions(&mut self, symbol_table: &mut SymbolTable<String, DataType>, errors: &mut Vec<AxiomError>) {
+        for index in 0..self.functions.len() {
+            let name = &self.functions[index].identifier_node.identifier_token.name;
+
+            if symbol_table.has(name) {
+                let previous_location = self.functions[..index].iter()
+                    .find(|previous_function| previous_function.identifier_node.identifier_token.name == *name)
+                    .map(|previous_function| previous_function.identifier_node.location());
+
+                errors.push(AxiomError::DuplicatedIdentifier(self.functions[index].identifier_node.location(), name.clone(), previous_location));
+            }
+
+            self.functions[index].declare(symbol_table, errors);
+        }
+    }
+
+    pub fn analyze_functions(&mut self, symbol_table: &mut SymbolTable<String, DataType>, errors: &mut Vec<AxiomError>) {
         for function in &mut self.functions {
             function.analyze(symbol_table, errors);
         }
     }
-}
\ No newline at end of file
+}
+
+impl Analyzer for FileNode {
+    fn analyze(&mut self, symbol_table: &mut SymbolTable<String, DataType>, errors: &mut Vec<AxiomError>) {
+        self.declare_functions(symbol_table, errors);
+        self.analyze_functions(symbol_table, errors);
+    }
+}
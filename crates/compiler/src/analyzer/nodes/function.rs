@@ -3,14 +3,33 @@ use crate::ast::{FunctionNode};
 use crate::datatype::DataType;
 use crate::error::AxiomError;
 use crate::error::location::{Location};
+use crate::lint::{Lint, LintLevel};
 use crate::utils::SymbolTable;
 
-impl Analyzer for FunctionNode {
-    fn analyze(&mut self, symbol_table: &mut SymbolTable<String, DataType>, errors: &mut Vec<AxiomError>) {
-        let already_exist = symbol_table.has(&self.identifier_node.identifier_token.name);
+impl FunctionNode {
+    /// Checks the signature and adds the function to the symbol table, without looking at its body.
+    pub fn declare(&mut self, symbol_table: &mut SymbolTable<String, DataType>, errors: &mut Vec<AxiomError>) {
+        for attribute in &self.attributes {
+            let name = &attribute.identifier_node.identifier_token.name;
+
+            if name == "test" {
+                if !attribute.arguments.is_empty() {
+                    errors.push(AxiomError::SyntaxError(attribute.location(), "The test attribute takes no arguments".into()));
+                }
+
+                continue;
+            }
 
-        if already_exist {
-            errors.push(AxiomError::DuplicatedIdentifier(self.identifier_node.location(), self.identifier_node.identifier_token.name.clone()));
+            if LintLevel::parse(name).is_none() {
+                errors.push(AxiomError::UnknownAttribute(attribute.identifier_node.location(), name.clone()));
+                continue;
+            }
+
+            for argument in &attribute.arguments {
+                if Lint::parse(&argument.identifier_token.name).is_none() {
+                    errors.push(AxiomError::UnknownLint(argument.location(), argument.identifier_token.name.clone()));
+                }
+            }
         }
 
         let mut parameter_types = vec![];
@@ -38,21 +57,47 @@ impl Analyzer for FunctionNode {
             type_node.analyze(symbol_table, errors);
         }
 
+        if self.identifier_node.identifier_token.name == "main" {
+            let valid_parameters = matches!(parameter_types.as_slice(), [] | [DataType::I32] | [DataType::Args]);
+
+            if !valid_parameters || output_type != DataType::I32 {
+                errors.push(AxiomError::InvalidMainSignature(self.identifier_node.location()));
+            }
+        }
+
+        if self.is_test() && (!parameter_types.is_empty() || output_type != DataType::None) {
+            errors.push(AxiomError::InvalidTestFunction(self.identifier_node.location()));
+        }
+
         self.data_type = DataType::Function(parameter_types, Box::from(output_type.clone()));
 
         symbol_table.add(self.identifier_node.identifier_token.name.clone(), self.data_type.clone());
         
         self.identifier_node.analyze(symbol_table, errors);
+    }
+}
+
+impl Analyzer for FunctionNode {
+    fn analyze(&mut self, symbol_table: &mut SymbolTable<String, DataType>, errors: &mut Vec<AxiomError>) {
+        let output_type = match &self.data_type {
+            DataType::Function(_, output_type) => *output_type.clone(),
+            _ => DataType::None,
+        };
 
         symbol_table.push();
 
         symbol_table.add("return".to_string(), output_type);
 
-        for parameter in &self.parameters {
-            let already_exist = symbol_table.has(&parameter.identifier_node.identifier_token.name);
+        for (index, parameter) in self.parameters.iter().enumerate() {
+            let name = &parameter.identifier_node.identifier_token.name;
+            let already_exist = symbol_table.has_in_current_scope(name);
 
             if already_exist {
-                errors.push(AxiomError::DuplicatedIdentifier(parameter.location(), parameter.identifier_node.identifier_token.name.clone()));
+                let previous_location = self.parameters[..index].iter()
+                    .find(|previous_parameter| previous_parameter.identifier_node.identifier_token.name == *name)
+                    .map(|previous_parameter| previous_parameter.location());
+
+                errors.push(AxiomError::DuplicatedIdentifier(parameter.location(), name.clone(), previous_location));
             } else {
                 symbol_table.add(parameter.identifier_node.identifier_token.name.clone(), parameter.identifier_node.data_type.clone());
             }
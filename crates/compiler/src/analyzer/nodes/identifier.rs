@@ -7,6 +7,11 @@ use crate::utils::SymbolTable;
 
 impl Analyzer for IdentifierNode {
     fn analyze(&mut self, symbol_table: &mut SymbolTable<String, DataType>, errors: &mut Vec<AxiomError>) {
+        if self.identifier_token.is_discard() {
+            errors.push(AxiomError::SyntaxError(self.location(), "'_' can only be used to discard a value in 'let _ = ...'".to_string()));
+            return;
+        }
+
         match symbol_table.get(&self.identifier_token.name) {
             Some(data_type) => {
                 self.data_type = data_type.clone()
@@ -1,11 +1,25 @@
+use std::num::IntErrorKind;
 use crate::analyzer::Analyzer;
 use crate::ast::NumberNode;
 use crate::datatype::DataType;
 use crate::error::AxiomError;
+use crate::error::location::Location;
 use crate::utils::SymbolTable;
 
 impl Analyzer for NumberNode {
     fn analyze(&mut self, symbol_table: &mut SymbolTable<String, DataType>, errors: &mut Vec<AxiomError>) {
+        let is_in_range = match self.number_token.parse_value() {
+            Ok(value) => match self.data_type {
+                DataType::I32 => i32::try_from(value).is_ok(),
+                _ => true,
+            },
+            Err(error) if matches!(error.kind(), IntErrorKind::PosOverflow | IntErrorKind::NegOverflow) => false,
+            // Malformed literals are already reported by the lexer.
+            Err(_) => return,
+        };
 
+        if !is_in_range {
+            errors.push(AxiomError::LiteralOutOfRange(self.location(), self.number_token.value.clone(), Box::from(self.data_type.clone())));
+        }
     }
 }
\ No newline at end of file
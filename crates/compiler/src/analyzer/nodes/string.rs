@@ -0,0 +1,9 @@
+use crate::analyzer::Analyzer;
+use crate::ast::StringNode;
+use crate::datatype::DataType;
+use crate::error::AxiomError;
+use crate::utils::SymbolTable;
+
+impl Analyzer for StringNode {
+    fn analyze(&mut self, _symbol_table: &mut SymbolTable<String, DataType>, _errors: &mut Vec<AxiomError>) {}
+}
\ No newline at end of file
@@ -1,5 +1,5 @@
 use crate::analyzer::Analyzer;
-use crate::ast::{UnaryNode};
+use crate::ast::{UnaryNode, UnaryType};
 use crate::datatype::DataType;
 use crate::error::AxiomError;
 use crate::utils::SymbolTable;
@@ -8,6 +8,12 @@ impl Analyzer for UnaryNode {
     fn analyze(&mut self, symbol_table: &mut SymbolTable<String, DataType>, errors: &mut Vec<AxiomError>) {
         self.expression.analyze(symbol_table, errors);
 
-        self.data_type = self.expression.data_type().clone();
+        let data_type = self.expression.data_type();
+
+        if (self.operation_type == UnaryType::BitwiseNot && *data_type != DataType::I32) || matches!(data_type, DataType::Char | DataType::Str | DataType::Args) {
+            errors.push(AxiomError::WrongDataType(self.expression.location(), Box::from(DataType::I32), Box::from(data_type.clone())))
+        }
+
+        self.data_type = data_type.clone();
     }
 }
\ No newline at end of file
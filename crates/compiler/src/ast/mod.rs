@@ -1,14 +1,15 @@
 use crate::analyzer::Analyzer;
 pub use crate::ast::nodes::*;
-use crate::codegen::{CodeGen, CodeGenerator};
+use crate::c_codegen::{CCodeGen, CCodeGenerator};
 use crate::datatype::DataType;
 use crate::error::AxiomError;
 use crate::error::location::{Location, Position, Range};
+use crate::mir::{Lower, MirBuilder, Operand};
 use crate::utils::SymbolTable;
 
 mod nodes;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum Node {
     File(FileNode),
     Function(FunctionNode),
@@ -17,13 +18,17 @@ pub enum Node {
     IfElse(IfElseNode),
     Return(ReturnNode),
     Declaration(DeclarationNode),
+    Destructuring(DestructuringNode),
     Assignment(AssignmentNode),
     Ternary(TernaryNode),
     Binary(BinaryNode),
     Unary(UnaryNode),
     Number(NumberNode),
+    Char(CharNode),
+    String(StringNode),
     Identifier(IdentifierNode),
     Call(CallNode),
+    Error(ErrorNode),
 }
 
 impl Node {
@@ -31,10 +36,13 @@ impl Node {
         match &self {
             Node::File(file_node) => file_node.display(indent),
             Node::Number(number_node) => number_node.display(indent),
+            Node::Char(char_node) => char_node.display(indent),
+            Node::String(string_node) => string_node.display(indent),
             Node::Identifier(identifier_node) => identifier_node.display(indent),
             Node::Binary(binary_operation_node) => binary_operation_node.display(indent),
             Node::Assignment(assignment_node) => assignment_node.display(indent),
             Node::Declaration(declaration_node) => declaration_node.display(indent),
+            Node::Destructuring(destructuring_node) => destructuring_node.display(indent),
             Node::Scope(scope_node) => scope_node.display(indent),
             Node::Function(function_node) => function_node.display(indent),
             Node::Return(return_node) => return_node.display(indent),
@@ -43,6 +51,7 @@ impl Node {
             Node::Ternary(ternary_node) => ternary_node.display(indent),
             Node::IfElse(if_else_node) => if_else_node.display(indent),
             Node::Unary(unary_node) => unary_node.display(indent),
+            Node::Error(error_node) => error_node.display(indent),
         }
     }
 
@@ -50,10 +59,13 @@ impl Node {
         match &self {
             Node::File(file_node) => file_node.location(),
             Node::Number(number_node) => number_node.location(),
+            Node::Char(char_node) => char_node.location(),
+            Node::String(string_node) => string_node.location(),
             Node::Identifier(identifier_node) => identifier_node.location(),
             Node::Binary(binary_operation_node) => binary_operation_node.location(),
             Node::Assignment(assignment_node) => assignment_node.location(),
             Node::Declaration(declaration_node) => declaration_node.location(),
+            Node::Destructuring(destructuring_node) => destructuring_node.location(),
             Node::Scope(scope_node) => scope_node.location(),
             Node::Function(function_node) => function_node.location(),
             Node::Return(return_node) => return_node.location(),
@@ -62,6 +74,7 @@ impl Node {
             Node::Ternary(ternary_node) => ternary_node.location(),
             Node::IfElse(if_else_node) => if_else_node.location(),
             Node::Unary(unary_node) => unary_node.location(),
+            Node::Error(error_node) => error_node.location(),
         }
     }
 
@@ -69,10 +82,13 @@ impl Node {
         match &self {
             Node::File(_) => &DataType::None,
             Node::Number(number_node) => &number_node.data_type,
+            Node::Char(char_node) => &char_node.data_type,
+            Node::String(string_node) => &string_node.data_type,
             Node::Identifier(identifier_node) => &identifier_node.data_type,
             Node::Binary(binary_operation_node) => &binary_operation_node.data_type,
             Node::Assignment(_) => &DataType::None,
             Node::Declaration(_) => &DataType::None,
+            Node::Destructuring(_) => &DataType::None,
             Node::Scope(_) => &DataType::None,
             Node::Function(function_node) => &function_node.data_type,
             Node::Return(_) => &DataType::None,
@@ -81,6 +97,7 @@ impl Node {
             Node::Ternary(ternary_node) => &ternary_node.data_type,
             Node::IfElse(_) => &DataType::None,
             Node::Unary(unary_node) => &unary_node.data_type,
+            Node::Error(_) => &DataType::None,
         }
     }
 
@@ -93,13 +110,17 @@ impl Node {
             Node::IfElse(if_else_node) => if_else_node.get_node_at(position),
             Node::Return(return_node) => return_node.get_node_at(position),
             Node::Declaration(declaration_node) => declaration_node.get_node_at(position),
+            Node::Destructuring(destructuring_node) => destructuring_node.get_node_at(position),
             Node::Assignment(assignment_node) => assignment_node.get_node_at(position),
             Node::Ternary(ternary_node) => ternary_node.get_node_at(position),
             Node::Binary(binary_operation_node) => binary_operation_node.get_node_at(position),
             Node::Unary(unary_node) => unary_node.get_node_at(position),
             Node::Number(number_node) => number_node.get_node_at(position),
+            Node::Char(char_node) => char_node.get_node_at(position),
+            Node::String(string_node) => string_node.get_node_at(position),
             Node::Identifier(identifier_node) => identifier_node.get_node_at(position),
-            Node::Call(call_node) => call_node.get_node_at(position)
+            Node::Call(call_node) => call_node.get_node_at(position),
+            Node::Error(error_node) => error_node.get_node_at(position)
         }
     }
 }
@@ -109,10 +130,13 @@ impl Analyzer for Node {
         match self {
             Node::File(file_node) => file_node.analyze(symbol_table, errors),
             Node::Number(number_node) => number_node.analyze(symbol_table, errors),
+            Node::Char(char_node) => char_node.analyze(symbol_table, errors),
+            Node::String(string_node) => string_node.analyze(symbol_table, errors),
             Node::Identifier(identifier_node) => identifier_node.analyze(symbol_table, errors),
             Node::Binary(binary_operation_node) => binary_operation_node.analyze(symbol_table, errors),
             Node::Assignment(assignment_node) =>assignment_node.analyze(symbol_table, errors),
             Node::Declaration(declaration_node) => declaration_node.analyze(symbol_table, errors),
+            Node::Destructuring(destructuring_node) => destructuring_node.analyze(symbol_table, errors),
             Node::Scope(scope_node) => scope_node.analyze(symbol_table, errors),
             Node::Function(function_node) => function_node.analyze(symbol_table, errors),
             Node::Return(return_node) => return_node.analyze(symbol_table, errors),
@@ -121,27 +145,57 @@ impl Analyzer for Node {
             Node::Ternary(ternary_node) => ternary_node.analyze(symbol_table, errors),
             Node::IfElse(if_else_node) => if_else_node.analyze(symbol_table, errors),
             Node::Unary(unary_node) => unary_node.analyze(symbol_table, errors),
+            Node::Error(_) => {}
         }
     }
 }
 
-impl CodeGen for Node {
-    fn build(&mut self, code_generator: &mut CodeGenerator) {
+impl Lower for Node {
+    fn lower(&self, mir_builder: &mut MirBuilder) -> Option<Operand> {
         match self {
-            Node::File(file_node) => file_node.build(code_generator),
-            Node::Number(number_node) => number_node.build(code_generator),
-            Node::Identifier(identifier_node) => identifier_node.build(code_generator),
-            Node::Binary(binary_operation_node) => binary_operation_node.build(code_generator),
-            Node::Assignment(assignment_node) =>assignment_node.build(code_generator),
-            Node::Declaration(declaration_node) => declaration_node.build(code_generator),
-            Node::Scope(scope_node) => scope_node.build(code_generator),
-            Node::Function(function_node) => function_node.build(code_generator),
-            Node::Return(return_node) => return_node.build(code_generator),
-            Node::Call(call_node) => call_node.build(code_generator),
-            Node::Parameter(parameter_node) => parameter_node.build(code_generator),
-            Node::Ternary(ternary_node) => ternary_node.build(code_generator),
-            Node::IfElse(if_else_node) => if_else_node.build(code_generator),
-            Node::Unary(unary_node) => unary_node.build(code_generator),
+            Node::File(file_node) => file_node.lower(mir_builder),
+            Node::Number(number_node) => number_node.lower(mir_builder),
+            Node::Char(char_node) => char_node.lower(mir_builder),
+            Node::String(string_node) => string_node.lower(mir_builder),
+            Node::Identifier(identifier_node) => identifier_node.lower(mir_builder),
+            Node::Binary(binary_operation_node) => binary_operation_node.lower(mir_builder),
+            Node::Assignment(assignment_node) => assignment_node.lower(mir_builder),
+            Node::Declaration(declaration_node) => declaration_node.lower(mir_builder),
+            Node::Destructuring(destructuring_node) => destructuring_node.lower(mir_builder),
+            Node::Scope(scope_node) => scope_node.lower(mir_builder),
+            Node::Function(function_node) => function_node.lower(mir_builder),
+            Node::Return(return_node) => return_node.lower(mir_builder),
+            Node::Call(call_node) => call_node.lower(mir_builder),
+            Node::Parameter(parameter_node) => parameter_node.lower(mir_builder),
+            Node::Ternary(ternary_node) => ternary_node.lower(mir_builder),
+            Node::IfElse(if_else_node) => if_else_node.lower(mir_builder),
+            Node::Unary(unary_node) => unary_node.lower(mir_builder),
+            Node::Error(_) => unreachable!(),
+        }
+    }
+}
+
+impl CCodeGen for Node {
+    fn emit(&self, c_code_generator: &mut CCodeGenerator) {
+        match self {
+            Node::File(file_node) => file_node.emit(c_code_generator),
+            Node::Number(number_node) => number_node.emit(c_code_generator),
+            Node::Char(char_node) => char_node.emit(c_code_generator),
+            Node::String(string_node) => string_node.emit(c_code_generator),
+            Node::Identifier(identifier_node) => identifier_node.emit(c_code_generator),
+            Node::Binary(binary_operation_node) => binary_operation_node.emit(c_code_generator),
+            Node::Assignment(assignment_node) => assignment_node.emit(c_code_generator),
+            Node::Declaration(declaration_node) => declaration_node.emit(c_code_generator),
+            Node::Destructuring(destructuring_node) => destructuring_node.emit(c_code_generator),
+            Node::Scope(scope_node) => scope_node.emit(c_code_generator),
+            Node::Function(function_node) => function_node.emit(c_code_generator),
+            Node::Return(return_node) => return_node.emit(c_code_generator),
+            Node::Call(call_node) => call_node.emit(c_code_generator),
+            Node::Parameter(parameter_node) => parameter_node.emit(c_code_generator),
+            Node::Ternary(ternary_node) => ternary_node.emit(c_code_generator),
+            Node::IfElse(if_else_node) => if_else_node.emit(c_code_generator),
+            Node::Unary(unary_node) => unary_node.emit(c_code_generator),
+            Node::Error(_) => unreachable!(),
         }
     }
 }
\ No newline at end of file
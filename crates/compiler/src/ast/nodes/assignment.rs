@@ -1,7 +1,7 @@
 use crate::ast::{IdentifierNode, Node};
 use crate::error::location::{Location, Position, Range};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct AssignmentNode {
     location: Range,
     pub identifier_node: Box<IdentifierNode>,
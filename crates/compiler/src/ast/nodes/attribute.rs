@@ -0,0 +1,35 @@
+use crate::ast::IdentifierNode;
+use crate::error::location::{Location, Range};
+
+#[derive(Debug, Clone)]
+pub struct AttributeNode {
+    location: Range,
+    pub identifier_node: Box<IdentifierNode>,
+    pub arguments: Vec<Box<IdentifierNode>>,
+}
+
+impl AttributeNode {
+    pub fn new(location: Range, identifier_node: Box<IdentifierNode>, arguments: Vec<Box<IdentifierNode>>) -> AttributeNode {
+        AttributeNode {
+            location,
+            identifier_node,
+            arguments,
+        }
+    }
+
+    pub fn display(&self, indent: usize) {
+        let arguments: Vec<&str> = self.arguments.iter().map(|argument| argument.identifier_token.name.as_str()).collect();
+
+        if arguments.is_empty() {
+            println!("{}- #[{}]", " ".repeat(indent * 4), self.identifier_node.identifier_token.name);
+        } else {
+            println!("{}- #[{}({})]", " ".repeat(indent * 4), self.identifier_node.identifier_token.name, arguments.join(", "));
+        }
+    }
+}
+
+impl Location for AttributeNode {
+    fn location(&self) -> Range {
+        self.location.clone()
+    }
+}
\ No newline at end of file
@@ -2,12 +2,14 @@ use crate::ast::Node;
 use crate::datatype::DataType;
 use crate::error::location::{Location, Position, Range};
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum BinaryType {
     Addition,
     Subtraction,
     Multiplication,
     Division,
+    Modulo,
+    Exponentiation,
     Equal,
     NotEqual,
     GreaterThan,
@@ -18,11 +20,12 @@ pub enum BinaryType {
     ShiftRight,
     BitwiseOr,
     BitwiseAnd,
+    BitwiseXor,
     Or,
     And,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct BinaryNode {
     location: Range,
     pub data_type: DataType,
@@ -0,0 +1,42 @@
+use crate::ast::Node;
+use crate::datatype::DataType;
+use crate::error::location::{Location, Position, Range};
+use crate::token::CharToken;
+
+#[derive(Clone)]
+#[derive(Debug)]
+pub struct CharNode {
+    location: Range,
+    pub data_type: DataType,
+    pub char_token: CharToken,
+    pub value: char
+}
+
+impl CharNode {
+    pub fn new(location: Range, char_token: CharToken, value: char) -> CharNode {
+        CharNode {
+            location,
+            data_type: DataType::Char,
+            char_token,
+            value
+        }
+    }
+
+    pub fn display(&self, indent: usize) {
+        println!("{}- {}", " ".repeat(indent * 4), self.char_token.literal);
+    }
+
+    pub fn get_node_at(&self, position: &Position) -> Option<Box<Node>> {
+        if !position.is_in_range(&self.location()) {
+            return None;
+        }
+
+        Some(Box::from(Node::Char(self.clone())))
+    }
+}
+
+impl Location for CharNode {
+    fn location(&self) -> Range {
+        self.location.clone()
+    }
+}
\ No newline at end of file
@@ -0,0 +1,44 @@
+use crate::ast::{IdentifierNode, Node};
+use crate::error::location::{Location, Position, Range};
+
+#[derive(Debug, Clone)]
+pub struct DestructuringNode {
+    location: Range,
+    pub identifier_nodes: Vec<Box<IdentifierNode>>,
+    pub expression: Box<Node>,
+}
+
+impl DestructuringNode {
+    pub fn new(location: Range, identifier_nodes: Vec<Box<IdentifierNode>>, expression: Box<Node>) -> DestructuringNode {
+        DestructuringNode {
+            location,
+            identifier_nodes,
+            expression,
+        }
+    }
+
+    pub fn display(&self, indent: usize) {
+        let names: Vec<&str> = self.identifier_nodes.iter().map(|identifier_node| identifier_node.identifier_token.name.as_str()).collect();
+
+        println!("{}- let ({}) = ", " ".repeat(indent * 4), names.join(", "));
+        self.expression.display(indent * 4);
+    }
+
+    pub fn get_node_at(&self, position: &Position) -> Option<Box<Node>> {
+        if !position.is_in_range(&self.location()) {
+            return None;
+        }
+
+        if let Some(identifier_node) = self.identifier_nodes.iter().find(|identifier_node| position.is_in_range(&identifier_node.location())) {
+            return identifier_node.get_node_at(position);
+        }
+
+        self.expression.get_node_at(position)
+    }
+}
+
+impl Location for DestructuringNode {
+    fn location(&self) -> Range {
+        self.location.clone()
+    }
+}
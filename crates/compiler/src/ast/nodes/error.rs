@@ -0,0 +1,34 @@
+use crate::ast::Node;
+use crate::error::location::{Location, Position, Range};
+
+#[derive(Clone)]
+#[derive(Debug)]
+pub struct ErrorNode {
+    location: Range,
+}
+
+impl ErrorNode {
+    pub fn new(location: Range) -> ErrorNode {
+        ErrorNode {
+            location
+        }
+    }
+
+    pub fn display(&self, indent: usize) {
+        println!("{}- <error>", " ".repeat(indent * 4));
+    }
+
+    pub fn get_node_at(&self, position: &Position) -> Option<Box<Node>> {
+        if !position.is_in_range(&self.location()) {
+            return None;
+        }
+
+        Some(Box::from(Node::Error(self.clone())))
+    }
+}
+
+impl Location for ErrorNode {
+    fn location(&self) -> Range {
+        self.location.clone()
+    }
+}
\ No newline at end of file
@@ -1,7 +1,7 @@
 use crate::ast::{FunctionNode, Node};
 use crate::error::location::{Location, Position, Range};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct FileNode {
     location: Range,
     pub functions: Vec<Box<FunctionNode>>
@@ -1,11 +1,13 @@
-use crate::ast::{IdentifierNode, Node, ParameterNode, ScopeNode};
+use crate::ast::{AttributeNode, IdentifierNode, Node, ParameterNode, ScopeNode};
 use crate::datatype::DataType;
 use crate::error::location::{Location, Position, Range};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct FunctionNode {
     location: Range,
     pub data_type: DataType,
+    pub doc_comments: Vec<String>,
+    pub attributes: Vec<Box<AttributeNode>>,
     pub identifier_node: Box<IdentifierNode>,
     pub parameters: Vec<Box<ParameterNode>>,
     pub type_node: Option<Box<IdentifierNode>>,
@@ -13,10 +15,12 @@ pub struct FunctionNode {
 }
 
 impl FunctionNode {
-    pub fn new(location: Range, identifier_node: Box<IdentifierNode>, parameters: Vec<Box<ParameterNode>>, type_node: Option<Box<IdentifierNode>>, scope: Box<ScopeNode>) -> FunctionNode {
+    pub fn new(location: Range, doc_comments: Vec<String>, attributes: Vec<Box<AttributeNode>>, identifier_node: Box<IdentifierNode>, parameters: Vec<Box<ParameterNode>>, type_node: Option<Box<IdentifierNode>>, scope: Box<ScopeNode>) -> FunctionNode {
         FunctionNode {
             location,
             data_type: DataType::Function(vec![], Box::from(DataType::None)),
+            doc_comments,
+            attributes,
             identifier_node,
             parameters,
             type_node,
@@ -24,7 +28,20 @@ impl FunctionNode {
         }
     }
     
+    pub fn is_test(&self) -> bool {
+        self.attributes.iter().any(|attribute| attribute.identifier_node.identifier_token.name == "test")
+    }
+
+    pub fn documentation(&self) -> String {
+        let lines: Vec<&str> = self.doc_comments.iter().map(|line| line.strip_prefix(' ').unwrap_or(line).trim_end()).collect();
+
+        lines.join("\n").trim().to_string()
+    }
+
     pub fn display(&self, indent: usize) {
+        for attribute in &self.attributes {
+            attribute.display(indent);
+        }
         println!("{}- function {}(", " ".repeat(indent * 4), self.identifier_node.identifier_token.name);
         for param in &self.parameters {
             param.display(indent + 1);
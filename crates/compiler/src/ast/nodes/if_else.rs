@@ -1,7 +1,7 @@
 use crate::ast::{Node, ScopeNode};
 use crate::error::location::{Location, Position, Range};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct IfElseNode {
     location: Range,
     pub condition: Box<Node>,
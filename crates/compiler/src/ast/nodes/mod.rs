@@ -1,4 +1,6 @@
 mod number;
+mod char;
+mod string;
 mod binary;
 mod identifier;
 mod assignment;
@@ -7,13 +9,18 @@ mod function;
 mod file;
 mod r#return;
 mod declaration;
+mod destructuring;
 mod call;
 mod parameter;
+mod attribute;
 mod ternary;
 mod if_else;
 mod unary;
+mod error;
 
 pub use number::*;
+pub use char::*;
+pub use string::*;
 pub use binary::*;
 pub use identifier::*;
 pub use assignment::*;
@@ -22,8 +29,11 @@ pub use function::*;
 pub use file::*;
 pub use r#return::*;
 pub use declaration::*;
+pub use destructuring::*;
 pub use call::*;
 pub use parameter::*;
+pub use attribute::*;
 pub use ternary::*;
 pub use if_else::*;
-pub use unary::*;
\ No newline at end of file
+pub use unary::*;
+pub use error::*;
\ No newline at end of file
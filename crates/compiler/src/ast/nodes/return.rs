@@ -1,7 +1,7 @@
 use crate::ast::Node;
 use crate::error::location::{Location, Position, Range};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ReturnNode {
     location: Range,
     pub expression: Box<Node>,
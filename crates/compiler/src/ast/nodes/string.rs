@@ -0,0 +1,42 @@
+use crate::ast::Node;
+use crate::datatype::DataType;
+use crate::error::location::{Location, Position, Range};
+use crate::token::StringToken;
+
+#[derive(Clone)]
+#[derive(Debug)]
+pub struct StringNode {
+    location: Range,
+    pub data_type: DataType,
+    pub string_token: StringToken,
+    pub value: String
+}
+
+impl StringNode {
+    pub fn new(location: Range, string_token: StringToken, value: String) -> StringNode {
+        StringNode {
+            location,
+            data_type: DataType::Str,
+            string_token,
+            value
+        }
+    }
+
+    pub fn display(&self, indent: usize) {
+        println!("{}- {}", " ".repeat(indent * 4), self.string_token.literal);
+    }
+
+    pub fn get_node_at(&self, position: &Position) -> Option<Box<Node>> {
+        if !position.is_in_range(&self.location()) {
+            return None;
+        }
+
+        Some(Box::from(Node::String(self.clone())))
+    }
+}
+
+impl Location for StringNode {
+    fn location(&self) -> Range {
+        self.location.clone()
+    }
+}
\ No newline at end of file
@@ -2,7 +2,7 @@ use crate::ast::Node;
 use crate::datatype::DataType;
 use crate::error::location::{Location, Position, Range};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct TernaryNode {
     location: Range,
     pub data_type: DataType,
@@ -2,7 +2,7 @@ use crate::ast::Node;
 use crate::datatype::DataType;
 use crate::error::location::{Location, Position, Range};
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum UnaryType {
     PreIncrement,
     PreDecrement,
@@ -10,10 +10,11 @@ pub enum UnaryType {
     PostDecrement,
     Minus,
     Absolute,
-    Not
+    Not,
+    BitwiseNot
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct UnaryNode {
     location: Range,
     pub data_type: DataType,
@@ -0,0 +1,593 @@
+mod nodes;
+
+use std::collections::HashSet;
+use std::error::Error;
+use crate::ast::FileNode;
+use crate::datatype::DataType;
+use crate::error::location::Range;
+use crate::utils::SymbolTable;
+
+const C_RESERVED_IDENTIFIERS: [&str; 45] = [
+    "auto", "break", "case", "char", "const", "continue", "default", "do", "double", "else", "enum",
+    "extern", "float", "for", "goto", "if", "inline", "int", "long", "register", "restrict", "return",
+    "short", "signed", "sizeof", "static", "struct", "switch", "typedef", "union", "unsigned", "void",
+    "volatile", "while", "bool", "true", "false", "abs", "printf", "int32_t", "uint32_t", "_Bool", "NULL",
+    "getenv", "strcmp",
+];
+
+const C_PRELUDE_FUNCTIONS: [&str; 26] = [
+    "print", "wrapping_add", "saturating_add", "checked_add", "wrapping_sub", "saturating_sub", "checked_sub",
+    "wrapping_mul", "saturating_mul", "checked_mul", "to_i32", "from_i32", "is_ascii_digit", "is_ascii_alpha",
+    "is_ascii_alphanumeric", "is_ascii_upper", "is_ascii_lower", "is_ascii_whitespace", "print_str",
+    "str_eq", "parse_i32", "arg_count", "arg", "len", "get", "env",
+];
+
+const POWER_FUNCTION_NAME: &str = "__axiom_power";
+const ENTRY_FUNCTION_NAME: &str = "__axiom_main";
+const CHECKED_TYPE_NAME: &str = "__axiom_checked_i32";
+const CHECKED_FIELD_NAMES: [&str; 2] = ["overflowed", "value"];
+
+const C_PRELUDE: &str = "#include <inttypes.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+typedef struct {
+    bool overflowed;
+    int32_t value;
+} __axiom_checked_i32;
+
+static void print(int32_t value) {
+    printf(\"%\" PRId32 \"\\n\", value);
+}
+
+static inline int32_t __axiom_power(int32_t base, int32_t exponent) {
+    uint32_t result = 1;
+    uint32_t factor = (uint32_t) base;
+
+    while (exponent > 0) {
+        if (exponent & 1) {
+            result *= factor;
+        }
+
+        exponent >>= 1;
+        factor *= factor;
+    }
+
+    return (int32_t) result;
+}
+
+static inline int32_t wrapping_add(int32_t left, int32_t right) {
+    return (int32_t) ((uint32_t) left + (uint32_t) right);
+}
+
+static inline int32_t saturating_add(int32_t left, int32_t right) {
+    int32_t result;
+    return __builtin_add_overflow(left, right, &result) ? (right < 0 ? INT32_MIN : INT32_MAX) : result;
+}
+
+static inline __axiom_checked_i32 checked_add(int32_t left, int32_t right) {
+    __axiom_checked_i32 checked;
+    checked.overflowed = __builtin_add_overflow(left, right, &checked.value);
+    return checked;
+}
+
+static inline int32_t wrapping_sub(int32_t left, int32_t right) {
+    return (int32_t) ((uint32_t) left - (uint32_t) right);
+}
+
+static inline int32_t saturating_sub(int32_t left, int32_t right) {
+    int32_t result;
+    return __builtin_sub_overflow(left, right, &result) ? (right < 0 ? INT32_MAX : INT32_MIN) : result;
+}
+
+static inline __axiom_checked_i32 checked_sub(int32_t left, int32_t right) {
+    __axiom_checked_i32 checked;
+    checked.overflowed = __builtin_sub_overflow(left, right, &checked.value);
+    return checked;
+}
+
+static inline int32_t wrapping_mul(int32_t left, int32_t right) {
+    return (int32_t) ((uint32_t) left * (uint32_t) right);
+}
+
+static inline int32_t saturating_mul(int32_t left, int32_t right) {
+    int32_t result;
+    return __builtin_mul_overflow(left, right, &result) ? ((left < 0) != (right < 0) ? INT32_MIN : INT32_MAX) : result;
+}
+
+static inline __axiom_checked_i32 checked_mul(int32_t left, int32_t right) {
+    __axiom_checked_i32 checked;
+    checked.overflowed = __builtin_mul_overflow(left, right, &checked.value);
+    return checked;
+}
+
+static inline int32_t to_i32(uint32_t value) {
+    return (int32_t) value;
+}
+
+static inline uint32_t from_i32(int32_t value) {
+    uint32_t scalar = (uint32_t) value;
+    return (scalar < 0xD800 || (scalar >= 0xE000 && scalar <= 0x10FFFF)) ? scalar : 0xFFFD;
+}
+
+static inline bool is_ascii_digit(uint32_t value) {
+    return value - '0' < 10;
+}
+
+static inline bool is_ascii_alpha(uint32_t value) {
+    return (value | 0x20) - 'a' < 26;
+}
+
+static inline bool is_ascii_alphanumeric(uint32_t value) {
+    return is_ascii_alpha(value) || is_ascii_digit(value);
+}
+
+static inline bool is_ascii_upper(uint32_t value) {
+    return value - 'A' < 26;
+}
+
+static inline bool is_ascii_lower(uint32_t value) {
+    return value - 'a' < 26;
+}
+
+static inline bool is_ascii_whitespace(uint32_t value) {
+    return value == ' ' || value - '\\t' < 5;
+}
+
+static void __axiom_assert(bool condition, const char *message) {
+    if (!condition) {
+        fputs(message, stderr);
+        exit(101);
+    }
+}
+
+static void __axiom_assert_eq(int32_t left, int32_t right, const char *message) {
+    if (left != right) {
+        fprintf(stderr, \"%s  left: %\" PRId32 \"\\n right: %\" PRId32 \"\\n\", message, left, right);
+        exit(101);
+    }
+}
+
+static int32_t __axiom_argc;
+static char **__axiom_argv;
+
+static void __axiom_message_panic(const char *message) {
+    fprintf(stderr, \"panicked: %s\\n\", message);
+    exit(101);
+}
+
+static void print_str(const char *value) {
+    printf(\"%s\\n\", value);
+}
+
+static inline bool str_eq(const char *left, const char *right) {
+    return strcmp(left, right) == 0;
+}
+
+static int32_t parse_i32(const char *value) {
+    const char *cursor = value;
+    bool is_negative = *cursor == '-';
+
+    if (*cursor == '-' || *cursor == '+') {
+        cursor++;
+    }
+
+    if (*cursor == '\\0') {
+        __axiom_message_panic(\"string is not a valid i32\");
+    }
+
+    int64_t magnitude = 0;
+
+    for (; *cursor != '\\0'; cursor++) {
+        if (*cursor < '0' || *cursor > '9' || magnitude > INT32_MAX) {
+            __axiom_message_panic(\"string is not a valid i32\");
+        }
+
+        magnitude = magnitude * 10 + (*cursor - '0');
+    }
+
+    if (magnitude > (is_negative ? -(int64_t) INT32_MIN : INT32_MAX)) {
+        __axiom_message_panic(\"string is not a valid i32\");
+    }
+
+    return (int32_t) (is_negative ? -magnitude : magnitude);
+}
+
+static inline int32_t arg_count(void) {
+    return __axiom_argc > 0 ? __axiom_argc - 1 : 0;
+}
+
+static const char *arg(int32_t index) {
+    if (index < 0 || index >= arg_count()) {
+        __axiom_message_panic(\"argument index out of range\");
+    }
+
+    return __axiom_argv[index + 1];
+}
+
+static inline int32_t len(int32_t arguments) {
+    return arguments;
+}
+
+static inline const char *get(int32_t arguments, int32_t index) {
+    (void) arguments;
+    return arg(index);
+}
+
+static const char *env(const char *name) {
+    const char *value = getenv(name);
+    return value != NULL ? value : \"\";
+}
+";
+
+pub trait CCodeGen {
+    fn emit(&self, c_code_generator: &mut CCodeGenerator);
+}
+
+pub struct CCodeGenerator {
+    source_name: String,
+    input_source: String,
+    source: String,
+    indent: usize,
+    declared_names: HashSet<String>,
+    function_names: HashSet<String>,
+    errors: Vec<String>,
+    pub variables: SymbolTable<String, String>,
+    pub last_expression: Option<String>,
+}
+
+impl CCodeGenerator {
+    pub fn new(source_name: &str, input_source: &str) -> CCodeGenerator {
+        CCodeGenerator {
+            source_name: CCodeGenerator::escape_string(source_name),
+            input_source: input_source.to_string(),
+            source: C_PRELUDE.to_string(),
+            indent: 0,
+            declared_names: HashSet::new(),
+            function_names: C_PRELUDE_FUNCTIONS.iter().map(|name| name.to_string()).collect(),
+            errors: vec![],
+            variables: SymbolTable::new(),
+            last_expression: None,
+        }
+    }
+
+    /// Emits several files as one translation unit, with every prototype ahead of the first body
+    /// so that functions can call into files emitted after their own.
+    pub fn emit_files(&mut self, files: &[(&str, &str, &FileNode)]) {
+        for (_, _, file_node) in files {
+            file_node.reserve_function_names(self);
+        }
+
+        for (_, _, file_node) in files {
+            file_node.emit_prototypes(self);
+        }
+
+        for (source_name, input_source, file_node) in files {
+            self.source_name = CCodeGenerator::escape_string(source_name);
+            self.input_source = input_source.to_string();
+
+            file_node.emit_functions(self);
+        }
+
+        for (_, _, file_node) in files {
+            file_node.emit_entry_point(self);
+        }
+    }
+
+    pub fn source(&self) -> Result<&str, Box<dyn Error>> {
+        if !self.errors.is_empty() {
+            return Err(self.errors.join("\n").into());
+        }
+
+        Ok(&self.source)
+    }
+
+    pub fn write_line(&mut self, line: &str) {
+        self.source.push_str(&" ".repeat(self.indent * 4));
+        self.source.push_str(line);
+        self.source.push('\n');
+    }
+
+    pub fn write_empty_line(&mut self) {
+        self.source.push('\n');
+    }
+
+    pub fn write_line_directive(&mut self, location: &Range) {
+        self.source.push_str(&format!("#line {} \"{}\"\n", location.start.line + 1, self.source_name));
+    }
+
+    pub fn assertion_message(&self, location: &Range) -> String {
+        let expression = CCodeGenerator::escape_string(&location.text(&self.input_source));
+
+        format!("\"panicked at {}:{}:{}: assertion failed: {}\\n\"", self.source_name, location.start.line + 1, location.start.column + 1, expression)
+    }
+
+    pub fn indent(&mut self) {
+        self.indent += 1;
+    }
+
+    pub fn dedent(&mut self) {
+        self.indent -= 1;
+    }
+
+    pub fn reserve_function_name(&mut self, name: &str) {
+        self.function_names.insert(CCodeGenerator::function_name(name));
+    }
+
+    pub fn begin_function(&mut self) {
+        self.declared_names.clone_from(&self.function_names);
+    }
+
+    pub fn declare_variable(&mut self, name: &str) -> String {
+        let base_name = CCodeGenerator::escape_identifier(name);
+
+        let mut c_name = base_name.clone();
+        let mut suffix = 1;
+        while self.declared_names.contains(&c_name) {
+            c_name = format!("{}_{}", base_name, suffix);
+            suffix += 1;
+        }
+
+        self.declared_names.insert(c_name.clone());
+        self.variables.add(name.to_string(), c_name.clone());
+
+        c_name
+    }
+
+    fn escape_string(text: &str) -> String {
+        text.replace('\\', "\\\\").replace('"', "\\\"")
+    }
+
+    pub fn escape_identifier(name: &str) -> String {
+        if C_RESERVED_IDENTIFIERS.contains(&name) || name.starts_with("__") {
+            format!("{}_", name)
+        } else {
+            name.replace('.', "_")
+        }
+    }
+
+    pub fn function_name(name: &str) -> String {
+        match name {
+            "main" => ENTRY_FUNCTION_NAME.to_string(),
+            name => CCodeGenerator::escape_identifier(name),
+        }
+    }
+
+    /// Spells `value` as a C string literal, escaping every byte outside printable ASCII so the
+    /// literal holds the same UTF-8 bytes whatever the source charset of the C compiler.
+    pub fn string_literal(value: &str) -> String {
+        let mut literal = String::from('"');
+
+        for byte in value.bytes() {
+            match byte {
+                b'"' | b'\\' | b'?' => literal.push_str(&format!("\\{}", byte as char)),
+                b' '..=b'~' => literal.push(byte as char),
+                byte => literal.push_str(&format!("\\{:03o}", byte)),
+            }
+        }
+
+        literal.push('"');
+        literal
+    }
+
+    pub fn type_name(&mut self, data_type: &DataType) -> &'static str {
+        match data_type {
+            DataType::I32 => "int32_t",
+            DataType::Char => "uint32_t",
+            DataType::Str => "const char *",
+            DataType::Args => "int32_t",
+            DataType::Bool => "bool",
+            DataType::None => "void",
+            DataType::Tuple(element_data_types) if *element_data_types == [DataType::Bool, DataType::I32] => CHECKED_TYPE_NAME,
+            data_type => {
+                self.errors.push(format!("The C backend cannot represent values of type '{}'", data_type));
+                "void"
+            }
+        }
+    }
+}
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::process::Command;
+    use crate::c_codegen::{CCodeGen, CCodeGenerator};
+    use crate::datatype::DataType;
+    use crate::ast::Node;
+    use crate::utils::test_support::{analyze, analyze_files, TemporaryDirectory};
+
+    fn generate_c(source: &str) -> String {
+        let ast = analyze(source);
+
+        let mut c_code_generator = CCodeGenerator::new("test.axiom", source);
+        ast.emit(&mut c_code_generator);
+
+        c_code_generator.source().unwrap().to_string()
+    }
+
+    fn compile_and_run(name: &str, source: &str) -> Option<(i32, String)> {
+        compile_and_run_c(name, &generate_c(source))
+    }
+
+    fn compile_and_run_c(name: &str, c_source: &str) -> Option<(i32, String)> {
+        if Command::new("cc").arg("--version").output().is_err() {
+            eprintln!("skipping {}: 'cc' not found", name);
+            return None;
+        }
+
+        let directory = TemporaryDirectory::new(name);
+        let c_path = directory.join("program.c");
+        let executable_path = directory.join("program");
+
+        fs::write(&c_path, c_source).unwrap();
+
+        let compilation = Command::new("cc").arg("-std=c99").arg(&c_path).arg("-o").arg(&executable_path).output().unwrap();
+        assert!(compilation.status.success(), "cc rejected the generated C:\n{}", String::from_utf8_lossy(&compilation.stderr));
+
+        let output = Command::new(&executable_path).output().unwrap();
+
+        Some((output.status.code().unwrap(), String::from_utf8(output.stdout).unwrap()))
+    }
+
+    #[test]
+    fn compiles_with_system_cc() {
+        let source = "function fibonacci(n: i32): i32 {
+    if n < 2 {
+        return n
+    } else {
+        return fibonacci(n - 1) + fibonacci(n - 2)
+    }
+}
+
+function main(): i32 {
+    let value = fibonacci(10)
+    print(value)
+    print(value > 50 ? wrapping_mul(value, 2) : 0)
+    return 3
+}
+";
+
+        if let Some((exit_code, stdout)) = compile_and_run("c-fibonacci", source) {
+            assert_eq!(exit_code, 3);
+            assert_eq!(stdout, "55\n110\n");
+        }
+    }
+
+    #[test]
+    fn locals_do_not_shadow_functions() {
+        let source = "function foo(): i32 {
+    return 4
+}
+
+function main(): i32 {
+    let foo = foo()
+    let wrapping_add = wrapping_add(foo, 1)
+    print(wrapping_add)
+    return foo
+}
+";
+
+        let c_source = generate_c(source);
+        assert!(c_source.contains("int32_t foo_1 = foo();"), "{}", c_source);
+        assert!(c_source.contains("int32_t wrapping_add_1 = wrapping_add(foo_1, 1);"), "{}", c_source);
+
+        if let Some((exit_code, stdout)) = compile_and_run("c-shadowing", source) {
+            assert_eq!(exit_code, 4);
+            assert_eq!(stdout, "5\n");
+        }
+    }
+
+    #[test]
+    fn checked_arithmetic_returns_flag_and_result() {
+        let source = "function main(): i32 {
+    let (overflowed, sum) = checked_add(2147483647, 1)
+    print(overflowed ? 1 : 0)
+    print(sum)
+    let (_, product) = checked_mul(6, 7)
+    return product
+}
+";
+
+        let c_source = generate_c(source);
+        assert!(c_source.contains("__axiom_checked_i32 tuple_0 = checked_add(2147483647, 1);"), "{}", c_source);
+        assert!(c_source.contains("bool overflowed = tuple_0.overflowed;"), "{}", c_source);
+        assert!(c_source.contains("int32_t sum = tuple_0.value;"), "{}", c_source);
+
+        if let Some((exit_code, stdout)) = compile_and_run("c-checked", source) {
+            assert_eq!(exit_code, 42);
+            assert_eq!(stdout, "1\n-2147483648\n");
+        }
+    }
+
+    #[test]
+    fn calls_functions_from_later_files() {
+        let main_source = "function main(): i32 {
+    return double(halve(42))
+}
+";
+        let helper_source = "function halve(n: i32): i32 {
+    assert(n > 0)
+    return n / 2
+}
+
+function double(n: i32): i32 {
+    return n * 2
+}
+";
+
+        let asts = analyze_files(&[main_source, helper_source]);
+        let files: Vec<_> = [("src/bin.axiom", main_source), ("src/helpers.axiom", helper_source)].iter().zip(&asts).map(|((source_name, source), ast)| match ast.as_ref() {
+            Node::File(file_node) => (*source_name, *source, file_node),
+            _ => unreachable!(),
+        }).collect();
+
+        let mut c_code_generator = CCodeGenerator::new("", "");
+        c_code_generator.emit_files(&files);
+        let c_source = c_code_generator.source().unwrap().to_string();
+
+        let prototype = c_source.find("int32_t double_(int32_t n);").unwrap();
+        let main_body = c_source.find("int32_t __axiom_main(void) {").unwrap();
+        assert!(prototype < main_body, "{}", c_source);
+        assert!(c_source.contains("#line 2 \"src/helpers.axiom\""), "{}", c_source);
+        assert!(c_source.contains("panicked at src/helpers.axiom:2:5"), "{}", c_source);
+
+        if let Some((exit_code, _)) = compile_and_run_c("c-multiple-files", &c_source) {
+            assert_eq!(exit_code, 42);
+        }
+    }
+
+    #[test]
+    fn logical_operators_short_circuit() {
+        let source = "function above_one(n: i32, d: i32): bool {
+    return d != 0 && n / d > 1
+}
+
+function main(): i32 {
+    print(above_one(10, 0) ? 1 : 0)
+    print(d_is_zero_or_small(10, 0) ? 1 : 0)
+    return above_one(10, 2) ? 3 : 4
+}
+
+function d_is_zero_or_small(n: i32, d: i32): bool {
+    return d == 0 || n / d <= 1
+}
+";
+
+        let c_source = generate_c(source);
+        assert!(c_source.contains("return ((d != 0) && ((n / d) > 1));"), "{}", c_source);
+
+        if let Some((exit_code, stdout)) = compile_and_run("c-short-circuit", source) {
+            assert_eq!(exit_code, 3);
+            assert_eq!(stdout, "0\n1\n");
+        }
+    }
+
+    #[test]
+    fn ascii_classifiers_reject_other_characters() {
+        let source = "function main(): i32 {
+    print(is_ascii_alpha('\u{E9}') ? 1 : 0)
+    print(is_ascii_alpha('Z') ? 1 : 0)
+    print(is_ascii_upper('\u{C9}') ? 1 : 0)
+    print(is_ascii_whitespace('\u{A0}') ? 1 : 0)
+    print(is_ascii_alphanumeric('7') ? 1 : 0)
+    return 0
+}
+";
+
+        if let Some((exit_code, stdout)) = compile_and_run("c-ascii-classifiers", source) {
+            assert_eq!(exit_code, 0);
+            assert_eq!(stdout, "0\n1\n0\n0\n1\n");
+        }
+    }
+
+    #[test]
+    fn rejects_unrepresentable_types() {
+        let mut c_code_generator = CCodeGenerator::new("test.axiom", "");
+        c_code_generator.type_name(&DataType::ToBeInferred);
+
+        let error = c_code_generator.source().unwrap_err();
+        assert_eq!(error.to_string(), "The C backend cannot represent values of type 'unknown'");
+    }
+}
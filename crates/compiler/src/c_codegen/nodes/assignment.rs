@@ -0,0 +1,14 @@
+use crate::ast::AssignmentNode;
+use crate::c_codegen::{CCodeGen, CCodeGenerator};
+
+impl CCodeGen for AssignmentNode {
+    fn emit(&self, c_code_generator: &mut CCodeGenerator) {
+        self.expression.emit(c_code_generator);
+        let expression = c_code_generator.last_expression.take().unwrap();
+
+        self.identifier_node.emit(c_code_generator);
+        let identifier = c_code_generator.last_expression.take().unwrap();
+
+        c_code_generator.write_line(&format!("{} = {};", identifier, expression));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,41 @@
+use crate::ast::{BinaryNode, BinaryType};
+use crate::c_codegen::{CCodeGen, CCodeGenerator, POWER_FUNCTION_NAME};
+
+impl CCodeGen for BinaryNode {
+    fn emit(&self, c_code_generator: &mut CCodeGenerator) {
+        self.left.emit(c_code_generator);
+        let left = c_code_generator.last_expression.take().unwrap();
+
+        self.right.emit(c_code_generator);
+        let right = c_code_generator.last_expression.take().unwrap();
+
+        if self.operation_type == BinaryType::Exponentiation {
+            c_code_generator.last_expression = Some(format!("{}({}, {})", POWER_FUNCTION_NAME, left, right));
+            return;
+        }
+
+        let operator = match self.operation_type {
+            BinaryType::Addition => "+",
+            BinaryType::Subtraction => "-",
+            BinaryType::Multiplication => "*",
+            BinaryType::Division => "/",
+            BinaryType::Modulo => "%",
+            BinaryType::Exponentiation => unreachable!(),
+            BinaryType::Equal => "==",
+            BinaryType::NotEqual => "!=",
+            BinaryType::GreaterThan => ">",
+            BinaryType::LessThan => "<",
+            BinaryType::GreaterThanOrEqual => ">=",
+            BinaryType::LessThanOrEqual => "<=",
+            BinaryType::ShiftLeft => "<<",
+            BinaryType::ShiftRight => ">>",
+            BinaryType::BitwiseOr => "|",
+            BinaryType::BitwiseAnd => "&",
+            BinaryType::BitwiseXor => "^",
+            BinaryType::Or => "||",
+            BinaryType::And => "&&",
+        };
+
+        c_code_generator.last_expression = Some(format!("({} {} {})", left, operator, right));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,23 @@
+use crate::ast::CallNode;
+use crate::c_codegen::{CCodeGen, CCodeGenerator};
+use crate::error::location::Location;
+
+impl CCodeGen for CallNode {
+    fn emit(&self, c_code_generator: &mut CCodeGenerator) {
+        let mut parameters: Vec<String> = self.parameters.iter().map(|parameter| {
+            parameter.emit(c_code_generator);
+            c_code_generator.last_expression.take().unwrap()
+        }).collect();
+
+        let name = match self.identifier_node.identifier_token.name.as_str() {
+            name @ ("assert" | "assert_eq") => {
+                parameters.push(c_code_generator.assertion_message(&self.location()));
+
+                format!("__axiom_{}", name)
+            }
+            name => CCodeGenerator::function_name(name),
+        };
+
+        c_code_generator.last_expression = Some(format!("{}({})", name, parameters.join(", ")));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,8 @@
+use crate::ast::CharNode;
+use crate::c_codegen::{CCodeGen, CCodeGenerator};
+
+impl CCodeGen for CharNode {
+    fn emit(&self, c_code_generator: &mut CCodeGenerator) {
+        c_code_generator.last_expression = Some(format!("UINT32_C({})", self.value as u32));
+    }
+}
\ No newline at end of file
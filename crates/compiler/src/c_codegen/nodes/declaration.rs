@@ -0,0 +1,19 @@
+use crate::ast::DeclarationNode;
+use crate::c_codegen::{CCodeGen, CCodeGenerator};
+
+impl CCodeGen for DeclarationNode {
+    fn emit(&self, c_code_generator: &mut CCodeGenerator) {
+        self.expression.emit(c_code_generator);
+        let expression = c_code_generator.last_expression.take().unwrap();
+
+        if self.identifier_node.identifier_token.is_discard() {
+            c_code_generator.write_line(&format!("(void) {};", expression));
+            return;
+        }
+
+        let type_name = c_code_generator.type_name(self.expression.data_type());
+        let c_name = c_code_generator.declare_variable(&self.identifier_node.identifier_token.name);
+
+        c_code_generator.write_line(&format!("{} {} = {};", type_name, c_name, expression));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,25 @@
+use crate::ast::DestructuringNode;
+use crate::c_codegen::{CCodeGen, CCodeGenerator, CHECKED_FIELD_NAMES};
+
+impl CCodeGen for DestructuringNode {
+    fn emit(&self, c_code_generator: &mut CCodeGenerator) {
+        self.expression.emit(c_code_generator);
+        let expression = c_code_generator.last_expression.take().unwrap();
+
+        let type_name = c_code_generator.type_name(self.expression.data_type());
+        let tuple_name = c_code_generator.declare_variable("tuple.0");
+
+        c_code_generator.write_line(&format!("{} {} = {};", type_name, tuple_name, expression));
+
+        for (identifier_node, field_name) in self.identifier_nodes.iter().zip(CHECKED_FIELD_NAMES) {
+            if identifier_node.identifier_token.is_discard() {
+                continue;
+            }
+
+            let type_name = c_code_generator.type_name(&identifier_node.data_type);
+            let c_name = c_code_generator.declare_variable(&identifier_node.identifier_token.name);
+
+            c_code_generator.write_line(&format!("{} {} = {}.{};", type_name, c_name, tuple_name, field_name));
+        }
+    }
+}
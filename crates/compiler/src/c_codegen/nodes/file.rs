@@ -0,0 +1,53 @@
+use crate::ast::FileNode;
+use crate::c_codegen::{CCodeGen, CCodeGenerator};
+
+impl FileNode {
+    pub fn reserve_function_names(&self, c_code_generator: &mut CCodeGenerator) {
+        for function in &self.functions {
+            c_code_generator.reserve_function_name(&function.identifier_node.identifier_token.name);
+        }
+    }
+
+    pub fn emit_prototypes(&self, c_code_generator: &mut CCodeGenerator) {
+        for function in &self.functions {
+            c_code_generator.begin_function();
+            c_code_generator.variables.push();
+
+            let signature = function.c_signature(c_code_generator);
+            c_code_generator.write_line(&format!("{};", signature));
+
+            c_code_generator.variables.pop();
+        }
+    }
+
+    pub fn emit_functions(&self, c_code_generator: &mut CCodeGenerator) {
+        for function in &self.functions {
+            c_code_generator.write_empty_line();
+            function.emit(c_code_generator);
+        }
+    }
+
+    pub fn emit_entry_point(&self, c_code_generator: &mut CCodeGenerator) {
+        if let Some(main) = self.functions.iter().find(|function| function.identifier_node.identifier_token.name == "main") {
+            let arguments = if main.parameters.is_empty() { "" } else { "arg_count()" };
+
+            c_code_generator.write_empty_line();
+            c_code_generator.write_line("int main(int argc, char **argv) {");
+            c_code_generator.indent();
+            c_code_generator.write_line("__axiom_argc = argc;");
+            c_code_generator.write_line("__axiom_argv = argv;");
+            c_code_generator.write_line(&format!("return {}({});", CCodeGenerator::function_name("main"), arguments));
+            c_code_generator.dedent();
+            c_code_generator.write_line("}");
+        }
+    }
+}
+
+impl CCodeGen for FileNode {
+    fn emit(&self, c_code_generator: &mut CCodeGenerator) {
+        self.reserve_function_names(c_code_generator);
+        self.emit_prototypes(c_code_generator);
+        self.emit_functions(c_code_generator);
+        self.emit_entry_point(c_code_generator);
+    }
+}
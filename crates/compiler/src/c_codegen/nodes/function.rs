@@ -0,0 +1,40 @@
+use crate::ast::FunctionNode;
+use crate::c_codegen::{CCodeGen, CCodeGenerator};
+use crate::datatype::DataType;
+use crate::error::location::Location;
+
+impl CCodeGen for FunctionNode {
+    fn emit(&self, c_code_generator: &mut CCodeGenerator) {
+        c_code_generator.begin_function();
+        c_code_generator.variables.push();
+
+        let signature = self.c_signature(c_code_generator);
+
+        c_code_generator.write_line_directive(&self.location());
+        c_code_generator.write_line(&format!("{} {{", signature));
+        self.scope.emit(c_code_generator);
+        c_code_generator.write_line("}");
+
+        c_code_generator.variables.pop();
+    }
+}
+
+impl FunctionNode {
+    pub fn c_signature(&self, c_code_generator: &mut CCodeGenerator) -> String {
+        let name = &self.identifier_node.identifier_token.name;
+
+        let return_type = match &self.data_type {
+            DataType::Function(_, return_type) => c_code_generator.type_name(return_type),
+            _ => unreachable!(),
+        };
+
+        let parameters: Vec<String> = self.parameters.iter().map(|parameter| {
+            parameter.emit(c_code_generator);
+            c_code_generator.last_expression.take().unwrap()
+        }).collect();
+
+        let parameters = if parameters.is_empty() { "void".to_string() } else { parameters.join(", ") };
+
+        format!("{} {}({})", return_type, CCodeGenerator::function_name(name), parameters)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,13 @@
+use crate::ast::IdentifierNode;
+use crate::c_codegen::{CCodeGen, CCodeGenerator};
+
+impl CCodeGen for IdentifierNode {
+    fn emit(&self, c_code_generator: &mut CCodeGenerator) {
+        let name = match c_code_generator.variables.get(&self.identifier_token.name) {
+            Some(c_name) => c_name.clone(),
+            None => CCodeGenerator::escape_identifier(&self.identifier_token.name),
+        };
+
+        c_code_generator.last_expression = Some(name);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,27 @@
+use crate::ast::IfElseNode;
+use crate::c_codegen::{CCodeGen, CCodeGenerator};
+
+impl CCodeGen for IfElseNode {
+    fn emit(&self, c_code_generator: &mut CCodeGenerator) {
+        self.condition.emit(c_code_generator);
+        let condition = c_code_generator.last_expression.take().unwrap();
+
+        c_code_generator.write_line(&format!("if ({}) {{", condition));
+        self.consequent.emit(c_code_generator);
+
+        for (condition, consequent) in &self.conditional_alternatives {
+            condition.emit(c_code_generator);
+            let condition = c_code_generator.last_expression.take().unwrap();
+
+            c_code_generator.write_line(&format!("}} else if ({}) {{", condition));
+            consequent.emit(c_code_generator);
+        }
+
+        if let Some(alternative) = &self.alternative {
+            c_code_generator.write_line("} else {");
+            alternative.emit(c_code_generator);
+        }
+
+        c_code_generator.write_line("}");
+    }
+}
\ No newline at end of file
@@ -1,4 +1,6 @@
 mod number;
+mod char;
+mod string;
 mod binary;
 mod identifier;
 mod assignment;
@@ -7,6 +9,7 @@ mod function;
 mod file;
 mod r#return;
 mod declaration;
+mod destructuring;
 mod call;
 mod parameter;
 mod ternary;
@@ -0,0 +1,16 @@
+use crate::ast::NumberNode;
+use crate::c_codegen::{CCodeGen, CCodeGenerator};
+
+impl CCodeGen for NumberNode {
+    fn emit(&self, c_code_generator: &mut CCodeGenerator) {
+        let value = self.number_token.parse_value().unwrap();
+
+        if value == i32::MIN as i64 {
+            c_code_generator.last_expression = Some("INT32_MIN".to_string());
+        } else if value < 0 {
+            c_code_generator.last_expression = Some(format!("({})", value));
+        } else {
+            c_code_generator.last_expression = Some(value.to_string());
+        }
+    }
+}
\ No newline at end of file
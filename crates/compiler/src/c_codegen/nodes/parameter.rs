@@ -0,0 +1,11 @@
+use crate::ast::ParameterNode;
+use crate::c_codegen::{CCodeGen, CCodeGenerator};
+
+impl CCodeGen for ParameterNode {
+    fn emit(&self, c_code_generator: &mut CCodeGenerator) {
+        let type_name = c_code_generator.type_name(&self.identifier_node.data_type);
+        let c_name = c_code_generator.declare_variable(&self.identifier_node.identifier_token.name);
+
+        c_code_generator.last_expression = Some(format!("{} {}", type_name, c_name));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,11 @@
+use crate::ast::ReturnNode;
+use crate::c_codegen::{CCodeGen, CCodeGenerator};
+
+impl CCodeGen for ReturnNode {
+    fn emit(&self, c_code_generator: &mut CCodeGenerator) {
+        self.expression.emit(c_code_generator);
+        let expression = c_code_generator.last_expression.take().unwrap();
+
+        c_code_generator.write_line(&format!("return {};", expression));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,29 @@
+use crate::ast::{Node, ScopeNode};
+use crate::c_codegen::{CCodeGen, CCodeGenerator};
+
+impl CCodeGen for ScopeNode {
+    fn emit(&self, c_code_generator: &mut CCodeGenerator) {
+        c_code_generator.variables.push();
+        c_code_generator.indent();
+
+        for statement in &self.statements {
+            c_code_generator.write_line_directive(&statement.location());
+
+            if let Node::Scope(scope_node) = &**statement {
+                c_code_generator.write_line("{");
+                scope_node.emit(c_code_generator);
+                c_code_generator.write_line("}");
+                continue;
+            }
+
+            statement.emit(c_code_generator);
+
+            if let Some(expression) = c_code_generator.last_expression.take() {
+                c_code_generator.write_line(&format!("{};", expression));
+            }
+        }
+
+        c_code_generator.dedent();
+        c_code_generator.variables.pop();
+    }
+}
\ No newline at end of file
@@ -0,0 +1,8 @@
+use crate::ast::StringNode;
+use crate::c_codegen::{CCodeGen, CCodeGenerator};
+
+impl CCodeGen for StringNode {
+    fn emit(&self, c_code_generator: &mut CCodeGenerator) {
+        c_code_generator.last_expression = Some(CCodeGenerator::string_literal(&self.value));
+    }
+}
\ No newline at end of file
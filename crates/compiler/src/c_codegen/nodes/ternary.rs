@@ -0,0 +1,17 @@
+use crate::ast::TernaryNode;
+use crate::c_codegen::{CCodeGen, CCodeGenerator};
+
+impl CCodeGen for TernaryNode {
+    fn emit(&self, c_code_generator: &mut CCodeGenerator) {
+        self.condition.emit(c_code_generator);
+        let condition = c_code_generator.last_expression.take().unwrap();
+
+        self.consequent.emit(c_code_generator);
+        let consequent = c_code_generator.last_expression.take().unwrap();
+
+        self.alternative.emit(c_code_generator);
+        let alternative = c_code_generator.last_expression.take().unwrap();
+
+        c_code_generator.last_expression = Some(format!("({} ? {} : {})", condition, consequent, alternative));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,27 @@
+use crate::ast::{Node, UnaryNode, UnaryType};
+use crate::c_codegen::{CCodeGen, CCodeGenerator};
+
+impl CCodeGen for UnaryNode {
+    fn emit(&self, c_code_generator: &mut CCodeGenerator) {
+        self.expression.emit(c_code_generator);
+        let expression = c_code_generator.last_expression.take().unwrap();
+
+        let is_identifier = matches!(*self.expression, Node::Identifier(_));
+
+        let expression = match self.operation_type {
+            UnaryType::PreIncrement if is_identifier => format!("++{}", expression),
+            UnaryType::PreIncrement => format!("({} + 1)", expression),
+            UnaryType::PreDecrement if is_identifier => format!("--{}", expression),
+            UnaryType::PreDecrement => format!("({} - 1)", expression),
+            UnaryType::PostIncrement if is_identifier => format!("{}++", expression),
+            UnaryType::PostDecrement if is_identifier => format!("{}--", expression),
+            UnaryType::PostIncrement | UnaryType::PostDecrement => expression,
+            UnaryType::Minus => format!("(-{})", expression),
+            UnaryType::Absolute => format!("abs({})", expression),
+            UnaryType::Not => format!("(!{})", expression),
+            UnaryType::BitwiseNot => format!("(~{})", expression),
+        };
+
+        c_code_generator.last_expression = Some(expression);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,128 @@
+use std::error::Error;
+use inkwell::AddressSpace;
+use inkwell::IntPredicate;
+use inkwell::module::Linkage;
+use inkwell::values::{FunctionValue, GlobalValue, IntValue, PointerValue};
+use crate::codegen::{ARGUMENT_COUNT_NAME, ARGUMENT_VALUES_NAME, CodeGenerator, ENTRY_FUNCTION_NAME, PANIC_FUNCTION_NAME};
+
+const ARGUMENT_INDEX_PANIC_MESSAGE: &str = "panicked: argument index out of range\n";
+
+impl<'code_generator> CodeGenerator<'code_generator> {
+    pub(crate) fn add_argument_functions(&mut self) {
+        let i32_type = self.context.i32_type();
+        let i8_pointer_pointer_type = self.context.i8_type().ptr_type(AddressSpace::default()).ptr_type(AddressSpace::default());
+
+        let argument_count = self.module.add_global(i32_type, None, ARGUMENT_COUNT_NAME);
+        argument_count.set_linkage(Linkage::Internal);
+        argument_count.set_initializer(&i32_type.const_zero());
+
+        let argument_values = self.module.add_global(i8_pointer_pointer_type, None, ARGUMENT_VALUES_NAME);
+        argument_values.set_linkage(Linkage::Internal);
+        argument_values.set_initializer(&i8_pointer_pointer_type.const_null());
+
+        self.add_inline_function("arg_count", &[], i32_type);
+        let count = self.build_user_argument_count(argument_count);
+        self.builder.build_return(Some(&count)).unwrap();
+
+        self.add_argument_function(argument_count, argument_values);
+
+        // The argument list `main` may take is its length, its values are read from the globals
+        let arguments = self.add_inline_function("len", &[i32_type], i32_type)[0];
+        self.builder.build_return(Some(&arguments)).unwrap();
+
+        let parameters = self.add_inline_function("get", &[i32_type, i32_type], self.string_type());
+        let arg = self.module.get_function("arg").unwrap();
+        let value = self.builder.build_call(arg, &[parameters[1].into()], "value").unwrap().try_as_basic_value().basic().unwrap();
+        self.builder.build_return(Some(&value)).unwrap();
+    }
+
+    fn build_user_argument_count(&self, argument_count: GlobalValue<'code_generator>) -> IntValue<'code_generator> {
+        let i32_type = self.context.i32_type();
+
+        let count = self.builder.build_load(argument_count.as_pointer_value(), "argc").unwrap().into_int_value();
+        let user_count = self.builder.build_int_sub(count, i32_type.const_int(1, false), "user_count").unwrap();
+        let is_empty = self.builder.build_int_compare(IntPredicate::SLT, user_count, i32_type.const_zero(), "is_empty").unwrap();
+
+        self.builder.build_select(is_empty, i32_type.const_zero(), user_count, "arg_count").unwrap().into_int_value()
+    }
+
+    fn add_argument_function(&mut self, argument_count: GlobalValue<'code_generator>, argument_values: GlobalValue<'code_generator>) {
+        let i32_type = self.context.i32_type();
+
+        let arg_type = self.string_type().fn_type(&[i32_type.into()], false);
+        let arg = self.module.add_function("arg", arg_type, Some(Linkage::Private));
+
+        let entry_block = self.context.append_basic_block(arg, "entry");
+        let lookup_block = self.context.append_basic_block(arg, "lookup");
+        let out_of_range_block = self.context.append_basic_block(arg, "out_of_range");
+
+        self.builder.position_at_end(entry_block);
+
+        let index = arg.get_nth_param(0).unwrap().into_int_value();
+        let count = self.build_user_argument_count(argument_count);
+        let is_in_range = self.builder.build_int_compare(IntPredicate::ULT, index, count, "is_in_range").unwrap();
+        self.builder.build_conditional_branch(is_in_range, lookup_block, out_of_range_block).unwrap();
+
+        self.builder.position_at_end(lookup_block);
+
+        let values = self.builder.build_load(argument_values.as_pointer_value(), "argv").unwrap().into_pointer_value();
+        let position = self.builder.build_int_add(index, i32_type.const_int(1, false), "position").unwrap();
+        let value_pointer = unsafe { self.builder.build_in_bounds_gep(values, &[position], "value.pointer") }.unwrap();
+        let value = self.builder.build_load(value_pointer, "value").unwrap().into_pointer_value();
+        let value = self.build_string_value(value);
+        self.builder.build_return(Some(&value)).unwrap();
+
+        self.builder.position_at_end(out_of_range_block);
+        self.build_message_panic(ARGUMENT_INDEX_PANIC_MESSAGE);
+    }
+
+    pub(crate) fn build_message_panic(&self, message: &str) {
+        let panic = self.module.get_function(PANIC_FUNCTION_NAME).unwrap();
+        let message_pointer = self.builder.build_global_string_ptr(message, "panic.message").unwrap().as_pointer_value();
+        let message_length = self.context.i32_type().const_int(message.len() as u64, false);
+
+        self.builder.build_call(panic, &[message_pointer.into(), message_length.into()], "").unwrap();
+        self.builder.build_unreachable().unwrap();
+    }
+
+    pub(crate) fn build_entry_call(&self, main: FunctionValue<'code_generator>, count: IntValue<'code_generator>, values: PointerValue<'code_generator>) -> IntValue<'code_generator> {
+        let argument_count = self.module.get_global(ARGUMENT_COUNT_NAME).unwrap();
+        let argument_values = self.module.get_global(ARGUMENT_VALUES_NAME).unwrap();
+
+        self.builder.build_store(argument_count.as_pointer_value(), count).unwrap();
+        self.builder.build_store(argument_values.as_pointer_value(), values).unwrap();
+
+        let arguments = match main.count_params() {
+            0 => vec![],
+            _ => vec![self.build_user_argument_count(argument_count).into()],
+        };
+
+        let result = self.builder.build_call(main, &arguments, "main").unwrap().try_as_basic_value().basic();
+
+        match result {
+            Some(result) => self.builder.build_int_z_extend_or_bit_cast(result.into_int_value(), self.context.i32_type(), "exit_code").unwrap(),
+            None => self.context.i32_type().const_zero(),
+        }
+    }
+
+    pub fn add_native_start(&mut self) -> Result<(), Box<dyn Error>> {
+        let main = self.module.get_function(ENTRY_FUNCTION_NAME).ok_or("Function 'main' not found")?;
+
+        let i32_type = self.context.i32_type();
+        let i8_pointer_pointer_type = self.context.i8_type().ptr_type(AddressSpace::default()).ptr_type(AddressSpace::default());
+
+        let start_type = i32_type.fn_type(&[i32_type.into(), i8_pointer_pointer_type.into()], false);
+        let start = self.module.add_function("main", start_type, None);
+
+        let entry_block = self.context.append_basic_block(start, "entry");
+        self.builder.position_at_end(entry_block);
+
+        let count = start.get_nth_param(0).unwrap().into_int_value();
+        let values = start.get_nth_param(1).unwrap().into_pointer_value();
+
+        let exit_code = self.build_entry_call(main, count, values);
+        self.builder.build_return(Some(&exit_code)).unwrap();
+
+        Ok(())
+    }
+}
\ No newline at end of file
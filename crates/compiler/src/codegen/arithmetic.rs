@@ -0,0 +1,173 @@
+use inkwell::intrinsics::Intrinsic;
+use inkwell::IntPredicate;
+use inkwell::module::Linkage;
+use inkwell::types::IntType;
+use inkwell::values::{IntValue, PointerValue, StructValue};
+use crate::codegen::{CodeGenerator, POWER_FUNCTION_NAME};
+
+#[derive(Clone, Copy)]
+enum ArithmeticOperation {
+    Add,
+    Subtract,
+    Multiply,
+}
+
+impl ArithmeticOperation {
+    fn name(&self) -> &'static str {
+        match self {
+            ArithmeticOperation::Add => "add",
+            ArithmeticOperation::Subtract => "sub",
+            ArithmeticOperation::Multiply => "mul",
+        }
+    }
+}
+
+impl<'code_generator> CodeGenerator<'code_generator> {
+    pub(crate) fn add_arithmetic_functions(&mut self) {
+        for operation in [ArithmeticOperation::Add, ArithmeticOperation::Subtract, ArithmeticOperation::Multiply] {
+            self.add_wrapping_function(operation);
+            self.add_saturating_function(operation);
+        }
+
+        self.add_power_function();
+    }
+
+    fn add_arithmetic_function(&mut self, name: &str, return_type: IntType<'code_generator>) -> (IntValue<'code_generator>, IntValue<'code_generator>) {
+        let i32_type = self.context.i32_type();
+        let parameters = self.add_inline_function(name, &[i32_type, i32_type], return_type);
+
+        (parameters[0], parameters[1])
+    }
+
+    fn build_overflow_intrinsic(&self, operation: ArithmeticOperation, left: IntValue<'code_generator>, right: IntValue<'code_generator>) -> StructValue<'code_generator> {
+        let intrinsic_name = format!("llvm.s{}.with.overflow", operation.name());
+        let intrinsic = Intrinsic::find(&intrinsic_name).unwrap().get_declaration(&self.module, &[left.get_type().into()]).unwrap();
+
+        self.builder.build_call(intrinsic, &[left.into(), right.into()], "checked").unwrap().try_as_basic_value().basic().unwrap().into_struct_value()
+    }
+
+    fn add_wrapping_function(&mut self, operation: ArithmeticOperation) {
+        let (left, right) = self.add_arithmetic_function(&format!("wrapping_{}", operation.name()), self.context.i32_type());
+
+        let result = match operation {
+            ArithmeticOperation::Add => self.builder.build_int_add(left, right, "add").unwrap(),
+            ArithmeticOperation::Subtract => self.builder.build_int_sub(left, right, "sub").unwrap(),
+            ArithmeticOperation::Multiply => self.builder.build_int_mul(left, right, "mul").unwrap(),
+        };
+
+        self.builder.build_return(Some(&result)).unwrap();
+    }
+
+    fn add_saturating_function(&mut self, operation: ArithmeticOperation) {
+        let i32_type = self.context.i32_type();
+        let (left, right) = self.add_arithmetic_function(&format!("saturating_{}", operation.name()), i32_type);
+
+        let result = match operation {
+            ArithmeticOperation::Add | ArithmeticOperation::Subtract => {
+                let intrinsic_name = format!("llvm.s{}.sat", operation.name());
+                let intrinsic = Intrinsic::find(&intrinsic_name).unwrap().get_declaration(&self.module, &[i32_type.into()]).unwrap();
+
+                self.builder.build_call(intrinsic, &[left.into(), right.into()], "saturated").unwrap().try_as_basic_value().basic().unwrap().into_int_value()
+            }
+            ArithmeticOperation::Multiply => {
+                let checked = self.build_overflow_intrinsic(operation, left, right);
+                let value = self.builder.build_extract_value(checked, 0, "value").unwrap().into_int_value();
+                let overflow = self.builder.build_extract_value(checked, 1, "overflow").unwrap().into_int_value();
+
+                let signs = self.builder.build_xor(left, right, "signs").unwrap();
+                let is_negative = self.builder.build_int_compare(IntPredicate::SLT, signs, i32_type.const_zero(), "is_negative").unwrap();
+                let bound = self.builder.build_select(is_negative, i32_type.const_int(i32::MIN as u64, true), i32_type.const_int(i32::MAX as u64, true), "bound").unwrap().into_int_value();
+
+                self.builder.build_select(overflow, bound, value, "saturated").unwrap().into_int_value()
+            }
+        };
+
+        self.builder.build_return(Some(&result)).unwrap();
+    }
+
+    fn add_power_function(&mut self) {
+        let i32_type = self.context.i32_type();
+        let bool_type = self.context.bool_type();
+
+        let result_type = self.context.struct_type(&[i32_type.into(), bool_type.into()], false);
+        let power_type = result_type.fn_type(&[i32_type.into(), i32_type.into()], false);
+        let power = self.module.add_function(POWER_FUNCTION_NAME, power_type, Some(Linkage::Private));
+
+        let entry_block = self.context.append_basic_block(power, "entry");
+        let loop_block = self.context.append_basic_block(power, "loop");
+        let multiply_block = self.context.append_basic_block(power, "multiply");
+        let square_block = self.context.append_basic_block(power, "square");
+        let exit_block = self.context.append_basic_block(power, "exit");
+
+        self.builder.position_at_end(entry_block);
+
+        let result = self.builder.build_alloca(i32_type, "result").unwrap();
+        let base = self.builder.build_alloca(i32_type, "base").unwrap();
+        let exponent = self.builder.build_alloca(i32_type, "exponent").unwrap();
+        let overflow = self.builder.build_alloca(bool_type, "overflow").unwrap();
+
+        self.builder.build_store(result, i32_type.const_int(1, false)).unwrap();
+        self.builder.build_store(base, power.get_nth_param(0).unwrap()).unwrap();
+        self.builder.build_store(exponent, power.get_nth_param(1).unwrap()).unwrap();
+        self.builder.build_store(overflow, bool_type.const_zero()).unwrap();
+        self.builder.build_unconditional_branch(loop_block).unwrap();
+
+        self.builder.position_at_end(loop_block);
+
+        let current_exponent = self.builder.build_load(exponent, "exponent").unwrap().into_int_value();
+        let is_positive = self.builder.build_int_compare(IntPredicate::SGT, current_exponent, i32_type.const_zero(), "is_positive").unwrap();
+        self.builder.build_conditional_branch(is_positive, multiply_block, exit_block).unwrap();
+
+        self.builder.position_at_end(multiply_block);
+
+        let current_exponent = self.builder.build_load(exponent, "exponent").unwrap().into_int_value();
+        let current_result = self.builder.build_load(result, "result").unwrap().into_int_value();
+        let current_base = self.builder.build_load(base, "base").unwrap().into_int_value();
+
+        let lowest_bit = self.builder.build_and(current_exponent, i32_type.const_int(1, false), "lowest_bit").unwrap();
+        let is_odd = self.builder.build_int_compare(IntPredicate::NE, lowest_bit, i32_type.const_zero(), "is_odd").unwrap();
+
+        let checked = self.build_overflow_intrinsic(ArithmeticOperation::Multiply, current_result, current_base);
+        let product = self.builder.build_extract_value(checked, 0, "product").unwrap().into_int_value();
+        let product_overflow = self.builder.build_extract_value(checked, 1, "product_overflow").unwrap().into_int_value();
+
+        let next_result = self.builder.build_select(is_odd, product, current_result, "next_result").unwrap();
+        let product_overflow = self.builder.build_and(is_odd, product_overflow, "product_overflow").unwrap();
+        self.build_overflow_update(overflow, product_overflow);
+        self.builder.build_store(result, next_result).unwrap();
+
+        let next_exponent = self.builder.build_right_shift(current_exponent, i32_type.const_int(1, false), true, "next_exponent").unwrap();
+        self.builder.build_store(exponent, next_exponent).unwrap();
+
+        let has_more_bits = self.builder.build_int_compare(IntPredicate::SGT, next_exponent, i32_type.const_zero(), "has_more_bits").unwrap();
+        self.builder.build_conditional_branch(has_more_bits, square_block, exit_block).unwrap();
+
+        self.builder.position_at_end(square_block);
+
+        let current_base = self.builder.build_load(base, "base").unwrap().into_int_value();
+
+        let checked = self.build_overflow_intrinsic(ArithmeticOperation::Multiply, current_base, current_base);
+        let square = self.builder.build_extract_value(checked, 0, "square").unwrap();
+        let square_overflow = self.builder.build_extract_value(checked, 1, "square_overflow").unwrap().into_int_value();
+
+        self.build_overflow_update(overflow, square_overflow);
+        self.builder.build_store(base, square).unwrap();
+        self.builder.build_unconditional_branch(loop_block).unwrap();
+
+        self.builder.position_at_end(exit_block);
+
+        let final_result = self.builder.build_load(result, "result").unwrap();
+        let final_overflow = self.builder.build_load(overflow, "overflow").unwrap();
+
+        let value = self.builder.build_insert_value(result_type.get_undef(), final_result, 0, "value").unwrap();
+        let value = self.builder.build_insert_value(value, final_overflow, 1, "value").unwrap();
+        self.builder.build_return(Some(&value)).unwrap();
+    }
+
+    fn build_overflow_update(&self, overflow: PointerValue<'code_generator>, new_overflow: IntValue<'code_generator>) {
+        let current_overflow = self.builder.build_load(overflow, "overflow").unwrap().into_int_value();
+        let next_overflow = self.builder.build_or(current_overflow, new_overflow, "next_overflow").unwrap();
+
+        self.builder.build_store(overflow, next_overflow).unwrap();
+    }
+}
\ No newline at end of file
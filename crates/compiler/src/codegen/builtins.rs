@@ -0,0 +1,166 @@
+use inkwell::AddressSpace;
+use inkwell::IntPredicate;
+use inkwell::attributes::{Attribute, AttributeLoc};
+use inkwell::module::Linkage;
+use inkwell::types::{BasicMetadataTypeEnum, IntType};
+use inkwell::values::{FunctionValue, IntValue};
+use crate::codegen::{ASSERTION_FUNCTION_NAME, CodeGenerator, PANIC_EXIT_CODE, PANIC_FUNCTION_NAME};
+use crate::codegen::target::is_wasi_triple;
+
+const STDERR_FILE_DESCRIPTOR: u64 = 2;
+
+impl<'code_generator> CodeGenerator<'code_generator> {
+    pub fn add_build_in_functions(&mut self, triple: &str) {
+        if is_wasi_triple(triple) {
+            self.add_wasi_output_shim();
+            self.add_wasi_panic_routine();
+            self.add_wasi_assertion_routine();
+            self.add_wasi_environment_function();
+        } else {
+            self.add_native_output_shim();
+            self.add_native_panic_routine();
+            self.add_native_assertion_routine();
+            self.add_native_environment_function();
+        }
+
+        self.add_arithmetic_functions();
+        self.add_char_functions();
+        self.add_string_functions();
+        self.add_argument_functions();
+    }
+
+    pub(crate) fn add_inline_function(&mut self, name: &str, parameter_types: &[IntType<'code_generator>], return_type: IntType<'code_generator>) -> Vec<IntValue<'code_generator>> {
+        let parameter_types: Vec<BasicMetadataTypeEnum> = parameter_types.iter().map(|parameter_type| BasicMetadataTypeEnum::from(*parameter_type)).collect();
+
+        let function_type = return_type.fn_type(&parameter_types, false);
+        let function = self.module.add_function(name, function_type, Some(Linkage::Private));
+
+        function.add_attribute(AttributeLoc::Function, self.context.create_enum_attribute(Attribute::get_named_enum_kind_id("alwaysinline"), 0));
+
+        let entry_block = self.context.append_basic_block(function, "entry");
+        self.builder.position_at_end(entry_block);
+
+        function.get_param_iter().map(|parameter| parameter.into_int_value()).collect()
+    }
+
+    pub(crate) fn add_panic_function(&mut self) -> FunctionValue<'code_generator> {
+        let i8_pointer_type = self.context.i8_type().ptr_type(AddressSpace::default());
+        let panic_type = self.context.void_type().fn_type(&[i8_pointer_type.into(), self.context.i32_type().into()], false);
+        let panic = self.module.add_function(PANIC_FUNCTION_NAME, panic_type, None);
+
+        panic.add_attribute(AttributeLoc::Function, self.context.create_enum_attribute(Attribute::get_named_enum_kind_id("noreturn"), 0));
+        panic.add_attribute(AttributeLoc::Function, self.context.create_enum_attribute(Attribute::get_named_enum_kind_id("cold"), 0));
+
+        panic
+    }
+
+    pub(crate) fn add_assertion_function(&mut self) -> FunctionValue<'code_generator> {
+        let i32_type = self.context.i32_type();
+        let i8_pointer_type = self.context.i8_type().ptr_type(AddressSpace::default());
+        let assertion_type = self.context.void_type().fn_type(&[i8_pointer_type.into(), i32_type.into(), i32_type.into(), i32_type.into()], false);
+        let assertion = self.module.add_function(ASSERTION_FUNCTION_NAME, assertion_type, None);
+
+        assertion.add_attribute(AttributeLoc::Function, self.context.create_enum_attribute(Attribute::get_named_enum_kind_id("noreturn"), 0));
+        assertion.add_attribute(AttributeLoc::Function, self.context.create_enum_attribute(Attribute::get_named_enum_kind_id("cold"), 0));
+
+        assertion
+    }
+
+    fn add_native_panic_routine(&mut self) {
+        let i32_type = self.context.i32_type();
+        let i8_pointer_type = self.context.i8_type().ptr_type(AddressSpace::default());
+
+        let dprintf_type = i32_type.fn_type(&[i32_type.into(), i8_pointer_type.into()], true);
+        let dprintf = self.module.add_function("dprintf", dprintf_type, Some(Linkage::External));
+
+        let exit_type = self.context.void_type().fn_type(&[i32_type.into()], false);
+        let exit = self.module.add_function("exit", exit_type, Some(Linkage::External));
+
+        let panic = self.add_panic_function();
+
+        let entry_block = self.context.append_basic_block(panic, "entry");
+        self.builder.position_at_end(entry_block);
+
+        let format = self.builder.build_global_string_ptr("%.*s", "panic.format").unwrap();
+        let message = panic.get_nth_param(0).unwrap();
+        let length = panic.get_nth_param(1).unwrap();
+
+        let arguments = &[i32_type.const_int(STDERR_FILE_DESCRIPTOR, false).into(), format.as_pointer_value().into(), length.into(), message.into()];
+        self.builder.build_call(dprintf, arguments, "dprintf").unwrap();
+        self.builder.build_call(exit, &[i32_type.const_int(PANIC_EXIT_CODE, false).into()], "").unwrap();
+        self.builder.build_unreachable().unwrap();
+    }
+
+    fn add_native_assertion_routine(&mut self) {
+        let i32_type = self.context.i32_type();
+
+        let dprintf = self.module.get_function("dprintf").unwrap();
+        let exit = self.module.get_function("exit").unwrap();
+
+        let assertion = self.add_assertion_function();
+
+        let entry_block = self.context.append_basic_block(assertion, "entry");
+        self.builder.position_at_end(entry_block);
+
+        let format = self.builder.build_global_string_ptr("%.*s  left: %d\n right: %d\n", "assertion.format").unwrap();
+        let message = assertion.get_nth_param(0).unwrap();
+        let length = assertion.get_nth_param(1).unwrap();
+        let left = assertion.get_nth_param(2).unwrap();
+        let right = assertion.get_nth_param(3).unwrap();
+
+        let arguments = &[i32_type.const_int(STDERR_FILE_DESCRIPTOR, false).into(), format.as_pointer_value().into(), length.into(), message.into(), left.into(), right.into()];
+        self.builder.build_call(dprintf, arguments, "dprintf").unwrap();
+        self.builder.build_call(exit, &[i32_type.const_int(PANIC_EXIT_CODE, false).into()], "").unwrap();
+        self.builder.build_unreachable().unwrap();
+    }
+
+    fn add_native_output_shim(&mut self) {
+        let i8_pointer_type = self.context.i8_type().ptr_type(AddressSpace::default());
+        let printf_type = self.context.i32_type().fn_type(&[i8_pointer_type.into()], true);
+        let printf = self.module.add_function("printf", printf_type, Some(Linkage::External));
+
+        let print_type = self.context.void_type().fn_type(&[self.context.i32_type().into()], false);
+        let print = self.module.add_function("print", print_type, None);
+
+        let entry_block = self.context.append_basic_block(print, "entry");
+        self.builder.position_at_end(entry_block);
+
+        let format = self.builder.build_global_string_ptr("%d\n", "print.format").unwrap();
+        let value = print.get_nth_param(0).unwrap();
+
+        self.builder.build_call(printf, &[format.as_pointer_value().into(), value.into()], "printf").unwrap();
+        self.builder.build_return(None).unwrap();
+
+        let print_str_type = self.context.void_type().fn_type(&[self.string_type().into()], false);
+        let print_str = self.module.add_function("print_str", print_str_type, None);
+
+        let entry_block = self.context.append_basic_block(print_str, "entry");
+        self.builder.position_at_end(entry_block);
+
+        let format = self.builder.build_global_string_ptr("%s\n", "print_str.format").unwrap();
+        let value = self.build_string_pointer(print_str.get_nth_param(0).unwrap().into_int_value());
+
+        self.builder.build_call(printf, &[format.as_pointer_value().into(), value.into()], "printf").unwrap();
+        self.builder.build_return(None).unwrap();
+    }
+
+    fn add_native_environment_function(&mut self) {
+        let i8_pointer_type = self.context.i8_type().ptr_type(AddressSpace::default());
+        let getenv_type = i8_pointer_type.fn_type(&[i8_pointer_type.into()], false);
+        let getenv = self.module.add_function("getenv", getenv_type, Some(Linkage::External));
+
+        let env_type = self.string_type().fn_type(&[self.string_type().into()], false);
+        let env = self.module.add_function("env", env_type, Some(Linkage::Private));
+
+        let entry_block = self.context.append_basic_block(env, "entry");
+        self.builder.position_at_end(entry_block);
+
+        let name = self.build_string_pointer(env.get_nth_param(0).unwrap().into_int_value());
+        let value = self.builder.build_call(getenv, &[name.into()], "getenv").unwrap().try_as_basic_value().basic().unwrap().into_pointer_value();
+        let value = self.build_string_value(value);
+
+        let is_unset = self.builder.build_int_compare(IntPredicate::EQ, value, self.string_type().const_zero(), "is_unset").unwrap();
+        let result = self.builder.build_select(is_unset, self.build_string_constant(""), value, "value").unwrap();
+        self.builder.build_return(Some(&result)).unwrap();
+    }
+}
\ No newline at end of file
@@ -0,0 +1,63 @@
+use inkwell::IntPredicate;
+use inkwell::values::IntValue;
+use crate::codegen::CodeGenerator;
+
+const REPLACEMENT_CHARACTER: u64 = 0xFFFD;
+
+impl<'code_generator> CodeGenerator<'code_generator> {
+    pub(crate) fn add_char_functions(&mut self) {
+        let i32_type = self.context.i32_type();
+        let bool_type = self.context.bool_type();
+
+        let value = self.add_inline_function("to_i32", &[i32_type], i32_type)[0];
+        self.builder.build_return(Some(&value)).unwrap();
+
+        let value = self.add_inline_function("from_i32", &[i32_type], i32_type)[0];
+        let is_below_surrogates = self.builder.build_int_compare(IntPredicate::ULT, value, i32_type.const_int(0xD800, false), "is_below_surrogates").unwrap();
+        let is_above_surrogates = self.build_is_in_range(value, 0xE000, 0x10FFFF - 0xE000 + 1);
+        let is_scalar_value = self.builder.build_or(is_below_surrogates, is_above_surrogates, "is_scalar_value").unwrap();
+        let result = self.builder.build_select(is_scalar_value, value, i32_type.const_int(REPLACEMENT_CHARACTER, false), "char").unwrap();
+        self.builder.build_return(Some(&result)).unwrap();
+
+        let value = self.add_inline_function("is_ascii_digit", &[i32_type], bool_type)[0];
+        let is_digit = self.build_is_in_range(value, '0' as u64, 10);
+        self.builder.build_return(Some(&is_digit)).unwrap();
+
+        let value = self.add_inline_function("is_ascii_alpha", &[i32_type], bool_type)[0];
+        let is_alpha = self.build_is_ascii_alpha(value);
+        self.builder.build_return(Some(&is_alpha)).unwrap();
+
+        let value = self.add_inline_function("is_ascii_alphanumeric", &[i32_type], bool_type)[0];
+        let is_alpha = self.build_is_ascii_alpha(value);
+        let is_digit = self.build_is_in_range(value, '0' as u64, 10);
+        let is_alphanumeric = self.builder.build_or(is_alpha, is_digit, "is_ascii_alphanumeric").unwrap();
+        self.builder.build_return(Some(&is_alphanumeric)).unwrap();
+
+        let value = self.add_inline_function("is_ascii_upper", &[i32_type], bool_type)[0];
+        let is_upper = self.build_is_in_range(value, 'A' as u64, 26);
+        self.builder.build_return(Some(&is_upper)).unwrap();
+
+        let value = self.add_inline_function("is_ascii_lower", &[i32_type], bool_type)[0];
+        let is_lower = self.build_is_in_range(value, 'a' as u64, 26);
+        self.builder.build_return(Some(&is_lower)).unwrap();
+
+        let value = self.add_inline_function("is_ascii_whitespace", &[i32_type], bool_type)[0];
+        let is_space = self.builder.build_int_compare(IntPredicate::EQ, value, i32_type.const_int(' ' as u64, false), "is_space").unwrap();
+        let is_control_whitespace = self.build_is_in_range(value, '\t' as u64, 5);
+        let is_whitespace = self.builder.build_or(is_space, is_control_whitespace, "is_ascii_whitespace").unwrap();
+        self.builder.build_return(Some(&is_whitespace)).unwrap();
+    }
+
+    fn build_is_in_range(&self, value: IntValue<'code_generator>, start: u64, length: u64) -> IntValue<'code_generator> {
+        let i32_type = self.context.i32_type();
+
+        let offset = self.builder.build_int_sub(value, i32_type.const_int(start, false), "offset").unwrap();
+        self.builder.build_int_compare(IntPredicate::ULT, offset, i32_type.const_int(length, false), "is_in_range").unwrap()
+    }
+
+    fn build_is_ascii_alpha(&self, value: IntValue<'code_generator>) -> IntValue<'code_generator> {
+        let lowercase = self.builder.build_or(value, self.context.i32_type().const_int(0x20, false), "lowercase").unwrap();
+
+        self.build_is_in_range(lowercase, 'a' as u64, 26)
+    }
+}
\ No newline at end of file
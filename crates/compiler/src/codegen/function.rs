@@ -0,0 +1,262 @@
+use inkwell::basic_block::BasicBlock;
+use inkwell::IntPredicate;
+use inkwell::intrinsics::Intrinsic;
+use inkwell::types::{BasicMetadataTypeEnum, IntType};
+use inkwell::values::{BasicMetadataValueEnum, BasicValueEnum, FunctionValue, IntValue, PhiValue, PointerValue, StructValue};
+use crate::codegen::{ASSERTION_FUNCTION_NAME, CodeGenerator, PANIC_FUNCTION_NAME, POWER_FUNCTION_NAME};
+use crate::mir::{BinaryOperation, BlockId, Constant, Instruction, MirFunction, MirType, Operand, PanicKind, Terminator, UnaryOperation};
+use crate::error::location::Range;
+
+struct FunctionContext<'function> {
+    function_value: FunctionValue<'function>,
+    blocks: Vec<BasicBlock<'function>>,
+    locals: Vec<PointerValue<'function>>,
+    values: Vec<Option<IntValue<'function>>>,
+    pending_phis: Vec<(PhiValue<'function>, Vec<(Operand, BlockId)>)>,
+}
+
+impl<'code_generator> CodeGenerator<'code_generator> {
+    fn int_type(&self, mir_type: MirType) -> IntType<'code_generator> {
+        match mir_type {
+            MirType::I32 | MirType::Char => self.context.i32_type(),
+            MirType::Bool => self.context.bool_type(),
+            MirType::Str => self.string_type(),
+            MirType::Void => unreachable!(),
+        }
+    }
+
+    pub(crate) fn declare_function(&mut self, function: &MirFunction) {
+        let parameter_types: Vec<BasicMetadataTypeEnum> = function.parameters.iter().map(|parameter| {
+            BasicMetadataTypeEnum::from(self.int_type(*parameter))
+        }).collect();
+
+        let function_type = match function.return_type {
+            MirType::Void => self.context.void_type().fn_type(&parameter_types, false),
+            return_type => self.int_type(return_type).fn_type(&parameter_types, false),
+        };
+
+        self.module.add_function(CodeGenerator::function_name(&function.name), function_type, None);
+    }
+
+    pub(crate) fn build_function(&mut self, function: &MirFunction, source_name: &str) {
+        let function_value = self.module.get_function(CodeGenerator::function_name(&function.name)).unwrap();
+
+        let blocks = function.blocks.iter().map(|block| self.context.append_basic_block(function_value, &block.label)).collect();
+
+        let mut function_context = FunctionContext {
+            function_value,
+            blocks,
+            locals: vec![],
+            values: vec![None; function.values.len()],
+            pending_phis: vec![],
+        };
+
+        self.builder.position_at_end(function_context.blocks[0]);
+
+        for local in &function.locals {
+            let pointer = self.builder.build_alloca(self.int_type(local.mir_type), &local.name).unwrap();
+            function_context.locals.push(pointer);
+        }
+
+        for block in function.block_order() {
+            let mir_block = &function.blocks[block.0];
+            self.builder.position_at_end(function_context.blocks[block.0]);
+
+            for instruction in &mir_block.instructions {
+                self.build_instruction(&mut function_context, function, instruction);
+            }
+
+            self.build_terminator(&function_context, mir_block.terminator.as_ref().unwrap(), source_name);
+        }
+
+        for (phi, incoming) in &function_context.pending_phis {
+            for (operand, block) in incoming {
+                let value = self.build_operand(&function_context, operand);
+                phi.add_incoming(&[(&value, function_context.blocks[block.0])]);
+            }
+        }
+    }
+
+    fn build_operand(&self, function_context: &FunctionContext<'code_generator>, operand: &Operand) -> IntValue<'code_generator> {
+        match operand {
+            Operand::Constant(Constant::I32(value)) => self.context.i32_type().const_int(*value as u64, true),
+            Operand::Constant(Constant::Bool(value)) => self.context.bool_type().const_int(*value as u64, false),
+            Operand::Constant(Constant::Char(value)) => self.context.i32_type().const_int(*value as u64, false),
+            Operand::Constant(Constant::Str(value)) => self.build_string_constant(value),
+            Operand::Value(value) => function_context.values[value.0].unwrap(),
+            Operand::Parameter(index) => function_context.function_value.get_nth_param(*index as u32).unwrap().into_int_value(),
+        }
+    }
+
+    fn build_instruction(&mut self, function_context: &mut FunctionContext<'code_generator>, function: &MirFunction, instruction: &Instruction) {
+        match instruction {
+            Instruction::Binary { result, operation, left: left_operand, right } => {
+                let is_signed = function.operand_type(left_operand).is_signed();
+                let left = self.build_operand(function_context, left_operand);
+                let right = self.build_operand(function_context, right);
+
+                let expression = match operation {
+                    BinaryOperation::Add => self.builder.build_int_add(left, right, "add").unwrap(),
+                    BinaryOperation::Subtract => self.builder.build_int_sub(left, right, "sub").unwrap(),
+                    BinaryOperation::Multiply => self.builder.build_int_mul(left, right, "mul").unwrap(),
+                    BinaryOperation::Divide => self.builder.build_int_signed_div(left, right, "div").unwrap(),
+                    BinaryOperation::Remainder => self.builder.build_int_signed_rem(left, right, "rem").unwrap(),
+                    BinaryOperation::Power => {
+                        let power = self.build_power(left, right);
+                        self.builder.build_extract_value(power, 0, "pow").unwrap().into_int_value()
+                    }
+                    BinaryOperation::Equal => self.builder.build_int_compare(IntPredicate::EQ, left, right, "eq").unwrap(),
+                    BinaryOperation::NotEqual => self.builder.build_int_compare(IntPredicate::NE, left, right, "ne").unwrap(),
+                    BinaryOperation::GreaterThan => {
+                        let predicate = if is_signed { IntPredicate::SGT } else { IntPredicate::UGT };
+                        self.builder.build_int_compare(predicate, left, right, "gt").unwrap()
+                    }
+                    BinaryOperation::GreaterThanOrEqual => {
+                        let predicate = if is_signed { IntPredicate::SGE } else { IntPredicate::UGE };
+                        self.builder.build_int_compare(predicate, left, right, "ge").unwrap()
+                    }
+                    BinaryOperation::LessThan => {
+                        let predicate = if is_signed { IntPredicate::SLT } else { IntPredicate::ULT };
+                        self.builder.build_int_compare(predicate, left, right, "lt").unwrap()
+                    }
+                    BinaryOperation::LessThanOrEqual => {
+                        let predicate = if is_signed { IntPredicate::SLE } else { IntPredicate::ULE };
+                        self.builder.build_int_compare(predicate, left, right, "le").unwrap()
+                    }
+                    BinaryOperation::ShiftLeft => self.builder.build_left_shift(left, right, "lsh").unwrap(),
+                    BinaryOperation::ShiftRight => self.builder.build_right_shift(left, right, is_signed, "rsh").unwrap(),
+                    BinaryOperation::BitwiseOr => self.builder.build_or(left, right, "or").unwrap(),
+                    BinaryOperation::BitwiseAnd => self.builder.build_and(left, right, "and").unwrap(),
+                    BinaryOperation::BitwiseXor => self.builder.build_xor(left, right, "xor").unwrap(),
+                };
+
+                function_context.values[result.0] = Some(expression);
+            }
+            Instruction::CheckedBinary { result, overflowed, operation, left, right } => {
+                let left = self.build_operand(function_context, left);
+                let right = self.build_operand(function_context, right);
+
+                let checked = match operation {
+                    BinaryOperation::Power => self.build_power(left, right),
+                    _ => {
+                        let intrinsic_name = match operation {
+                            BinaryOperation::Add => "llvm.sadd.with.overflow",
+                            BinaryOperation::Subtract => "llvm.ssub.with.overflow",
+                            BinaryOperation::Multiply => "llvm.smul.with.overflow",
+                            _ => unreachable!(),
+                        };
+
+                        let intrinsic = Intrinsic::find(intrinsic_name).unwrap().get_declaration(&self.module, &[left.get_type().into()]).unwrap();
+                        self.builder.build_call(intrinsic, &[left.into(), right.into()], "checked").unwrap().try_as_basic_value().basic().unwrap().into_struct_value()
+                    }
+                };
+
+                let value = self.builder.build_extract_value(checked, 0, "value").unwrap().into_int_value();
+                let overflow = self.builder.build_extract_value(checked, 1, "overflow").unwrap().into_int_value();
+
+                function_context.values[result.0] = Some(value);
+                function_context.values[overflowed.0] = Some(overflow);
+            }
+            Instruction::Unary { result, operation, operand } => {
+                let operand = self.build_operand(function_context, operand);
+
+                let expression = match operation {
+                    UnaryOperation::Negate => self.builder.build_int_sub(operand.get_type().const_zero(), operand, "neg").unwrap(),
+                    UnaryOperation::Absolute => {
+                        let abs_function = Intrinsic::find("llvm.abs").unwrap().get_declaration(&self.module, &[operand.get_type().into()]).unwrap();
+
+                        let arguments = &[operand.into(), self.context.bool_type().const_int(0, false).into()];
+                        self.builder.build_call(abs_function, arguments, "abs").unwrap().try_as_basic_value().basic().unwrap().into_int_value()
+                    }
+                    UnaryOperation::Not => self.builder.build_xor(operand, self.context.bool_type().const_int(1, false), "not").unwrap(),
+                    UnaryOperation::BitwiseNot => self.builder.build_not(operand, "bitnot").unwrap(),
+                };
+
+                function_context.values[result.0] = Some(expression);
+            }
+            Instruction::Load { result, local } => {
+                let value = self.builder.build_load(function_context.locals[local.0], "load").unwrap();
+
+                function_context.values[result.0] = Some(value.into_int_value());
+            }
+            Instruction::Store { local, value } => {
+                let value = self.build_operand(function_context, value);
+
+                self.builder.build_store(function_context.locals[local.0], value).unwrap();
+            }
+            Instruction::Call { result, function: callee, arguments } => {
+                let callee = self.module.get_function(CodeGenerator::function_name(callee)).unwrap();
+
+                let arguments: Vec<BasicMetadataValueEnum> = arguments.iter().map(|argument| self.build_operand(function_context, argument).into()).collect();
+
+                let call = self.builder.build_call(callee, &arguments, "call").unwrap();
+
+                if let Some(result) = result {
+                    let value = call.try_as_basic_value().basic().map(BasicValueEnum::into_int_value);
+                    function_context.values[result.0] = value;
+                }
+            }
+            Instruction::Phi { result, incoming } => {
+                let phi = self.builder.build_phi(self.int_type(function.values[result.0]), "result").unwrap();
+
+                function_context.values[result.0] = Some(phi.as_basic_value().into_int_value());
+                function_context.pending_phis.push((phi, incoming.clone()));
+            }
+        }
+    }
+
+    fn build_power(&self, base: IntValue<'code_generator>, exponent: IntValue<'code_generator>) -> StructValue<'code_generator> {
+        let power = self.module.get_function(POWER_FUNCTION_NAME).unwrap();
+
+        self.builder.build_call(power, &[base.into(), exponent.into()], "power").unwrap().try_as_basic_value().basic().unwrap().into_struct_value()
+    }
+
+    fn build_terminator(&self, function_context: &FunctionContext<'code_generator>, terminator: &Terminator, source_name: &str) {
+        match terminator {
+            Terminator::Return(Some(operand)) => {
+                let value = self.build_operand(function_context, operand);
+                self.builder.build_return(Some(&value)).unwrap();
+            }
+            Terminator::Return(None) => {
+                self.builder.build_return(None).unwrap();
+            }
+            Terminator::Jump(block) => {
+                self.builder.build_unconditional_branch(function_context.blocks[block.0]).unwrap();
+            }
+            Terminator::Branch { condition, then_block, else_block } => {
+                let condition = self.build_operand(function_context, condition);
+                self.builder.build_conditional_branch(condition, function_context.blocks[then_block.0], function_context.blocks[else_block.0]).unwrap();
+            }
+            Terminator::Panic { kind, location } => {
+                self.build_panic(function_context, kind, location, source_name);
+            }
+            Terminator::Unreachable => {
+                self.builder.build_unreachable().unwrap();
+            }
+        }
+    }
+
+    fn build_panic(&self, function_context: &FunctionContext<'code_generator>, kind: &PanicKind, location: &Range, source_name: &str) {
+        let message = format!("panicked at {}:{}:{}: {}\n", source_name, location.start.line + 1, location.start.column + 1, kind.message());
+
+        let message_pointer = self.builder.build_global_string_ptr(&message, "panic.message").unwrap().as_pointer_value();
+        let message_length = self.context.i32_type().const_int(message.len() as u64, false);
+
+        match kind {
+            PanicKind::AssertionNotEqual(_, left, right) => {
+                let assertion = self.module.get_function(ASSERTION_FUNCTION_NAME).unwrap();
+                let left = self.build_operand(function_context, left);
+                let right = self.build_operand(function_context, right);
+
+                self.builder.build_call(assertion, &[message_pointer.into(), message_length.into(), left.into(), right.into()], "").unwrap();
+            }
+            _ => {
+                let panic = self.module.get_function(PANIC_FUNCTION_NAME).unwrap();
+
+                self.builder.build_call(panic, &[message_pointer.into(), message_length.into()], "").unwrap();
+            }
+        }
+
+        self.builder.build_unreachable().unwrap();
+    }
+}
\ No newline at end of file
@@ -1,30 +1,31 @@
-mod nodes;
+mod function;
+mod builtins;
+mod arithmetic;
+mod char;
+mod wasi;
+mod arguments;
+mod string;
+pub mod target;
 
 use inkwell::builder::Builder;
 use inkwell::context::Context;
 use inkwell::module::Module;
-use inkwell::types::{FunctionType};
-use inkwell::values::{FunctionValue, IntValue, PointerValue};
-use crate::utils::SymbolTable;
+use crate::mir::MirModule;
 
-pub trait CodeGen {
-    fn build(&mut self, code_generator: &mut CodeGenerator);
-}
-
-#[derive(Clone)]
-pub struct FunctionContext<'function> {
-    pub function_value: FunctionValue<'function>,
-    pub function_type: FunctionType<'function>,
-    pub parameter_names: Vec<String>,
-}
+const PANIC_FUNCTION_NAME: &str = "__axiom_panic";
+const PANIC_EXIT_CODE: u64 = 101;
+const ASSERTION_FUNCTION_NAME: &str = "__axiom_assertion_failed";
+const POWER_FUNCTION_NAME: &str = "__axiom_power";
+const ENTRY_FUNCTION_NAME: &str = "__axiom_main";
+const ARGUMENT_COUNT_NAME: &str = "__axiom_argc";
+const ARGUMENT_VALUES_NAME: &str = "__axiom_argv";
+const ENVIRONMENT_VALUES_NAME: &str = "__axiom_environ";
+const STRING_LENGTH_FUNCTION_NAME: &str = "__axiom_string_length";
 
 pub struct CodeGenerator<'code_generator> {
     pub context: &'code_generator Context,
     pub module: Module<'code_generator>,
     pub builder: Builder<'code_generator>,
-    pub variables: SymbolTable<String, PointerValue<'code_generator>>,
-    pub last_assign: Option<IntValue<'code_generator>>,
-    pub current_function_context: Option<FunctionContext<'code_generator>>
 }
 
 impl<'code_generator> CodeGenerator<'code_generator> {
@@ -36,9 +37,30 @@ impl<'code_generator> CodeGenerator<'code_generator> {
             module,
             builder,
             context,
-            variables: SymbolTable::new(),
-            last_assign: None,
-            current_function_context: None
+        }
+    }
+
+    pub fn build(&mut self, mir_module: &MirModule) {
+        self.build_all(std::slice::from_ref(mir_module));
+    }
+
+    /// Declares the functions of every module before building any body, so calls may cross modules.
+    pub fn build_all(&mut self, mir_modules: &[MirModule]) {
+        for function in mir_modules.iter().flat_map(|mir_module| &mir_module.functions) {
+            self.declare_function(function);
+        }
+
+        for mir_module in mir_modules {
+            for function in &mir_module.functions {
+                self.build_function(function, &mir_module.source_name);
+            }
+        }
+    }
+
+    fn function_name(name: &str) -> &str {
+        match name {
+            "main" => ENTRY_FUNCTION_NAME,
+            name => name,
         }
     }
 
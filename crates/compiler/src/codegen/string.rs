@@ -0,0 +1,200 @@
+use inkwell::AddressSpace;
+use inkwell::IntPredicate;
+use inkwell::module::Linkage;
+use inkwell::types::IntType;
+use inkwell::values::{FunctionValue, IntValue, PointerValue};
+use crate::codegen::{CodeGenerator, STRING_LENGTH_FUNCTION_NAME};
+
+const STRING_VALUE_PANIC_MESSAGE: &str = "panicked: string is not a valid i32\n";
+
+impl<'code_generator> CodeGenerator<'code_generator> {
+    /// A `str` is carried around as the address of a NUL-terminated byte string, widened to an
+    /// integer so it travels through locals, parameters and phis like every other value.
+    pub(crate) fn string_type(&self) -> IntType<'code_generator> {
+        self.context.i64_type()
+    }
+
+    pub(crate) fn build_string_constant(&self, value: &str) -> IntValue<'code_generator> {
+        let global = self.builder.build_global_string_ptr(value, "string").unwrap();
+
+        global.as_pointer_value().const_to_int(self.string_type())
+    }
+
+    pub(crate) fn build_string_pointer(&self, string: IntValue<'code_generator>) -> PointerValue<'code_generator> {
+        let i8_pointer_type = self.context.i8_type().ptr_type(AddressSpace::default());
+
+        self.builder.build_int_to_ptr(string, i8_pointer_type, "string.pointer").unwrap()
+    }
+
+    pub(crate) fn build_string_value(&self, pointer: PointerValue<'code_generator>) -> IntValue<'code_generator> {
+        self.builder.build_ptr_to_int(pointer, self.string_type(), "string").unwrap()
+    }
+
+    pub(crate) fn add_string_functions(&mut self) {
+        self.add_string_equal_function();
+        self.add_parse_function();
+    }
+
+    pub(crate) fn add_string_length_function(&mut self) -> FunctionValue<'code_generator> {
+        if let Some(function) = self.module.get_function(STRING_LENGTH_FUNCTION_NAME) {
+            return function;
+        }
+
+        let i8_type = self.context.i8_type();
+        let i32_type = self.context.i32_type();
+
+        let length_type = i32_type.fn_type(&[i8_type.ptr_type(AddressSpace::default()).into()], false);
+        let length_function = self.module.add_function(STRING_LENGTH_FUNCTION_NAME, length_type, Some(Linkage::Private));
+
+        let entry_block = self.context.append_basic_block(length_function, "entry");
+        let loop_block = self.context.append_basic_block(length_function, "loop");
+        let end_block = self.context.append_basic_block(length_function, "end");
+
+        self.builder.position_at_end(entry_block);
+        let string = length_function.get_nth_param(0).unwrap().into_pointer_value();
+        self.builder.build_unconditional_branch(loop_block).unwrap();
+
+        self.builder.position_at_end(loop_block);
+
+        let length = self.builder.build_phi(i32_type, "length").unwrap();
+        let length_value = length.as_basic_value().into_int_value();
+
+        let character_pointer = unsafe { self.builder.build_in_bounds_gep(string, &[length_value], "character.pointer") }.unwrap();
+        let character = self.builder.build_load(character_pointer, "character").unwrap().into_int_value();
+        let next_length = self.builder.build_int_add(length_value, i32_type.const_int(1, false), "next_length").unwrap();
+        length.add_incoming(&[(&i32_type.const_zero(), entry_block), (&next_length, loop_block)]);
+
+        let is_end = self.builder.build_int_compare(IntPredicate::EQ, character, i8_type.const_zero(), "is_end").unwrap();
+        self.builder.build_conditional_branch(is_end, end_block, loop_block).unwrap();
+
+        self.builder.position_at_end(end_block);
+        self.builder.build_return(Some(&length_value)).unwrap();
+
+        length_function
+    }
+
+    fn add_string_equal_function(&mut self) {
+        let i8_type = self.context.i8_type();
+        let i32_type = self.context.i32_type();
+        let bool_type = self.context.bool_type();
+        let string_type = self.string_type();
+
+        let equal_type = bool_type.fn_type(&[string_type.into(), string_type.into()], false);
+        let equal = self.module.add_function("str_eq", equal_type, Some(Linkage::Private));
+
+        let entry_block = self.context.append_basic_block(equal, "entry");
+        let loop_block = self.context.append_basic_block(equal, "loop");
+        let same_block = self.context.append_basic_block(equal, "same");
+        let return_block = self.context.append_basic_block(equal, "return");
+
+        self.builder.position_at_end(entry_block);
+
+        let left = self.build_string_pointer(equal.get_nth_param(0).unwrap().into_int_value());
+        let right = self.build_string_pointer(equal.get_nth_param(1).unwrap().into_int_value());
+        self.builder.build_unconditional_branch(loop_block).unwrap();
+
+        self.builder.position_at_end(loop_block);
+
+        let index = self.builder.build_phi(i32_type, "index").unwrap();
+        let index_value = index.as_basic_value().into_int_value();
+
+        let left_pointer = unsafe { self.builder.build_in_bounds_gep(left, &[index_value], "left.pointer") }.unwrap();
+        let right_pointer = unsafe { self.builder.build_in_bounds_gep(right, &[index_value], "right.pointer") }.unwrap();
+        let left_character = self.builder.build_load(left_pointer, "left.character").unwrap().into_int_value();
+        let right_character = self.builder.build_load(right_pointer, "right.character").unwrap().into_int_value();
+
+        let is_same = self.builder.build_int_compare(IntPredicate::EQ, left_character, right_character, "is_same").unwrap();
+        self.builder.build_conditional_branch(is_same, same_block, return_block).unwrap();
+
+        self.builder.position_at_end(same_block);
+
+        let is_end = self.builder.build_int_compare(IntPredicate::EQ, left_character, i8_type.const_zero(), "is_end").unwrap();
+        let next_index = self.builder.build_int_add(index_value, i32_type.const_int(1, false), "next_index").unwrap();
+        index.add_incoming(&[(&i32_type.const_zero(), entry_block), (&next_index, same_block)]);
+        self.builder.build_conditional_branch(is_end, return_block, loop_block).unwrap();
+
+        self.builder.position_at_end(return_block);
+
+        let result = self.builder.build_phi(bool_type, "result").unwrap();
+        result.add_incoming(&[(&bool_type.const_zero(), loop_block), (&bool_type.const_all_ones(), same_block)]);
+        self.builder.build_return(Some(&result.as_basic_value())).unwrap();
+    }
+
+    fn add_parse_function(&mut self) {
+        let i8_type = self.context.i8_type();
+        let i32_type = self.context.i32_type();
+        let i64_type = self.context.i64_type();
+        let i8_pointer_type = i8_type.ptr_type(AddressSpace::default());
+
+        let parse_type = i32_type.fn_type(&[self.string_type().into()], false);
+        let parse = self.module.add_function("parse_i32", parse_type, Some(Linkage::Private));
+
+        let entry_block = self.context.append_basic_block(parse, "entry");
+        let digits_block = self.context.append_basic_block(parse, "digits");
+        let digit_block = self.context.append_basic_block(parse, "digit");
+        let accumulate_block = self.context.append_basic_block(parse, "accumulate");
+        let finish_block = self.context.append_basic_block(parse, "finish");
+        let return_block = self.context.append_basic_block(parse, "return");
+        let invalid_block = self.context.append_basic_block(parse, "invalid");
+
+        self.builder.position_at_end(entry_block);
+
+        let value = self.build_string_pointer(parse.get_nth_param(0).unwrap().into_int_value());
+
+        let first = self.builder.build_load(value, "first").unwrap().into_int_value();
+        let is_negative = self.builder.build_int_compare(IntPredicate::EQ, first, i8_type.const_int(b'-' as u64, false), "is_negative").unwrap();
+        let is_positive = self.builder.build_int_compare(IntPredicate::EQ, first, i8_type.const_int(b'+' as u64, false), "is_positive").unwrap();
+        let has_sign = self.builder.build_or(is_negative, is_positive, "has_sign").unwrap();
+        let sign_length = self.builder.build_int_z_extend(has_sign, i32_type, "sign_length").unwrap();
+        let start = unsafe { self.builder.build_in_bounds_gep(value, &[sign_length], "start") }.unwrap();
+
+        let start_character = self.builder.build_load(start, "start_character").unwrap().into_int_value();
+        let is_empty = self.builder.build_int_compare(IntPredicate::EQ, start_character, i8_type.const_zero(), "is_empty").unwrap();
+        self.builder.build_conditional_branch(is_empty, invalid_block, digits_block).unwrap();
+
+        self.builder.position_at_end(digits_block);
+
+        let cursor = self.builder.build_phi(i8_pointer_type, "cursor").unwrap();
+        let accumulator = self.builder.build_phi(i64_type, "accumulator").unwrap();
+        let cursor_value = cursor.as_basic_value().into_pointer_value();
+        let accumulator_value = accumulator.as_basic_value().into_int_value();
+
+        let character = self.builder.build_load(cursor_value, "character").unwrap().into_int_value();
+        let is_end = self.builder.build_int_compare(IntPredicate::EQ, character, i8_type.const_zero(), "is_end").unwrap();
+        self.builder.build_conditional_branch(is_end, finish_block, digit_block).unwrap();
+
+        self.builder.position_at_end(digit_block);
+
+        let digit = self.builder.build_int_sub(character, i8_type.const_int(b'0' as u64, false), "digit").unwrap();
+        let is_digit = self.builder.build_int_compare(IntPredicate::ULT, digit, i8_type.const_int(10, false), "is_digit").unwrap();
+        self.builder.build_conditional_branch(is_digit, accumulate_block, invalid_block).unwrap();
+
+        self.builder.position_at_end(accumulate_block);
+
+        let digit = self.builder.build_int_z_extend(digit, i64_type, "digit.wide").unwrap();
+        let shifted = self.builder.build_int_mul(accumulator_value, i64_type.const_int(10, false), "shifted").unwrap();
+        let next_accumulator = self.builder.build_int_add(shifted, digit, "next_accumulator").unwrap();
+        let next_cursor = unsafe { self.builder.build_in_bounds_gep(cursor_value, &[i32_type.const_int(1, false)], "next_cursor") }.unwrap();
+        let is_too_large = self.builder.build_int_compare(IntPredicate::UGT, next_accumulator, i64_type.const_int(i32::MIN.unsigned_abs() as u64, false), "is_too_large").unwrap();
+        self.builder.build_conditional_branch(is_too_large, invalid_block, digits_block).unwrap();
+
+        cursor.add_incoming(&[(&start, entry_block), (&next_cursor, accumulate_block)]);
+        accumulator.add_incoming(&[(&i64_type.const_zero(), entry_block), (&next_accumulator, accumulate_block)]);
+
+        self.builder.position_at_end(finish_block);
+
+        let exceeds_maximum = self.builder.build_int_compare(IntPredicate::UGT, accumulator_value, i64_type.const_int(i32::MAX as u64, false), "exceeds_maximum").unwrap();
+        let is_positive_overflow = self.builder.build_and(exceeds_maximum, self.builder.build_not(is_negative, "is_not_negative").unwrap(), "is_positive_overflow").unwrap();
+        self.builder.build_conditional_branch(is_positive_overflow, invalid_block, return_block).unwrap();
+
+        self.builder.position_at_end(return_block);
+
+        let magnitude = self.builder.build_int_truncate(accumulator_value, i32_type, "magnitude").unwrap();
+        let negated = self.builder.build_int_sub(i32_type.const_zero(), magnitude, "negated").unwrap();
+        let result = self.builder.build_select(is_negative, negated, magnitude, "result").unwrap();
+        self.builder.build_return(Some(&result)).unwrap();
+
+        self.builder.position_at_end(invalid_block);
+        self.build_message_panic(STRING_VALUE_PANIC_MESSAGE);
+    }
+}
\ No newline at end of file
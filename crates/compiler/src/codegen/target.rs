@@ -0,0 +1,93 @@
+use std::error::Error;
+use std::path::Path;
+use inkwell::OptimizationLevel;
+use inkwell::passes::PassBuilderOptions;
+use inkwell::targets::{CodeModel, FileType, InitializationConfig, RelocMode, Target, TargetMachine, TargetTriple};
+use crate::codegen::CodeGenerator;
+
+pub fn host_triple() -> String {
+    TargetMachine::get_default_triple().as_str().to_string_lossy().into_owned()
+}
+
+pub fn is_host_triple(triple: &str) -> bool {
+    let normalized_triple = TargetMachine::normalize_triple(&TargetTriple::create(triple));
+    let normalized_host_triple = TargetMachine::normalize_triple(&TargetMachine::get_default_triple());
+
+    normalized_triple == normalized_host_triple
+}
+
+pub fn is_wasi_triple(triple: &str) -> bool {
+    let mut components = triple.split('-');
+    let architecture = components.next().unwrap_or_default();
+
+    architecture.starts_with("wasm") && components.any(|component| component.starts_with("wasi"))
+}
+
+pub fn optimization_level(level: u8) -> OptimizationLevel {
+    match level {
+        0 => OptimizationLevel::None,
+        1 => OptimizationLevel::Less,
+        2 => OptimizationLevel::Default,
+        _ => OptimizationLevel::Aggressive,
+    }
+}
+
+pub fn initialize_target(triple: &str) {
+    let config = InitializationConfig::default();
+    let architecture = triple.split('-').next().unwrap_or_default();
+
+    match architecture {
+        "x86_64" | "i386" | "i486" | "i586" | "i686" => Target::initialize_x86(&config),
+        "aarch64" | "arm64" => Target::initialize_aarch64(&config),
+        architecture if architecture.starts_with("arm") || architecture.starts_with("thumb") => Target::initialize_arm(&config),
+        "riscv32" | "riscv64" => Target::initialize_riscv(&config),
+        "wasm32" | "wasm64" => Target::initialize_webassembly(&config),
+        _ => Target::initialize_all(&config),
+    }
+}
+
+impl<'code_generator> CodeGenerator<'code_generator> {
+    pub fn create_target_machine(&self, triple: &str, optimization_level: OptimizationLevel) -> Result<TargetMachine, Box<dyn Error>> {
+        initialize_target(triple);
+
+        let target_triple = TargetMachine::normalize_triple(&TargetTriple::create(triple));
+        let target = Target::from_triple(&target_triple).map_err(|error| format!("Unsupported target '{}': {}", triple, error))?;
+
+        let (cpu, features) = if is_host_triple(triple) {
+            (TargetMachine::get_host_cpu_name().to_string(), TargetMachine::get_host_cpu_features().to_string())
+        } else {
+            (String::new(), String::new())
+        };
+
+        let target_machine = target.create_target_machine(&target_triple, &cpu, &features, optimization_level, RelocMode::PIC, CodeModel::Default)
+            .ok_or(format!("Unable to create target machine for '{}'", triple))?;
+
+        self.module.set_triple(&target_triple);
+        self.module.set_data_layout(&target_machine.get_target_data().get_data_layout());
+
+        Ok(target_machine)
+    }
+
+    pub fn run_optimization_passes(&self, target_machine: &TargetMachine, level: u8) -> Result<(), Box<dyn Error>> {
+        if level == 0 {
+            return Ok(());
+        }
+
+        self.module.run_passes(&format!("default<O{}>", level.min(3)), target_machine, PassBuilderOptions::create())
+            .map_err(|error| format!("Unable to run optimization passes: {}", error))?;
+
+        Ok(())
+    }
+
+    pub fn write_llvm_ir_file(&self, path: &Path) -> Result<(), Box<dyn Error>> {
+        self.module.print_to_file(path).map_err(|error| format!("Unable to write '{}': {}", path.display(), error))?;
+
+        Ok(())
+    }
+
+    pub fn write_object_file(&self, target_machine: &TargetMachine, path: &Path) -> Result<(), Box<dyn Error>> {
+        target_machine.write_to_file(&self.module, FileType::Object, path)?;
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,410 @@
+use std::error::Error;
+use inkwell::AddressSpace;
+use inkwell::attributes::AttributeLoc;
+use inkwell::IntPredicate;
+use inkwell::module::Linkage;
+use inkwell::types::FunctionType;
+use inkwell::values::FunctionValue;
+use crate::codegen::{CodeGenerator, ENTRY_FUNCTION_NAME, ENVIRONMENT_VALUES_NAME, PANIC_EXIT_CODE};
+
+const WASI_MODULE_NAME: &str = "wasi_snapshot_preview1";
+const PRINT_BUFFER_SIZE: u64 = 12;
+const STDOUT_FILE_DESCRIPTOR: u64 = 1;
+const STDERR_FILE_DESCRIPTOR: u64 = 2;
+const WRITE_FUNCTION_NAME: &str = "__axiom_write";
+const WRITE_INTEGER_FUNCTION_NAME: &str = "__axiom_write_integer";
+
+impl<'code_generator> CodeGenerator<'code_generator> {
+    fn add_wasi_import(&self, import_name: &str, function_type: FunctionType<'code_generator>) -> FunctionValue<'code_generator> {
+        let function_name = format!("__wasi_{}", import_name);
+
+        if let Some(function) = self.module.get_function(&function_name) {
+            return function;
+        }
+
+        let function = self.module.add_function(&function_name, function_type, Some(Linkage::External));
+
+        function.add_attribute(AttributeLoc::Function, self.context.create_string_attribute("wasm-import-module", WASI_MODULE_NAME));
+        function.add_attribute(AttributeLoc::Function, self.context.create_string_attribute("wasm-import-name", import_name));
+
+        function
+    }
+
+    fn add_wasi_write_function(&mut self) -> FunctionValue<'code_generator> {
+        if let Some(function) = self.module.get_function(WRITE_FUNCTION_NAME) {
+            return function;
+        }
+
+        let i32_type = self.context.i32_type();
+        let i8_pointer_type = self.context.i8_type().ptr_type(AddressSpace::default());
+
+        let fd_write_type = i32_type.fn_type(&[i32_type.into(), i32_type.into(), i32_type.into(), i32_type.into()], false);
+        let fd_write = self.add_wasi_import("fd_write", fd_write_type);
+
+        let write_type = self.context.void_type().fn_type(&[i32_type.into(), i8_pointer_type.into(), i32_type.into()], false);
+        let write = self.module.add_function(WRITE_FUNCTION_NAME, write_type, Some(Linkage::Private));
+
+        let entry_block = self.context.append_basic_block(write, "entry");
+        self.builder.position_at_end(entry_block);
+
+        let io_vector = self.builder.build_alloca(i32_type.array_type(2), "io_vector").unwrap();
+        let written = self.builder.build_alloca(i32_type, "written").unwrap();
+
+        let file_descriptor = write.get_nth_param(0).unwrap().into_int_value();
+        let buffer = write.get_nth_param(1).unwrap().into_pointer_value();
+        let length = write.get_nth_param(2).unwrap().into_int_value();
+
+        let buffer_address = self.builder.build_ptr_to_int(buffer, i32_type, "buffer.address").unwrap();
+        let io_vector_buffer = unsafe { self.builder.build_in_bounds_gep(io_vector, &[i32_type.const_zero(), i32_type.const_zero()], "io_vector.buffer") }.unwrap();
+        let io_vector_length = unsafe { self.builder.build_in_bounds_gep(io_vector, &[i32_type.const_zero(), i32_type.const_int(1, false)], "io_vector.length") }.unwrap();
+        self.builder.build_store(io_vector_buffer, buffer_address).unwrap();
+        self.builder.build_store(io_vector_length, length).unwrap();
+
+        let io_vector_address = self.builder.build_ptr_to_int(io_vector, i32_type, "io_vector.address").unwrap();
+        let written_address = self.builder.build_ptr_to_int(written, i32_type, "written.address").unwrap();
+
+        let arguments = &[file_descriptor.into(), io_vector_address.into(), i32_type.const_int(1, false).into(), written_address.into()];
+        self.builder.build_call(fd_write, arguments, "fd_write").unwrap();
+        self.builder.build_return(None).unwrap();
+
+        write
+    }
+
+    fn add_wasi_write_integer_function(&mut self) -> FunctionValue<'code_generator> {
+        if let Some(function) = self.module.get_function(WRITE_INTEGER_FUNCTION_NAME) {
+            return function;
+        }
+
+        let i8_type = self.context.i8_type();
+        let i32_type = self.context.i32_type();
+
+        let write = self.add_wasi_write_function();
+
+        let write_integer_type = self.context.void_type().fn_type(&[i32_type.into(), i32_type.into()], false);
+        let write_integer = self.module.add_function(WRITE_INTEGER_FUNCTION_NAME, write_integer_type, Some(Linkage::Private));
+
+        let entry_block = self.context.append_basic_block(write_integer, "entry");
+        let digits_block = self.context.append_basic_block(write_integer, "digits");
+        let write_block = self.context.append_basic_block(write_integer, "write");
+
+        self.builder.position_at_end(entry_block);
+
+        let buffer = self.builder.build_alloca(i8_type.array_type(PRINT_BUFFER_SIZE as u32), "buffer").unwrap();
+
+        let file_descriptor = write_integer.get_nth_param(0).unwrap().into_int_value();
+        let value = write_integer.get_nth_param(1).unwrap().into_int_value();
+        let is_negative = self.builder.build_int_compare(IntPredicate::SLT, value, i32_type.const_zero(), "is_negative").unwrap();
+        let negated = self.builder.build_int_sub(i32_type.const_zero(), value, "negated").unwrap();
+        let magnitude = self.builder.build_select(is_negative, negated, value, "magnitude").unwrap().into_int_value();
+
+        let last_index = i32_type.const_int(PRINT_BUFFER_SIZE - 1, false);
+        let newline_pointer = unsafe { self.builder.build_in_bounds_gep(buffer, &[i32_type.const_zero(), last_index], "newline") }.unwrap();
+        self.builder.build_store(newline_pointer, i8_type.const_int(b'\n' as u64, false)).unwrap();
+
+        self.builder.build_unconditional_branch(digits_block).unwrap();
+
+        self.builder.position_at_end(digits_block);
+
+        let index = self.builder.build_phi(i32_type, "index").unwrap();
+        let remaining = self.builder.build_phi(i32_type, "remaining").unwrap();
+
+        let ten = i32_type.const_int(10, false);
+        let remaining_value = remaining.as_basic_value().into_int_value();
+        let digit = self.builder.build_int_unsigned_rem(remaining_value, ten, "digit").unwrap();
+        let quotient = self.builder.build_int_unsigned_div(remaining_value, ten, "quotient").unwrap();
+
+        let next_index = self.builder.build_int_sub(index.as_basic_value().into_int_value(), i32_type.const_int(1, false), "next_index").unwrap();
+        let digit = self.builder.build_int_truncate(digit, i8_type, "digit.byte").unwrap();
+        let character = self.builder.build_int_add(digit, i8_type.const_int(b'0' as u64, false), "character").unwrap();
+        let character_pointer = unsafe { self.builder.build_in_bounds_gep(buffer, &[i32_type.const_zero(), next_index], "character.pointer") }.unwrap();
+        self.builder.build_store(character_pointer, character).unwrap();
+
+        index.add_incoming(&[(&last_index, entry_block), (&next_index, digits_block)]);
+        remaining.add_incoming(&[(&magnitude, entry_block), (&quotient, digits_block)]);
+
+        let has_more_digits = self.builder.build_int_compare(IntPredicate::NE, quotient, i32_type.const_zero(), "has_more_digits").unwrap();
+        self.builder.build_conditional_branch(has_more_digits, digits_block, write_block).unwrap();
+
+        self.builder.position_at_end(write_block);
+
+        let sign_index = self.builder.build_int_sub(next_index, i32_type.const_int(1, false), "sign_index").unwrap();
+        let sign_pointer = unsafe { self.builder.build_in_bounds_gep(buffer, &[i32_type.const_zero(), sign_index], "sign.pointer") }.unwrap();
+        self.builder.build_store(sign_pointer, i8_type.const_int(b'-' as u64, false)).unwrap();
+
+        let start_index = self.builder.build_select(is_negative, sign_index, next_index, "start_index").unwrap().into_int_value();
+        let start_pointer = unsafe { self.builder.build_in_bounds_gep(buffer, &[i32_type.const_zero(), start_index], "start.pointer") }.unwrap();
+        let length = self.builder.build_int_sub(i32_type.const_int(PRINT_BUFFER_SIZE, false), start_index, "length").unwrap();
+
+        self.builder.build_call(write, &[file_descriptor.into(), start_pointer.into(), length.into()], "").unwrap();
+        self.builder.build_return(None).unwrap();
+
+        write_integer
+    }
+
+    pub(crate) fn add_wasi_output_shim(&mut self) {
+        let i32_type = self.context.i32_type();
+
+        let write_integer = self.add_wasi_write_integer_function();
+
+        let print_type = self.context.void_type().fn_type(&[i32_type.into()], false);
+        let print = self.module.add_function("print", print_type, None);
+
+        let entry_block = self.context.append_basic_block(print, "entry");
+        self.builder.position_at_end(entry_block);
+
+        let value = print.get_nth_param(0).unwrap();
+
+        self.builder.build_call(write_integer, &[i32_type.const_int(STDOUT_FILE_DESCRIPTOR, false).into(), value.into()], "").unwrap();
+        self.builder.build_return(None).unwrap();
+
+        let write = self.add_wasi_write_function();
+        let string_length = self.add_string_length_function();
+        let stdout = i32_type.const_int(STDOUT_FILE_DESCRIPTOR, false);
+
+        let print_str_type = self.context.void_type().fn_type(&[self.string_type().into()], false);
+        let print_str = self.module.add_function("print_str", print_str_type, None);
+
+        let entry_block = self.context.append_basic_block(print_str, "entry");
+        self.builder.position_at_end(entry_block);
+
+        let value = self.build_string_pointer(print_str.get_nth_param(0).unwrap().into_int_value());
+        let length = self.builder.build_call(string_length, &[value.into()], "length").unwrap().try_as_basic_value().basic().unwrap();
+        let newline = self.builder.build_global_string_ptr("\n", "print_str.newline").unwrap().as_pointer_value();
+
+        self.builder.build_call(write, &[stdout.into(), value.into(), length.into()], "").unwrap();
+        self.builder.build_call(write, &[stdout.into(), newline.into(), i32_type.const_int(1, false).into()], "").unwrap();
+        self.builder.build_return(None).unwrap();
+    }
+
+    /// WASI has no `getenv`, so `env` walks the NUL-terminated `NAME=value` list `_start` stores.
+    pub(crate) fn add_wasi_environment_function(&mut self) {
+        let i8_type = self.context.i8_type();
+        let i32_type = self.context.i32_type();
+        let i8_pointer_type = i8_type.ptr_type(AddressSpace::default());
+        let i8_pointer_pointer_type = i8_pointer_type.ptr_type(AddressSpace::default());
+
+        let empty_environment = self.module.add_global(i8_pointer_type.array_type(1), None, "__axiom_empty_environment");
+        empty_environment.set_linkage(Linkage::Private);
+        empty_environment.set_initializer(&i8_pointer_type.array_type(1).const_zero());
+
+        let environment_values = self.module.add_global(i8_pointer_pointer_type, None, ENVIRONMENT_VALUES_NAME);
+        environment_values.set_linkage(Linkage::Internal);
+        environment_values.set_initializer(&empty_environment.as_pointer_value().const_cast(i8_pointer_pointer_type));
+
+        let env_type = self.string_type().fn_type(&[self.string_type().into()], false);
+        let env = self.module.add_function("env", env_type, Some(Linkage::Private));
+
+        let entry_block = self.context.append_basic_block(env, "entry");
+        let entries_block = self.context.append_basic_block(env, "entries");
+        let compare_block = self.context.append_basic_block(env, "compare");
+        let character_block = self.context.append_basic_block(env, "character");
+        let next_character_block = self.context.append_basic_block(env, "next_character");
+        let name_end_block = self.context.append_basic_block(env, "name_end");
+        let found_block = self.context.append_basic_block(env, "found");
+        let next_entry_block = self.context.append_basic_block(env, "next_entry");
+        let unset_block = self.context.append_basic_block(env, "unset");
+
+        self.builder.position_at_end(entry_block);
+
+        let name = self.build_string_pointer(env.get_nth_param(0).unwrap().into_int_value());
+        let values = self.builder.build_load(environment_values.as_pointer_value(), "environ").unwrap().into_pointer_value();
+        self.builder.build_unconditional_branch(entries_block).unwrap();
+
+        self.builder.position_at_end(entries_block);
+
+        let index = self.builder.build_phi(i32_type, "index").unwrap();
+        let index_value = index.as_basic_value().into_int_value();
+
+        let entry_pointer = unsafe { self.builder.build_in_bounds_gep(values, &[index_value], "entry.pointer") }.unwrap();
+        let entry = self.builder.build_load(entry_pointer, "entry").unwrap().into_pointer_value();
+        let is_last = self.builder.build_is_null(entry, "is_last").unwrap();
+        self.builder.build_conditional_branch(is_last, unset_block, compare_block).unwrap();
+
+        self.builder.position_at_end(compare_block);
+
+        let offset = self.builder.build_phi(i32_type, "offset").unwrap();
+        let offset_value = offset.as_basic_value().into_int_value();
+
+        let name_character_pointer = unsafe { self.builder.build_in_bounds_gep(name, &[offset_value], "name.character.pointer") }.unwrap();
+        let entry_character_pointer = unsafe { self.builder.build_in_bounds_gep(entry, &[offset_value], "entry.character.pointer") }.unwrap();
+        let name_character = self.builder.build_load(name_character_pointer, "name.character").unwrap().into_int_value();
+        let entry_character = self.builder.build_load(entry_character_pointer, "entry.character").unwrap().into_int_value();
+
+        let is_name_end = self.builder.build_int_compare(IntPredicate::EQ, name_character, i8_type.const_zero(), "is_name_end").unwrap();
+        self.builder.build_conditional_branch(is_name_end, name_end_block, character_block).unwrap();
+
+        self.builder.position_at_end(character_block);
+
+        let is_same = self.builder.build_int_compare(IntPredicate::EQ, name_character, entry_character, "is_same").unwrap();
+        self.builder.build_conditional_branch(is_same, next_character_block, next_entry_block).unwrap();
+
+        self.builder.position_at_end(next_character_block);
+
+        let next_offset = self.builder.build_int_add(offset_value, i32_type.const_int(1, false), "next_offset").unwrap();
+        offset.add_incoming(&[(&i32_type.const_zero(), entries_block), (&next_offset, next_character_block)]);
+        self.builder.build_unconditional_branch(compare_block).unwrap();
+
+        self.builder.position_at_end(name_end_block);
+
+        let is_separator = self.builder.build_int_compare(IntPredicate::EQ, entry_character, i8_type.const_int(b'=' as u64, false), "is_separator").unwrap();
+        self.builder.build_conditional_branch(is_separator, found_block, next_entry_block).unwrap();
+
+        self.builder.position_at_end(found_block);
+
+        let value_offset = self.builder.build_int_add(offset_value, i32_type.const_int(1, false), "value_offset").unwrap();
+        let value = unsafe { self.builder.build_in_bounds_gep(entry, &[value_offset], "value") }.unwrap();
+        let value = self.build_string_value(value);
+        self.builder.build_return(Some(&value)).unwrap();
+
+        self.builder.position_at_end(next_entry_block);
+
+        let next_index = self.builder.build_int_add(index_value, i32_type.const_int(1, false), "next_index").unwrap();
+        index.add_incoming(&[(&i32_type.const_zero(), entry_block), (&next_index, next_entry_block)]);
+        self.builder.build_unconditional_branch(entries_block).unwrap();
+
+        self.builder.position_at_end(unset_block);
+        self.builder.build_return(Some(&self.build_string_constant(""))).unwrap();
+    }
+
+    pub(crate) fn add_wasi_panic_routine(&mut self) {
+        let i32_type = self.context.i32_type();
+
+        let write = self.add_wasi_write_function();
+
+        let proc_exit_type = self.context.void_type().fn_type(&[i32_type.into()], false);
+        let proc_exit = self.add_wasi_import("proc_exit", proc_exit_type);
+
+        let panic = self.add_panic_function();
+
+        let entry_block = self.context.append_basic_block(panic, "entry");
+        self.builder.position_at_end(entry_block);
+
+        let message = panic.get_nth_param(0).unwrap();
+        let length = panic.get_nth_param(1).unwrap();
+
+        self.builder.build_call(write, &[i32_type.const_int(STDERR_FILE_DESCRIPTOR, false).into(), message.into(), length.into()], "").unwrap();
+        self.builder.build_call(proc_exit, &[i32_type.const_int(PANIC_EXIT_CODE, false).into()], "").unwrap();
+        self.builder.build_unreachable().unwrap();
+    }
+
+    pub(crate) fn add_wasi_assertion_routine(&mut self) {
+        let i32_type = self.context.i32_type();
+        let stderr = i32_type.const_int(STDERR_FILE_DESCRIPTOR, false);
+
+        let write = self.add_wasi_write_function();
+        let write_integer = self.add_wasi_write_integer_function();
+
+        let proc_exit_type = self.context.void_type().fn_type(&[i32_type.into()], false);
+        let proc_exit = self.add_wasi_import("proc_exit", proc_exit_type);
+
+        let assertion = self.add_assertion_function();
+
+        let entry_block = self.context.append_basic_block(assertion, "entry");
+        self.builder.position_at_end(entry_block);
+
+        let message = assertion.get_nth_param(0).unwrap();
+        let length = assertion.get_nth_param(1).unwrap();
+        let left = assertion.get_nth_param(2).unwrap();
+        let right = assertion.get_nth_param(3).unwrap();
+
+        self.builder.build_call(write, &[stderr.into(), message.into(), length.into()], "").unwrap();
+
+        for (label, value) in [("  left: ", left), (" right: ", right)] {
+            let label_pointer = self.builder.build_global_string_ptr(label, "assertion.label").unwrap().as_pointer_value();
+            let label_length = i32_type.const_int(label.len() as u64, false);
+
+            self.builder.build_call(write, &[stderr.into(), label_pointer.into(), label_length.into()], "").unwrap();
+            self.builder.build_call(write_integer, &[stderr.into(), value.into()], "").unwrap();
+        }
+
+        self.builder.build_call(proc_exit, &[i32_type.const_int(PANIC_EXIT_CODE, false).into()], "").unwrap();
+        self.builder.build_unreachable().unwrap();
+    }
+
+    pub fn add_wasi_start(&mut self) -> Result<(), Box<dyn Error>> {
+        let main = self.module.get_function(ENTRY_FUNCTION_NAME).ok_or("Function 'main' not found")?;
+
+        main.add_attribute(AttributeLoc::Function, self.context.create_string_attribute("wasm-export-name", "main"));
+
+        let i8_type = self.context.i8_type();
+        let i32_type = self.context.i32_type();
+        let i8_pointer_type = i8_type.ptr_type(AddressSpace::default());
+
+        let args_sizes_get_type = i32_type.fn_type(&[i32_type.into(), i32_type.into()], false);
+        let args_sizes_get = self.add_wasi_import("args_sizes_get", args_sizes_get_type);
+
+        let args_get_type = i32_type.fn_type(&[i32_type.into(), i32_type.into()], false);
+        let args_get = self.add_wasi_import("args_get", args_get_type);
+
+        let proc_exit_type = self.context.void_type().fn_type(&[i32_type.into()], false);
+        let proc_exit = self.add_wasi_import("proc_exit", proc_exit_type);
+
+        let start_type = self.context.void_type().fn_type(&[], false);
+        let start = self.module.add_function("_start", start_type, None);
+
+        let entry_block = self.context.append_basic_block(start, "entry");
+        self.builder.position_at_end(entry_block);
+
+        let count = self.builder.build_alloca(i32_type, "count").unwrap();
+        let buffer_size = self.builder.build_alloca(i32_type, "buffer_size").unwrap();
+
+        let count_address = self.builder.build_ptr_to_int(count, i32_type, "count.address").unwrap();
+        let buffer_size_address = self.builder.build_ptr_to_int(buffer_size, i32_type, "buffer_size.address").unwrap();
+        self.builder.build_call(args_sizes_get, &[count_address.into(), buffer_size_address.into()], "args_sizes_get").unwrap();
+
+        let count = self.builder.build_load(count, "count").unwrap().into_int_value();
+        let buffer_size = self.builder.build_load(buffer_size, "buffer_size").unwrap().into_int_value();
+
+        let values = self.builder.build_array_alloca(i8_pointer_type, count, "values").unwrap();
+        let buffer = self.builder.build_array_alloca(i8_type, buffer_size, "buffer").unwrap();
+
+        let values_address = self.builder.build_ptr_to_int(values, i32_type, "values.address").unwrap();
+        let buffer_address = self.builder.build_ptr_to_int(buffer, i32_type, "buffer.address").unwrap();
+        self.builder.build_call(args_get, &[values_address.into(), buffer_address.into()], "args_get").unwrap();
+
+        self.build_environment_setup();
+
+        let exit_code = self.build_entry_call(main, count, values);
+
+        self.builder.build_call(proc_exit, &[exit_code.into()], "proc_exit").unwrap();
+        self.builder.build_unreachable().unwrap();
+
+        Ok(())
+    }
+
+    fn build_environment_setup(&self) {
+        let i8_type = self.context.i8_type();
+        let i32_type = self.context.i32_type();
+        let i8_pointer_type = i8_type.ptr_type(AddressSpace::default());
+
+        let environ_sizes_get_type = i32_type.fn_type(&[i32_type.into(), i32_type.into()], false);
+        let environ_sizes_get = self.add_wasi_import("environ_sizes_get", environ_sizes_get_type);
+
+        let environ_get_type = i32_type.fn_type(&[i32_type.into(), i32_type.into()], false);
+        let environ_get = self.add_wasi_import("environ_get", environ_get_type);
+
+        let count = self.builder.build_alloca(i32_type, "environ.count").unwrap();
+        let buffer_size = self.builder.build_alloca(i32_type, "environ.buffer_size").unwrap();
+
+        let count_address = self.builder.build_ptr_to_int(count, i32_type, "environ.count.address").unwrap();
+        let buffer_size_address = self.builder.build_ptr_to_int(buffer_size, i32_type, "environ.buffer_size.address").unwrap();
+        self.builder.build_call(environ_sizes_get, &[count_address.into(), buffer_size_address.into()], "environ_sizes_get").unwrap();
+
+        let count = self.builder.build_load(count, "environ.count").unwrap().into_int_value();
+        let buffer_size = self.builder.build_load(buffer_size, "environ.buffer_size").unwrap().into_int_value();
+
+        let slots = self.builder.build_int_add(count, i32_type.const_int(1, false), "environ.slots").unwrap();
+        let values = self.builder.build_array_alloca(i8_pointer_type, slots, "environ.values").unwrap();
+        let buffer = self.builder.build_array_alloca(i8_type, buffer_size, "environ.buffer").unwrap();
+
+        let values_address = self.builder.build_ptr_to_int(values, i32_type, "environ.values.address").unwrap();
+        let buffer_address = self.builder.build_ptr_to_int(buffer, i32_type, "environ.buffer.address").unwrap();
+        self.builder.build_call(environ_get, &[values_address.into(), buffer_address.into()], "environ_get").unwrap();
+
+        let terminator = unsafe { self.builder.build_in_bounds_gep(values, &[count], "environ.terminator") }.unwrap();
+        self.builder.build_store(terminator, i8_pointer_type.const_null()).unwrap();
+
+        let environment_values = self.module.get_global(ENVIRONMENT_VALUES_NAME).unwrap();
+        self.builder.build_store(environment_values.as_pointer_value(), values).unwrap();
+    }
+}
\ No newline at end of file
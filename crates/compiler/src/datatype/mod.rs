@@ -7,7 +7,11 @@ pub enum DataType {
 
     I32,
     Bool,
+    Char,
+    Str,
+    Args,
     Function(Vec<DataType>, Box<DataType>),
+    Tuple(Vec<DataType>),
     Type(Box<DataType>)
 }
 
@@ -18,6 +22,9 @@ impl Display for DataType {
             DataType::ToBeInferred => write!(f, "unknown"),
             DataType::I32 => write!(f, "i32"),
             DataType::Bool => write!(f, "bool"),
+            DataType::Char => write!(f, "char"),
+            DataType::Str => write!(f, "str"),
+            DataType::Args => write!(f, "args"),
             DataType::Function(param_datatypes, output_datatype) => {
                 write!(f, "fn(")?;
 
@@ -32,6 +39,11 @@ impl Display for DataType {
 
                 write!(f, ") -> {}", output_datatype)
             },
+            DataType::Tuple(element_datatypes) => {
+                let element_datatypes: Vec<String> = element_datatypes.iter().map(|element_datatype| element_datatype.to_string()).collect();
+
+                write!(f, "({})", element_datatypes.join(", "))
+            },
             DataType::Type(data_type) => write!(f, "{data_type}"),
         }
     }
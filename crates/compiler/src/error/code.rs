@@ -0,0 +1,109 @@
+use std::fmt::{Display, Formatter, Result};
+use crate::error::AxiomError;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ErrorCode {
+    E0001,
+    E0002,
+    E0003,
+    E0004,
+    E0005,
+    E0006,
+    E0007,
+    E0008,
+    E0009,
+    E0010,
+    E0011,
+    E0012,
+    E0013,
+    E0014,
+    E0015,
+    E0016,
+    E0017,
+}
+
+impl ErrorCode {
+    pub const ALL: [ErrorCode; 17] = [
+        ErrorCode::E0001, ErrorCode::E0002, ErrorCode::E0003, ErrorCode::E0004, ErrorCode::E0005, ErrorCode::E0006,
+        ErrorCode::E0007, ErrorCode::E0008, ErrorCode::E0009, ErrorCode::E0010, ErrorCode::E0011, ErrorCode::E0012,
+        ErrorCode::E0013, ErrorCode::E0014, ErrorCode::E0015, ErrorCode::E0016, ErrorCode::E0017,
+    ];
+
+    pub fn parse(code: &str) -> Option<ErrorCode> {
+        ErrorCode::ALL.into_iter().find(|error_code| error_code.to_string().eq_ignore_ascii_case(code))
+    }
+
+    pub fn explanation(&self) -> &'static str {
+        match self {
+            ErrorCode::E0001 => include_str!("explanations/E0001.md"),
+            ErrorCode::E0002 => include_str!("explanations/E0002.md"),
+            ErrorCode::E0003 => include_str!("explanations/E0003.md"),
+            ErrorCode::E0004 => include_str!("explanations/E0004.md"),
+            ErrorCode::E0005 => include_str!("explanations/E0005.md"),
+            ErrorCode::E0006 => include_str!("explanations/E0006.md"),
+            ErrorCode::E0007 => include_str!("explanations/E0007.md"),
+            ErrorCode::E0008 => include_str!("explanations/E0008.md"),
+            ErrorCode::E0009 => include_str!("explanations/E0009.md"),
+            ErrorCode::E0010 => include_str!("explanations/E0010.md"),
+            ErrorCode::E0011 => include_str!("explanations/E0011.md"),
+            ErrorCode::E0012 => include_str!("explanations/E0012.md"),
+            ErrorCode::E0013 => include_str!("explanations/E0013.md"),
+            ErrorCode::E0014 => include_str!("explanations/E0014.md"),
+            ErrorCode::E0015 => include_str!("explanations/E0015.md"),
+            ErrorCode::E0016 => include_str!("explanations/E0016.md"),
+            ErrorCode::E0017 => include_str!("explanations/E0017.md"),
+        }
+    }
+}
+
+impl Display for ErrorCode {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl AxiomError {
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            AxiomError::UnexpectedEOF(_) => ErrorCode::E0001,
+            AxiomError::SyntaxError(_, _) => ErrorCode::E0002,
+            AxiomError::DuplicatedIdentifier(_, _, _) => ErrorCode::E0003,
+            AxiomError::IdentifierUsedBeforeDeclaration(_, _) => ErrorCode::E0004,
+            AxiomError::WrongDataType(_, _, _) => ErrorCode::E0005,
+            AxiomError::NotAFunction(_, _) => ErrorCode::E0006,
+            AxiomError::MismatchedNumberOfParameters(_, _, _, _) => ErrorCode::E0007,
+            AxiomError::NotAType(_, _) => ErrorCode::E0008,
+            AxiomError::LiteralOutOfRange(_, _, _) => ErrorCode::E0009,
+            AxiomError::UnexpectedCharacter(_, _) => ErrorCode::E0010,
+            AxiomError::UnterminatedLiteral(_, _) => ErrorCode::E0011,
+            AxiomError::UnknownAttribute(_, _) => ErrorCode::E0012,
+            AxiomError::UnknownLint(_, _) => ErrorCode::E0013,
+            AxiomError::InvalidMainSignature(_) => ErrorCode::E0014,
+            AxiomError::InvalidTestFunction(_) => ErrorCode::E0015,
+            AxiomError::InvalidDestructuring(_, _, _) => ErrorCode::E0016,
+            AxiomError::MalformedNumberLiteral(_, _, _) => ErrorCode::E0017,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::code::ErrorCode;
+
+    #[test]
+    fn every_code_has_an_explanation() {
+        for code in ErrorCode::ALL {
+            assert!(!code.explanation().trim().is_empty(), "{} has no explanation", code);
+        }
+    }
+
+    #[test]
+    fn every_code_parses() {
+        for code in ErrorCode::ALL {
+            assert_eq!(ErrorCode::parse(&code.to_string()), Some(code));
+            assert_eq!(ErrorCode::parse(&code.to_string().to_lowercase()), Some(code));
+        }
+
+        assert_eq!(ErrorCode::parse("E9999"), None);
+    }
+}
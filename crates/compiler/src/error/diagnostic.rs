@@ -0,0 +1,234 @@
+use std::fmt::{Display, Formatter, Result};
+use crate::datatype::DataType;
+use crate::error::AxiomError;
+use crate::error::code::ErrorCode;
+use crate::error::location::Range;
+use crate::lint::Lint;
+
+const RED: &str = "\x1b[1;31m";
+const YELLOW: &str = "\x1b[1;33m";
+const GREEN: &str = "\x1b[1;32m";
+const BLUE: &str = "\x1b[1;34m";
+const BOLD: &str = "\x1b[1m";
+const RESET: &str = "\x1b[0m";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Severity {
+    Error,
+    Warning,
+    Note,
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            Severity::Error => write!(f, "error"),
+            Severity::Warning => write!(f, "warning"),
+            Severity::Note => write!(f, "note"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Label {
+    pub location: Range,
+    pub message: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub code: Option<ErrorCode>,
+    pub lint: Option<Lint>,
+    pub message: String,
+    pub location: Range,
+    pub label: Option<String>,
+    pub secondary_labels: Vec<Label>,
+    pub notes: Vec<String>,
+    pub help: Option<String>,
+}
+
+impl Diagnostic {
+    pub fn new(message: String, location: Range) -> Diagnostic {
+        Diagnostic {
+            severity: Severity::Error,
+            code: None,
+            lint: None,
+            message,
+            location,
+            label: None,
+            secondary_labels: vec![],
+            notes: vec![],
+            help: None,
+        }
+    }
+
+    pub fn with_severity(mut self, severity: Severity) -> Diagnostic {
+        self.severity = severity;
+        self
+    }
+
+    pub fn with_lint(mut self, lint: Lint) -> Diagnostic {
+        self.lint = Some(lint);
+        self
+    }
+
+    pub fn with_code(mut self, code: ErrorCode) -> Diagnostic {
+        self.code = Some(code);
+        self
+    }
+
+    pub fn with_label(mut self, message: &str) -> Diagnostic {
+        self.label = Some(message.to_string());
+        self
+    }
+
+    pub fn with_secondary_label(mut self, location: Range, message: &str) -> Diagnostic {
+        self.secondary_labels.push(Label {
+            location,
+            message: message.to_string(),
+        });
+        self
+    }
+
+    pub fn with_note(mut self, note: &str) -> Diagnostic {
+        self.notes.push(note.to_string());
+        self
+    }
+
+    pub fn with_help(mut self, help: &str) -> Diagnostic {
+        self.help = Some(help.to_string());
+        self
+    }
+}
+
+impl AxiomError {
+    pub fn diagnostic(&self) -> Diagnostic {
+        let diagnostic = Diagnostic::new(self.message(), self.location()).with_code(self.code());
+
+        match self {
+            AxiomError::UnexpectedEOF(_) => diagnostic.with_label("expected more input here").with_help("check for a missing '}' or ')'"),
+            AxiomError::SyntaxError(_, _) => diagnostic,
+            AxiomError::DuplicatedIdentifier(_, identifier, previous_location) => {
+                let diagnostic = diagnostic.with_label(&format!("'{}' is declared again here", identifier));
+
+                match previous_location {
+                    Some(previous_location) => diagnostic.with_secondary_label(previous_location.clone(), "first declared here").with_help("rename one of the declarations"),
+                    None => diagnostic.with_note(&format!("'{}' is a built-in name or is declared in another file", identifier)).with_help("choose a different name"),
+                }
+            }
+            AxiomError::IdentifierUsedBeforeDeclaration(_, identifier) => diagnostic.with_label("not found in this scope").with_help(&format!("declare '{}' before using it", identifier)),
+            AxiomError::WrongDataType(_, expected, received) => diagnostic.with_label(&format!("expected {}, found {}", expected, received)),
+            AxiomError::NotAFunction(_, identifier) => diagnostic.with_label("called here").with_note(&format!("'{}' is not declared as a function", identifier)),
+            AxiomError::MismatchedNumberOfParameters(_, _, function_parameter_count, call_parameter_count) => diagnostic.with_label(&format!("expected {} arguments, given {}", function_parameter_count, call_parameter_count)),
+            AxiomError::NotAType(_, _) => diagnostic.with_label("used as a type here").with_help("the built-in types are i32, bool and char"),
+            AxiomError::LiteralOutOfRange(_, _, data_type) => {
+                let diagnostic = diagnostic.with_label("literal does not fit");
+
+                match **data_type {
+                    DataType::I32 => diagnostic.with_note(&format!("i32 values range from {} to {}", i32::MIN, i32::MAX)),
+                    _ => diagnostic,
+                }
+            }
+            AxiomError::UnexpectedCharacter(_, _) => diagnostic.with_label("not valid in Axiom source").with_help("remove this character"),
+            AxiomError::UnterminatedLiteral(_, _) => diagnostic.with_label("literal starts here").with_help("add the closing quote"),
+            AxiomError::UnknownAttribute(_, _) => diagnostic.with_label("unknown attribute").with_help("the supported attributes are allow, warn, deny and test"),
+            AxiomError::UnknownLint(_, _) => diagnostic.with_label("unknown lint").with_help(&format!("the known lints are {}", Lint::ALL.map(|lint| lint.name()).join(", "))),
+            AxiomError::InvalidMainSignature(_) => diagnostic.with_label("main is declared here").with_note("main must be declared as 'function main(): i32', 'function main(arguments: args): i32' or 'function main(argument_count: i32): i32'"),
+            AxiomError::InvalidTestFunction(_) => diagnostic.with_label("test function declared here").with_note("test functions take no parameters and return nothing"),
+            AxiomError::InvalidDestructuring(_, data_type, _) => match **data_type {
+                DataType::Tuple(ref element_data_types) => diagnostic.with_label(&format!("this value has {} elements", element_data_types.len())).with_help("bind every element, e.g. 'let (overflowed, value) = checked_add(left, right)'"),
+                _ => diagnostic.with_label("this value is not a tuple"),
+            },
+            AxiomError::MalformedNumberLiteral(_, _, _) => diagnostic.with_label("malformed literal").with_help("number literals look like 42, 1_000, 0xFF, 0o17 or 0b1010"),
+        }
+    }
+}
+
+pub struct DiagnosticRenderer<'source> {
+    source_name: &'source str,
+    lines: Vec<&'source str>,
+    colored: bool,
+}
+
+impl<'source> DiagnosticRenderer<'source> {
+    pub fn new(source_name: &'source str, source: &'source str, colored: bool) -> DiagnosticRenderer<'source> {
+        DiagnosticRenderer {
+            source_name,
+            lines: source.lines().collect(),
+            colored,
+        }
+    }
+
+    fn paint(&self, text: &str, style: &str) -> String {
+        if self.colored {
+            format!("{}{}{}", style, text, RESET)
+        } else {
+            text.to_string()
+        }
+    }
+
+    pub fn render(&self, diagnostic: &Diagnostic) -> String {
+        let start = &diagnostic.location.start;
+
+        let last_line = diagnostic.secondary_labels.iter()
+            .map(|label| label.location.start.line)
+            .fold(start.line, usize::max);
+        let gutter_width = (last_line + 1).to_string().len();
+        let padding = " ".repeat(gutter_width);
+
+        let mut output = String::new();
+
+        let style = match diagnostic.severity {
+            Severity::Error => RED,
+            Severity::Warning => YELLOW,
+            Severity::Note => GREEN,
+        };
+
+        let severity = match diagnostic.code {
+            Some(code) => format!("{}[{}]", diagnostic.severity, code),
+            None => diagnostic.severity.to_string(),
+        };
+
+        output.push_str(&format!("{}: {}\n", self.paint(&severity, style), self.paint(&diagnostic.message, BOLD)));
+        output.push_str(&format!("{}{} {}:{}:{}\n", padding, self.paint("-->", BLUE), self.source_name, start.line + 1, start.column + 1));
+        output.push_str(&format!("{} {}\n", padding, self.paint("|", BLUE)));
+
+        self.render_snippet(&mut output, &diagnostic.location, diagnostic.label.as_deref(), '^', style, gutter_width);
+
+        for label in &diagnostic.secondary_labels {
+            self.render_snippet(&mut output, &label.location, Some(&label.message), '-', BLUE, gutter_width);
+        }
+
+        for note in &diagnostic.notes {
+            output.push_str(&format!("{} {} {}: {}\n", padding, self.paint("=", BLUE), self.paint("note", BOLD), note));
+        }
+
+        if let Some(help) = &diagnostic.help {
+            output.push_str(&format!("{} {} {}: {}\n", padding, self.paint("=", BLUE), self.paint("help", BOLD), help));
+        }
+
+        output
+    }
+
+    fn render_snippet(&self, output: &mut String, location: &Range, label: Option<&str>, marker: char, style: &str, gutter_width: usize) {
+        let line = self.lines.get(location.start.line).copied().unwrap_or("");
+        let line_number = format!("{:>width$}", location.start.line + 1, width = gutter_width);
+
+        let prefix: String = line.chars().take(location.start.column).map(|char| if char == '\t' { '\t' } else { ' ' }).collect();
+        let length = if location.start.line == location.end.line {
+            location.end.column.saturating_sub(location.start.column) + 1
+        } else {
+            line.chars().count().saturating_sub(location.start.column).max(1)
+        };
+
+        let mut underline = marker.to_string().repeat(length);
+        if let Some(label) = label {
+            underline.push(' ');
+            underline.push_str(label);
+        }
+
+        output.push_str(&format!("{} {} {}\n", self.paint(&line_number, BLUE), self.paint("|", BLUE), line));
+        output.push_str(&format!("{} {} {}{}\n", " ".repeat(gutter_width), self.paint("|", BLUE), prefix, self.paint(&underline, style)));
+    }
+}
\ No newline at end of file
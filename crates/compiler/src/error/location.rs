@@ -70,6 +70,20 @@ impl Range {
             acc
         })
     }
+
+    pub fn text(&self, source: &str) -> String {
+        let lines: Vec<String> = source.lines().enumerate()
+            .filter(|(index, _)| *index >= self.start.line && *index <= self.end.line)
+            .map(|(index, line)| {
+                let start = if index == self.start.line { self.start.column } else { 0 };
+                let end = if index == self.end.line { self.end.column + 1 } else { usize::MAX };
+
+                line.chars().skip(start).take(end.saturating_sub(start)).collect::<String>().trim().to_string()
+            })
+            .collect();
+
+        lines.join(" ")
+    }
 }
 
 impl Into<LSPRange> for Range {
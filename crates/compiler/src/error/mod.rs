@@ -4,32 +4,82 @@ use crate::datatype::DataType;
 use crate::error::location::{Position, Range};
 
 pub mod location;
+pub mod diagnostic;
+pub mod code;
 
 #[derive(Debug)]
 pub enum AxiomError {
     UnexpectedEOF(Position),
     SyntaxError(Range, String),
-    DuplicatedIdentifier(Range, String),
+    DuplicatedIdentifier(Range, String, Option<Range>),
     IdentifierUsedBeforeDeclaration(Range, String),
     WrongDataType(Range, Box<DataType>, Box<DataType>),
     NotAFunction(Range, String),
     MismatchedNumberOfParameters(Range, String, usize, usize),
     NotAType(Range, String),
+    LiteralOutOfRange(Range, String, Box<DataType>),
+    UnexpectedCharacter(Range, char),
+    UnterminatedLiteral(Range, String),
+    UnknownAttribute(Range, String),
+    UnknownLint(Range, String),
+    InvalidMainSignature(Range),
+    InvalidTestFunction(Range),
+    InvalidDestructuring(Range, Box<DataType>, usize),
+    MalformedNumberLiteral(Range, String, String),
 }
 
-impl Display for AxiomError {
-    fn fmt(&self, f: &mut Formatter) -> Result {
+impl AxiomError {
+    pub fn location(&self) -> Range {
+        match self {
+            AxiomError::UnexpectedEOF(position) => Range::new(position.clone(), position.clone()),
+            AxiomError::SyntaxError(location, _) => location.clone(),
+            AxiomError::DuplicatedIdentifier(location, _, _) => location.clone(),
+            AxiomError::IdentifierUsedBeforeDeclaration(location, _) => location.clone(),
+            AxiomError::WrongDataType(location, _, _) => location.clone(),
+            AxiomError::NotAFunction(location, _) => location.clone(),
+            AxiomError::MismatchedNumberOfParameters(location, _, _, _) => location.clone(),
+            AxiomError::NotAType(location, _) => location.clone(),
+            AxiomError::LiteralOutOfRange(location, _, _) => location.clone(),
+            AxiomError::UnexpectedCharacter(location, _) => location.clone(),
+            AxiomError::UnterminatedLiteral(location, _) => location.clone(),
+            AxiomError::UnknownAttribute(location, _) => location.clone(),
+            AxiomError::UnknownLint(location, _) => location.clone(),
+            AxiomError::InvalidMainSignature(location) => location.clone(),
+            AxiomError::InvalidTestFunction(location) => location.clone(),
+            AxiomError::InvalidDestructuring(location, _, _) => location.clone(),
+            AxiomError::MalformedNumberLiteral(location, _, _) => location.clone(),
+        }
+    }
+
+    pub fn message(&self) -> String {
         match self {
-            AxiomError::UnexpectedEOF(location) => write!(f, "[{:?}] - Unexpected EOF", location),
-            AxiomError::SyntaxError(location, message) => write!(f, "[{:?}] - SyntaxError: {}", location, message),
-            AxiomError::DuplicatedIdentifier(location, identifier) => write!(f, "[{:?}] - Duplicated identifier: {}", location, identifier),
-            AxiomError::IdentifierUsedBeforeDeclaration(location, identifier) => write!(f, "[{:?}] - Identifier used before declaration: {}", location, identifier),
-            AxiomError::WrongDataType(location, expected, received) => write!(f, "[{:?}] - Expected DataType: {}, but found: {}", location, expected, received),
-            AxiomError::NotAFunction(location, identifier) => write!(f, "[{:?}] - {} is not a function", location, identifier),
-            AxiomError::MismatchedNumberOfParameters(location, identifier, function_parameter_count, call_parameter_count) => write!(f, "[{:?}] - Mismatched number of parameters, function {} takes {} parameters, but given {}", location, identifier, function_parameter_count, call_parameter_count),
-            AxiomError::NotAType(location, identifier) => write!(f, "[{:?}] - {} is not a type", location, identifier),
+            AxiomError::UnexpectedEOF(_) => "Unexpected EOF".to_string(),
+            AxiomError::SyntaxError(_, message) => format!("SyntaxError: {}", message),
+            AxiomError::DuplicatedIdentifier(_, identifier, _) => format!("Duplicated identifier: {}", identifier),
+            AxiomError::IdentifierUsedBeforeDeclaration(_, identifier) => format!("Identifier used before declaration: {}", identifier),
+            AxiomError::WrongDataType(_, expected, received) => format!("Expected DataType: {}, but found: {}", expected, received),
+            AxiomError::NotAFunction(_, identifier) => format!("{} is not a function", identifier),
+            AxiomError::MismatchedNumberOfParameters(_, identifier, function_parameter_count, call_parameter_count) => format!("Mismatched number of parameters, function {} takes {} parameters, but given {}", identifier, function_parameter_count, call_parameter_count),
+            AxiomError::NotAType(_, identifier) => format!("{} is not a type", identifier),
+            AxiomError::LiteralOutOfRange(_, literal, data_type) => format!("Literal {} is out of range for {}", literal, data_type),
+            AxiomError::UnexpectedCharacter(_, char) => format!("Unexpected character {:?}", char),
+            AxiomError::UnterminatedLiteral(_, literal_kind) => format!("Unterminated {} literal", literal_kind),
+            AxiomError::UnknownAttribute(_, attribute) => format!("Unknown attribute {}", attribute),
+            AxiomError::UnknownLint(_, lint) => format!("Unknown lint {}", lint),
+            AxiomError::InvalidMainSignature(_) => "Invalid signature for main".to_string(),
+            AxiomError::InvalidTestFunction(_) => "Invalid signature for test function".to_string(),
+            AxiomError::InvalidDestructuring(_, data_type, binding_count) => format!("Cannot bind a value of type {} to {} name{}", data_type, binding_count, if *binding_count == 1 { "" } else { "s" }),
+            AxiomError::MalformedNumberLiteral(_, literal, reason) => format!("Malformed number literal {}: {}", literal, reason),
         }
     }
 }
 
+impl Display for AxiomError {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let start = self.location().start;
+
+        write!(f, "{}:{}: {}", start.line + 1, start.column + 1, self.message())
+    }
+}
+
 impl Error for AxiomError {}
\ No newline at end of file
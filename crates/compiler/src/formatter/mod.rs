@@ -0,0 +1,153 @@
+mod syntax;
+mod printer;
+
+use crate::error::AxiomError;
+use crate::formatter::printer::Printer;
+use crate::formatter::syntax::SyntaxTree;
+use crate::lexer::Lexer;
+use crate::parser::Parser;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BraceStyle {
+    SameLine,
+    NextLine,
+}
+
+#[derive(Debug, Clone)]
+pub struct FormatOptions {
+    pub indent_width: usize,
+    pub hard_tabs: bool,
+    pub blank_lines_between_functions: usize,
+    pub brace_style: BraceStyle,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        FormatOptions {
+            indent_width: 4,
+            hard_tabs: false,
+            blank_lines_between_functions: 1,
+            brace_style: BraceStyle::SameLine,
+        }
+    }
+}
+
+pub fn format(source: &str, options: &FormatOptions) -> Result<String, Vec<AxiomError>> {
+    let (tokens, mut errors) = Lexer::new(&source.to_string()).parse();
+
+    let (ast, parser_errors) = Parser::new(tokens.clone()).parse();
+    errors.extend(parser_errors);
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    let syntax_tree = SyntaxTree::new(source, tokens, &ast);
+    debug_assert_eq!(syntax_tree.text(), source, "concrete syntax tree must reproduce the source");
+
+    Ok(Printer::new(options).print(&syntax_tree))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::formatter::{format, BraceStyle, FormatOptions};
+
+    const UNFORMATTED: &str = "// leading comment
+/// Adds two numbers.
+function add(left:i32,right :i32):i32{
+  return left+right // trailing comment
+}
+
+
+#[test]
+function adds(){
+    // inside
+  assert_eq( add(1,2),3 )
+}
+function main():i32{
+let (overflowed,sum)=checked_add(1,2)
+  if overflowed{return 1}else if sum>2 {
+      return sum>3?0:2
+  }   else{
+     // before return
+     return 0
+  }
+  // end of body
+}
+// trailing file comment
+";
+
+    const FORMATTED: &str = "// leading comment
+/// Adds two numbers.
+function add(left: i32, right: i32): i32 {
+    return left + right // trailing comment
+}
+
+#[test]
+function adds() {
+    // inside
+    assert_eq(add(1, 2), 3)
+}
+
+function main(): i32 {
+    let (overflowed, sum) = checked_add(1, 2)
+    if overflowed {
+        return 1
+    } else if sum > 2 {
+        return sum > 3 ? 0 : 2
+    } else {
+        // before return
+        return 0
+    }
+    // end of body
+}
+// trailing file comment
+";
+
+    const SOURCES: [&str; 3] = [
+        UNFORMATTED,
+        "#[allow(unused_variables)] function main(): i32 { let letter = 'a' // a char
+let count = 0 count += 2 count++ return -count ** 2 % 7 }",
+        "function empty() {\n    // only a comment\n}\n\n\n\nfunction nested(value: i32): bool {\nif value > 0 {\nif value < 10 { return value != 3 } // small\n}\nreturn !(value < 0 || value >= 10) && value != 3\n}\n",
+    ];
+
+    fn option_variants() -> Vec<FormatOptions> {
+        vec![
+            FormatOptions::default(),
+            FormatOptions { brace_style: BraceStyle::NextLine, ..FormatOptions::default() },
+            FormatOptions { hard_tabs: true, blank_lines_between_functions: 2, ..FormatOptions::default() },
+            FormatOptions { indent_width: 2, blank_lines_between_functions: 0, ..FormatOptions::default() },
+        ]
+    }
+
+    fn comments(source: &str) -> Vec<&str> {
+        source.lines().filter_map(|line| line.find("//").map(|start| line[start..].trim_end())).collect()
+    }
+
+    #[test]
+    fn formats_source() {
+        assert_eq!(format(UNFORMATTED, &FormatOptions::default()).unwrap(), FORMATTED);
+    }
+
+    #[test]
+    fn formatting_is_idempotent() {
+        for options in option_variants() {
+            for source in SOURCES {
+                let formatted = format(source, &options).unwrap();
+
+                assert_eq!(format(&formatted, &options).unwrap(), formatted, "{:?}", options);
+            }
+        }
+    }
+
+    #[test]
+    fn preserves_comments() {
+        for options in option_variants() {
+            for source in SOURCES {
+                let formatted = format(source, &options).unwrap();
+
+                assert_eq!(comments(&formatted), comments(source), "{:?}\n{}", options, formatted);
+            }
+        }
+    }
+}
@@ -0,0 +1,384 @@
+use crate::formatter::syntax::{is_parentheses, is_punctuation, SyntaxElement, SyntaxKind, SyntaxNode, SyntaxToken, SyntaxTree, Trivia};
+use crate::formatter::{BraceStyle, FormatOptions};
+use crate::token::{OperatorArithmeticType, OperatorBitwiseType, OperatorCategory, OperatorLogicalType, ParenthesesState, ParenthesesType, PunctuationType, Token};
+
+const MAXIMUM_BLANK_LINES: usize = 1;
+
+#[derive(Clone, Copy)]
+struct BlankLines {
+    minimum: usize,
+    maximum: usize,
+}
+
+impl BlankLines {
+    fn none() -> BlankLines {
+        BlankLines::exactly(0)
+    }
+
+    fn exactly(count: usize) -> BlankLines {
+        BlankLines {
+            minimum: count,
+            maximum: count,
+        }
+    }
+
+    fn up_to(count: usize) -> BlankLines {
+        BlankLines {
+            minimum: 0,
+            maximum: count,
+        }
+    }
+
+    fn clamp(&self, newlines: usize) -> usize {
+        newlines.saturating_sub(1).clamp(self.minimum, self.maximum)
+    }
+}
+
+struct Comment<'a> {
+    text: &'a str,
+    newlines_before: usize,
+}
+
+struct LeadingTrivia<'a> {
+    comments: Vec<Comment<'a>>,
+    newlines_before_token: usize,
+}
+
+impl LeadingTrivia<'_> {
+    fn new(trivia: &[Trivia]) -> LeadingTrivia<'_> {
+        let mut comments = vec![];
+        let mut newlines = 0;
+
+        for piece in trivia {
+            match piece {
+                Trivia::Whitespace(text) => newlines += text.matches('\n').count(),
+                Trivia::Comment(text) => {
+                    comments.push(Comment {
+                        text: text.trim_end(),
+                        newlines_before: newlines,
+                    });
+                    newlines = 0;
+                }
+            }
+        }
+
+        LeadingTrivia {
+            comments,
+            newlines_before_token: newlines,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Context {
+    Attribute,
+    Signature,
+    Statement,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Role {
+    Operand,
+    Keyword,
+    Opening,
+    Closing,
+    Punctuation,
+    PrefixOperator,
+    PostfixOperator,
+    BinaryOperator,
+}
+
+impl Role {
+    fn of(token: &Token, previous_role: Option<Role>) -> Role {
+        let follows_operand = matches!(previous_role, Some(Role::Operand | Role::Closing | Role::PostfixOperator));
+
+        match token {
+            Token::Number(_) | Token::Char(_) | Token::String(_) | Token::Identifier(_) | Token::DocComment(_) | Token::Unknown(_, _) => Role::Operand,
+            Token::Keyword(_) => Role::Keyword,
+            Token::Parentheses(parentheses_token) => match parentheses_token.parentheses_type {
+                ParenthesesType::Round(ParenthesesState::Opening) | ParenthesesType::Curly(ParenthesesState::Opening) | ParenthesesType::Square(ParenthesesState::Opening) => Role::Opening,
+                _ => Role::Closing,
+            },
+            Token::Punctuation(_) => Role::Punctuation,
+            Token::Operator(operator_token) => match operator_token.operator_type {
+                OperatorCategory::Arithmetic(OperatorArithmeticType::Increment | OperatorArithmeticType::Decrement) => match previous_role {
+                    Some(Role::Operand | Role::Closing) => Role::PostfixOperator,
+                    _ => Role::PrefixOperator,
+                },
+                OperatorCategory::Arithmetic(OperatorArithmeticType::Addition | OperatorArithmeticType::Subtraction) if !follows_operand => Role::PrefixOperator,
+                OperatorCategory::Logical(OperatorLogicalType::Not) | OperatorCategory::Bitwise(OperatorBitwiseType::Not) => Role::PrefixOperator,
+                _ => Role::BinaryOperator,
+            },
+        }
+    }
+}
+
+struct Previous {
+    token: Token,
+    text: String,
+    role: Role,
+}
+
+pub struct Printer<'a> {
+    options: &'a FormatOptions,
+    output: String,
+    indent_level: usize,
+    previous: Option<Previous>,
+}
+
+impl Printer<'_> {
+    pub fn new(options: &FormatOptions) -> Printer<'_> {
+        Printer {
+            options,
+            output: String::new(),
+            indent_level: 0,
+            previous: None,
+        }
+    }
+
+    pub fn print(mut self, syntax_tree: &SyntaxTree) -> String {
+        for (index, child) in syntax_tree.root.children.iter().enumerate() {
+            if let SyntaxElement::Node(function) = child {
+                let blank_lines = if index == 0 { BlankLines::none() } else { BlankLines::exactly(self.options.blank_lines_between_functions) };
+
+                self.function(function, blank_lines);
+            }
+        }
+
+        self.begin_line(&syntax_tree.trailing_trivia, BlankLines::up_to(self.options.blank_lines_between_functions.max(MAXIMUM_BLANK_LINES)));
+
+        let length = self.output.trim_end().len();
+        self.output.truncate(length);
+
+        if !self.output.is_empty() {
+            self.output.push('\n');
+        }
+
+        self.output
+    }
+
+    fn function(&mut self, function: &SyntaxNode, mut blank_lines: BlankLines) {
+        let mut starts_line = true;
+
+        for child in &function.children {
+            match child {
+                SyntaxElement::Node(attribute) if attribute.kind == SyntaxKind::Attribute => {
+                    for (index, token) in attribute.tokens().into_iter().enumerate() {
+                        match index {
+                            0 => self.line_token(token, blank_lines),
+                            _ => self.inline_token(token, Context::Attribute),
+                        }
+                    }
+
+                    blank_lines = BlankLines::up_to(MAXIMUM_BLANK_LINES);
+                }
+                SyntaxElement::Node(scope) if scope.kind == SyntaxKind::Scope => self.scope(scope),
+                SyntaxElement::Node(node) => {
+                    for token in node.tokens() {
+                        self.inline_token(token, Context::Signature);
+                    }
+                }
+                SyntaxElement::Token(token) if starts_line => {
+                    self.line_token(token, blank_lines);
+                    starts_line = false;
+                }
+                SyntaxElement::Token(token) => self.inline_token(token, Context::Signature),
+            }
+        }
+    }
+
+    fn scope(&mut self, scope: &SyntaxNode) {
+        let mut statements = vec![];
+        let mut opening = None;
+        let mut closing = None;
+
+        for child in &scope.children {
+            match child {
+                SyntaxElement::Node(statement) => statements.push(statement),
+                SyntaxElement::Token(token) if opening.is_none() => opening = Some(token),
+                SyntaxElement::Token(token) => closing = Some(token),
+            }
+        }
+
+        if let Some(opening) = opening {
+            match self.options.brace_style {
+                BraceStyle::SameLine => self.inline_token(opening, Context::Statement),
+                BraceStyle::NextLine => self.line_token(opening, BlankLines::none()),
+            }
+        }
+
+        let Some(closing) = closing else {
+            return;
+        };
+
+        if statements.is_empty() && LeadingTrivia::new(&closing.leading_trivia).comments.is_empty() {
+            self.write_token(closing, Role::Closing);
+            return;
+        }
+
+        self.indent_level += 1;
+
+        for (index, statement) in statements.into_iter().enumerate() {
+            let blank_lines = if index == 0 { BlankLines::none() } else { BlankLines::up_to(MAXIMUM_BLANK_LINES) };
+
+            self.statement(statement, blank_lines);
+        }
+
+        self.begin_line(&closing.leading_trivia, BlankLines::none());
+        self.indent_level -= 1;
+        self.indent();
+        self.write_token(closing, Role::Closing);
+    }
+
+    fn statement(&mut self, statement: &SyntaxNode, blank_lines: BlankLines) {
+        for (index, child) in statement.children.iter().enumerate() {
+            match child {
+                SyntaxElement::Node(scope) => self.scope(scope),
+                SyntaxElement::Token(token) if index == 0 => self.line_token(token, blank_lines),
+                SyntaxElement::Token(token) if self.options.brace_style == BraceStyle::NextLine && self.previous.as_ref().is_some_and(|previous| is_parentheses(&previous.token, ParenthesesType::Curly(ParenthesesState::Closing))) => {
+                    self.line_token(token, BlankLines::none());
+                }
+                SyntaxElement::Token(token) => self.inline_token(token, Context::Statement),
+            }
+        }
+    }
+
+    fn line_token(&mut self, token: &SyntaxToken, blank_lines: BlankLines) {
+        self.begin_line(&token.leading_trivia, blank_lines);
+        self.indent();
+
+        let role = Role::of(&token.token, None);
+        self.write_token(token, role);
+    }
+
+    fn inline_token(&mut self, token: &SyntaxToken, context: Context) {
+        let role = Role::of(&token.token, self.previous.as_ref().map(|previous| previous.role));
+        let leading_trivia = LeadingTrivia::new(&token.leading_trivia);
+
+        if leading_trivia.comments.is_empty() {
+            if self.needs_space(token, role, context) {
+                self.output.push(' ');
+            }
+        } else {
+            for comment in &leading_trivia.comments {
+                if comment.newlines_before == 0 {
+                    self.output.push(' ');
+                } else {
+                    self.newline();
+                    self.continuation_indent();
+                }
+
+                self.output.push_str(comment.text);
+            }
+
+            self.newline();
+            self.continuation_indent();
+        }
+
+        self.write_token(token, role);
+    }
+
+    fn write_token(&mut self, token: &SyntaxToken, role: Role) {
+        self.output.push_str(&token.text);
+
+        self.previous = Some(Previous {
+            token: token.token.clone(),
+            text: token.text.clone(),
+            role,
+        });
+    }
+
+    fn needs_space(&self, next: &SyntaxToken, next_role: Role, context: Context) -> bool {
+        let Some(previous) = &self.previous else {
+            return false;
+        };
+
+        if context == Context::Attribute {
+            return is_punctuation(&previous.token, PunctuationType::Comma);
+        }
+
+        if is_punctuation(&next.token, PunctuationType::Comma) || next_role == Role::PostfixOperator {
+            return false;
+        }
+
+        if is_punctuation(&next.token, PunctuationType::Colon) {
+            return context == Context::Statement;
+        }
+
+        if previous.role == Role::PrefixOperator {
+            return next_role == Role::PrefixOperator && ['+', '-'].iter().any(|sign| previous.text.ends_with(*sign) && next.text.starts_with(*sign));
+        }
+
+        let is_curly = |token: &Token| matches!(token, Token::Parentheses(parentheses_token) if matches!(parentheses_token.parentheses_type, ParenthesesType::Curly(_)));
+
+        if next_role == Role::Closing && !is_curly(&next.token) {
+            return false;
+        }
+
+        if previous.role == Role::Opening && !is_curly(&previous.token) {
+            return false;
+        }
+
+        if is_parentheses(&next.token, ParenthesesType::Round(ParenthesesState::Opening)) {
+            return !matches!(previous.token, Token::Identifier(_));
+        }
+
+        true
+    }
+
+    fn begin_line(&mut self, trivia: &[Trivia], blank_lines: BlankLines) {
+        let leading_trivia = LeadingTrivia::new(trivia);
+        let mut comments = leading_trivia.comments.iter().peekable();
+        let at_start = self.output.is_empty();
+
+        if !at_start {
+            while let Some(comment) = comments.next_if(|comment| comment.newlines_before == 0) {
+                self.output.push(' ');
+                self.output.push_str(comment.text);
+            }
+
+            self.newline();
+        }
+
+        let mut blank_lines = if at_start { BlankLines::none() } else { blank_lines };
+
+        for comment in comments {
+            self.blank_lines(blank_lines.clamp(comment.newlines_before));
+            self.indent();
+            self.output.push_str(comment.text);
+            self.newline();
+
+            blank_lines = BlankLines::up_to(MAXIMUM_BLANK_LINES);
+        }
+
+        self.blank_lines(blank_lines.clamp(leading_trivia.newlines_before_token));
+        self.previous = None;
+    }
+
+    fn blank_lines(&mut self, count: usize) {
+        for _ in 0..count {
+            self.output.push('\n');
+        }
+    }
+
+    fn newline(&mut self) {
+        let length = self.output.trim_end_matches([' ', '\t']).len();
+        self.output.truncate(length);
+        self.output.push('\n');
+    }
+
+    fn indent(&mut self) {
+        self.write_indentation(self.indent_level);
+    }
+
+    fn continuation_indent(&mut self) {
+        self.write_indentation(self.indent_level + 1);
+    }
+
+    fn write_indentation(&mut self, level: usize) {
+        match self.options.hard_tabs {
+            true => self.output.push_str(&"\t".repeat(level)),
+            false => self.output.push_str(&" ".repeat(level * self.options.indent_width)),
+        }
+    }
+}
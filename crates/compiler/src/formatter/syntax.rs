@@ -0,0 +1,344 @@
+use std::collections::HashSet;
+use std::iter::Peekable;
+use std::vec::IntoIter;
+use crate::ast::{Node, ScopeNode};
+use crate::error::location::Location;
+use crate::token::{ParenthesesState, ParenthesesType, PunctuationType, Token};
+
+#[derive(Debug, Clone)]
+pub enum Trivia {
+    Whitespace(String),
+    Comment(String),
+}
+
+impl Trivia {
+    pub fn text(&self) -> &str {
+        match self {
+            Trivia::Whitespace(text) => text,
+            Trivia::Comment(text) => text,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SyntaxToken {
+    pub token: Token,
+    pub text: String,
+    pub leading_trivia: Vec<Trivia>,
+}
+
+impl SyntaxToken {
+    pub fn full_text(&self) -> String {
+        self.leading_trivia.iter().map(Trivia::text).collect::<String>() + &self.text
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SyntaxKind {
+    File,
+    Function,
+    Attribute,
+    Parameter,
+    Scope,
+    Statement,
+}
+
+#[derive(Debug, Clone)]
+pub enum SyntaxElement {
+    Node(SyntaxNode),
+    Token(SyntaxToken),
+}
+
+impl SyntaxElement {
+    pub fn text(&self) -> String {
+        match self {
+            SyntaxElement::Node(node) => node.text(),
+            SyntaxElement::Token(token) => token.full_text(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SyntaxNode {
+    pub kind: SyntaxKind,
+    pub children: Vec<SyntaxElement>,
+}
+
+impl SyntaxNode {
+    pub fn new(kind: SyntaxKind, children: Vec<SyntaxElement>) -> SyntaxNode {
+        SyntaxNode {
+            kind,
+            children,
+        }
+    }
+
+    pub fn text(&self) -> String {
+        self.children.iter().map(SyntaxElement::text).collect()
+    }
+
+    pub fn tokens(&self) -> Vec<&SyntaxToken> {
+        self.children.iter().flat_map(|child| match child {
+            SyntaxElement::Node(node) => node.tokens(),
+            SyntaxElement::Token(token) => vec![token],
+        }).collect()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SyntaxTree {
+    pub root: SyntaxNode,
+    pub trailing_trivia: Vec<Trivia>,
+}
+
+impl SyntaxTree {
+    pub fn new(source: &str, tokens: Vec<Token>, ast: &Node) -> SyntaxTree {
+        let (syntax_tokens, trailing_trivia) = attach_trivia(source, tokens);
+
+        let mut statement_starts = HashSet::new();
+        collect_statement_starts(ast, &mut statement_starts);
+
+        let mut tree_builder = TreeBuilder {
+            tokens: syntax_tokens.into_iter().peekable(),
+            statement_starts,
+        };
+
+        SyntaxTree {
+            root: tree_builder.file(),
+            trailing_trivia,
+        }
+    }
+
+    pub fn text(&self) -> String {
+        self.root.text() + &self.trailing_trivia.iter().map(Trivia::text).collect::<String>()
+    }
+}
+
+fn attach_trivia(source: &str, tokens: Vec<Token>) -> (Vec<SyntaxToken>, Vec<Trivia>) {
+    let chars: Vec<char> = source.chars().collect();
+
+    let mut line_starts = vec![0];
+    line_starts.extend(chars.iter().enumerate().filter(|(_, char)| **char == '\n').map(|(index, _)| index + 1));
+
+    let mut offset = 0;
+    let mut syntax_tokens = vec![];
+    let mut leading_trivia = vec![];
+
+    for token in tokens {
+        let location = token.location();
+        let start = line_starts[location.start.line] + location.start.column;
+        let end = line_starts[location.end.line] + location.end.column + 1;
+
+        leading_trivia.extend(split_trivia(&chars[offset..start]));
+        let text: String = chars[start..end].iter().collect();
+        offset = end;
+
+        if matches!(token, Token::DocComment(_)) {
+            leading_trivia.push(Trivia::Comment(text));
+            continue;
+        }
+
+        syntax_tokens.push(SyntaxToken {
+            leading_trivia: std::mem::take(&mut leading_trivia),
+            text,
+            token,
+        });
+    }
+
+    leading_trivia.extend(split_trivia(&chars[offset..]));
+
+    (syntax_tokens, leading_trivia)
+}
+
+fn split_trivia(chars: &[char]) -> Vec<Trivia> {
+    let mut trivia = vec![];
+    let mut index = 0;
+
+    while index < chars.len() {
+        let start = index;
+
+        if chars[index] == '/' {
+            while index < chars.len() && chars[index] != '\n' {
+                index += 1;
+            }
+
+            trivia.push(Trivia::Comment(chars[start..index].iter().collect()));
+        } else {
+            while index < chars.len() && chars[index] != '/' {
+                index += 1;
+            }
+
+            trivia.push(Trivia::Whitespace(chars[start..index].iter().collect()));
+        }
+    }
+
+    trivia
+}
+
+fn collect_statement_starts(node: &Node, statement_starts: &mut HashSet<(usize, usize)>) {
+    match node {
+        Node::File(file_node) => {
+            for function in &file_node.functions {
+                collect_scope_statement_starts(&function.scope, statement_starts);
+            }
+        }
+        Node::IfElse(if_else_node) => {
+            collect_scope_statement_starts(&if_else_node.consequent, statement_starts);
+
+            for (_, consequent) in &if_else_node.conditional_alternatives {
+                collect_scope_statement_starts(consequent, statement_starts);
+            }
+
+            if let Some(alternative) = &if_else_node.alternative {
+                collect_scope_statement_starts(alternative, statement_starts);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn collect_scope_statement_starts(scope_node: &ScopeNode, statement_starts: &mut HashSet<(usize, usize)>) {
+    for statement in &scope_node.statements {
+        let start = statement.location().start;
+        statement_starts.insert((start.line, start.column));
+
+        collect_statement_starts(statement, statement_starts);
+    }
+}
+
+pub fn is_parentheses(token: &Token, parentheses_type: ParenthesesType) -> bool {
+    matches!(token, Token::Parentheses(parentheses_token) if parentheses_token.parentheses_type == parentheses_type)
+}
+
+pub fn is_punctuation(token: &Token, punctuation_type: PunctuationType) -> bool {
+    matches!(token, Token::Punctuation(punctuation_token) if punctuation_token.punctuation_type == punctuation_type)
+}
+
+struct TreeBuilder {
+    tokens: Peekable<IntoIter<SyntaxToken>>,
+    statement_starts: HashSet<(usize, usize)>,
+}
+
+impl TreeBuilder {
+    fn peek(&mut self) -> Option<&Token> {
+        self.tokens.peek().map(|syntax_token| &syntax_token.token)
+    }
+
+    fn bump(&mut self, children: &mut Vec<SyntaxElement>) {
+        if let Some(syntax_token) = self.tokens.next() {
+            children.push(SyntaxElement::Token(syntax_token));
+        }
+    }
+
+    fn file(&mut self) -> SyntaxNode {
+        let mut children = vec![];
+
+        while self.peek().is_some() {
+            children.push(SyntaxElement::Node(self.function()));
+        }
+
+        SyntaxNode::new(SyntaxKind::File, children)
+    }
+
+    fn function(&mut self) -> SyntaxNode {
+        let mut children = vec![];
+
+        while self.peek().is_some_and(|token| is_punctuation(token, PunctuationType::Hash)) {
+            children.push(SyntaxElement::Node(self.attribute()));
+        }
+
+        while self.peek().is_some_and(|token| !is_parentheses(token, ParenthesesType::Round(ParenthesesState::Opening))) {
+            self.bump(&mut children);
+        }
+        self.bump(&mut children);
+
+        while let Some(token) = self.peek() {
+            if is_parentheses(token, ParenthesesType::Round(ParenthesesState::Closing)) {
+                self.bump(&mut children);
+                break;
+            }
+
+            if is_punctuation(token, PunctuationType::Comma) {
+                self.bump(&mut children);
+            } else {
+                children.push(SyntaxElement::Node(self.parameter()));
+            }
+        }
+
+        while self.peek().is_some_and(|token| !is_parentheses(token, ParenthesesType::Curly(ParenthesesState::Opening))) {
+            self.bump(&mut children);
+        }
+
+        if self.peek().is_some() {
+            children.push(SyntaxElement::Node(self.scope()));
+        }
+
+        SyntaxNode::new(SyntaxKind::Function, children)
+    }
+
+    fn attribute(&mut self) -> SyntaxNode {
+        let mut children = vec![];
+
+        while let Some(token) = self.peek() {
+            let is_closing = is_parentheses(token, ParenthesesType::Square(ParenthesesState::Closing));
+
+            self.bump(&mut children);
+
+            if is_closing {
+                break;
+            }
+        }
+
+        SyntaxNode::new(SyntaxKind::Attribute, children)
+    }
+
+    fn parameter(&mut self) -> SyntaxNode {
+        let mut children = vec![];
+
+        while self.peek().is_some_and(|token| !is_punctuation(token, PunctuationType::Comma) && !is_parentheses(token, ParenthesesType::Round(ParenthesesState::Closing))) {
+            self.bump(&mut children);
+        }
+
+        SyntaxNode::new(SyntaxKind::Parameter, children)
+    }
+
+    fn scope(&mut self) -> SyntaxNode {
+        let mut children = vec![];
+        self.bump(&mut children);
+
+        while let Some(token) = self.peek() {
+            if is_parentheses(token, ParenthesesType::Curly(ParenthesesState::Closing)) {
+                self.bump(&mut children);
+                break;
+            }
+
+            children.push(SyntaxElement::Node(self.statement()));
+        }
+
+        SyntaxNode::new(SyntaxKind::Scope, children)
+    }
+
+    fn statement(&mut self) -> SyntaxNode {
+        let mut children = vec![];
+        self.bump(&mut children);
+
+        while let Some(token) = self.peek() {
+            if is_parentheses(token, ParenthesesType::Curly(ParenthesesState::Closing)) {
+                break;
+            }
+
+            if is_parentheses(token, ParenthesesType::Curly(ParenthesesState::Opening)) {
+                children.push(SyntaxElement::Node(self.scope()));
+                continue;
+            }
+
+            let start = token.location().start;
+            if self.statement_starts.contains(&(start.line, start.column)) {
+                break;
+            }
+
+            self.bump(&mut children);
+        }
+
+        SyntaxNode::new(SyntaxKind::Statement, children)
+    }
+}
@@ -1,6 +1,6 @@
-use std::error::Error;
+use crate::error::AxiomError;
 use crate::error::location::{Position, Range};
-use crate::token::{NumberToken, Token, IdentifierToken, OperatorToken, OperatorCategory, OperatorArithmeticType, OperatorAssignmentType, ParenthesesToken, ParenthesesType, ParenthesesState, KeywordToken, OperatorComparisonType, PunctuationToken, PunctuationType, OperatorBitwiseType, OperatorLogicalType};
+use crate::token::{CharToken, StringToken, DocCommentToken, NumberToken, Token, IdentifierToken, OperatorToken, OperatorCategory, OperatorArithmeticType, OperatorAssignmentType, ParenthesesToken, ParenthesesType, ParenthesesState, KeywordToken, OperatorComparisonType, PunctuationToken, PunctuationType, OperatorBitwiseType, OperatorLogicalType};
 
 pub struct Lexer{
     chars: Vec<char>,
@@ -8,6 +8,7 @@ pub struct Lexer{
     position: Position,
     current_char: Option<char>,
     tokens: Vec<Token>,
+    errors: Vec<AxiomError>,
     is_next_char_on_new_line: bool,
 }
 
@@ -20,6 +21,7 @@ impl Lexer {
             index: 0,
             position: Position::new(0, 0),
             tokens: Vec::new(),
+            errors: Vec::new(),
             chars,
             is_next_char_on_new_line: false,
         }
@@ -48,7 +50,7 @@ impl Lexer {
         self.current_char
     }
 
-    pub fn parse(mut self) -> Result<Vec<Token>, Box<dyn Error>> {
+    pub fn parse(mut self) -> (Vec<Token>, Vec<AxiomError>) {
         let mut first_loop = true;
         
         loop {
@@ -64,17 +66,21 @@ impl Lexer {
                     continue;
                 }
                 char if char.is_ascii_digit() => self.parse_number(),
-                char if char.is_alphabetic() => self.parse_identifier(),
+                '\'' => self.parse_char(),
+                '"' => self.parse_string(),
+                char if char.is_alphabetic() || char == '_' => self.parse_identifier(),
+                '/' if self.peek() == Some('/') => self.parse_comment(),
                 char if Lexer::is_operator(char) => self.parse_operator(),
                 char if Lexer::is_parentheses(char) => self.parse_parentheses(),
                 char if Lexer::is_punctuation(char) => self.parse_punctuation(),
                 _ => {
-                    self.tokens.push(Token::Unknown(self.position.clone(), current_char));
+                    let location = Range::new(self.position.clone(), self.position.clone());
+                    self.errors.push(AxiomError::UnexpectedCharacter(location, current_char));
                 }
             }
         }
 
-        Ok(self.tokens)
+        (self.tokens, self.errors)
     }
 
     fn parse_number(&mut self) {
@@ -85,7 +91,7 @@ impl Lexer {
             match self.peek() {
                 Some(char) => {
                     match char {
-                        x if x.is_ascii_digit() => current_number.push(self.take().unwrap()),
+                        x if x.is_alphanumeric() || x == '_' => current_number.push(self.take().unwrap()),
                         _ => break
                     }
                 }
@@ -95,9 +101,96 @@ impl Lexer {
         
         let location = Range::new(start_position, self.position.clone());
         let number_token = NumberToken::new(String::from_iter(current_number), location);
+
+        if let Err(error) = number_token.check_digits() {
+            self.errors.push(error);
+        }
+
         self.tokens.push(Token::Number(number_token));
     }
     
+    fn parse_comment(&mut self) {
+        let start_position = self.position.clone();
+        let mut comment = String::new();
+
+        while let Some(char) = self.peek() && char != '\n' {
+            comment.push(self.take().unwrap());
+        }
+
+        if let Some(text) = comment.strip_prefix("//") && !text.starts_with('/') {
+            let location = Range::new(start_position, self.position.clone());
+            self.tokens.push(Token::DocComment(DocCommentToken::new(text.to_string(), location)));
+        }
+    }
+
+    fn parse_char(&mut self) {
+        let start_position = self.position.clone();
+        let mut literal = String::from('\'');
+        let mut is_terminated = false;
+
+        loop {
+            match self.peek() {
+                Some('\n') | None => break,
+                Some('\'') => {
+                    literal.push(self.take().unwrap());
+                    is_terminated = true;
+                    break;
+                }
+                Some('\\') => {
+                    literal.push(self.take().unwrap());
+
+                    if let Some(char) = self.peek() && char != '\n' {
+                        literal.push(self.take().unwrap());
+                    }
+                }
+                Some(_) => literal.push(self.take().unwrap()),
+            }
+        }
+
+        let location = Range::new(start_position, self.position.clone());
+        let char_token = CharToken::new(literal, is_terminated, location);
+
+        if let Err(error) = char_token.parse_value() {
+            self.errors.push(error);
+        }
+
+        self.tokens.push(Token::Char(char_token));
+    }
+
+    fn parse_string(&mut self) {
+        let start_position = self.position.clone();
+        let mut literal = String::from('"');
+        let mut is_terminated = false;
+
+        loop {
+            match self.peek() {
+                Some('\n') | None => break,
+                Some('"') => {
+                    literal.push(self.take().unwrap());
+                    is_terminated = true;
+                    break;
+                }
+                Some('\\') => {
+                    literal.push(self.take().unwrap());
+
+                    if let Some(char) = self.peek() && char != '\n' {
+                        literal.push(self.take().unwrap());
+                    }
+                }
+                Some(_) => literal.push(self.take().unwrap()),
+            }
+        }
+
+        let location = Range::new(start_position, self.position.clone());
+        let string_token = StringToken::new(literal, is_terminated, location);
+
+        if let Err(error) = string_token.parse_value() {
+            self.errors.push(error);
+        }
+
+        self.tokens.push(Token::String(string_token));
+    }
+
     fn parse_identifier(&mut self) {
         let start_position = self.position.clone();
         let mut current_identifier = self.current_char.unwrap().to_string();
@@ -106,7 +199,7 @@ impl Lexer {
             match self.peek() {
                 Some(char) => {
                     match char {
-                        x if x.is_alphabetic() || x.is_ascii_digit() => current_identifier.push(self.take().unwrap()),
+                        x if x.is_alphabetic() || x.is_ascii_digit() || x == '_' => current_identifier.push(self.take().unwrap()),
                         _ => break
                     }
                 }
@@ -128,7 +221,7 @@ impl Lexer {
     
     fn is_operator(current_char: char) -> bool {
         match current_char {
-            '+' | '-' | '*' | '/' | '=' | '!' | '>' | '<' | '|' | '&' => true,
+            '+' | '-' | '*' | '/' | '%' | '=' | '!' | '>' | '<' | '|' | '&' | '^' | '~' => true,
             _ => false
         }
     }
@@ -160,6 +253,7 @@ impl Lexer {
                 self.parse_double_operator(start_position, |char| {
                     match char {
                         '=' => Some(OperatorCategory::Assignment(OperatorAssignmentType::MultiplicationAssignment)),
+                        '*' => Some(OperatorCategory::Arithmetic(OperatorArithmeticType::Exponentiation)),
                         _ => None
                     }
                 }, OperatorCategory::Arithmetic(OperatorArithmeticType::Multiplication));
@@ -172,6 +266,14 @@ impl Lexer {
                     }
                 }, OperatorCategory::Arithmetic(OperatorArithmeticType::Division));
             }
+            '%' => {
+                self.parse_double_operator(start_position, |char| {
+                    match char {
+                        '=' => Some(OperatorCategory::Assignment(OperatorAssignmentType::ModuloAssignment)),
+                        _ => None
+                    }
+                }, OperatorCategory::Arithmetic(OperatorArithmeticType::Modulo));
+            }
             '=' => {
                 self.parse_double_operator(start_position, |char| {
                     match char {
@@ -244,6 +346,18 @@ impl Lexer {
                     }
                 }, OperatorCategory::Bitwise(OperatorBitwiseType::And));
             }
+            '^' => {
+                self.parse_double_operator(start_position, |char| {
+                    match char {
+                        '=' => Some(OperatorCategory::Assignment(OperatorAssignmentType::BitwiseXorAssignment)),
+                        _ => None
+                    }
+                }, OperatorCategory::Bitwise(OperatorBitwiseType::Xor));
+            }
+            '~' => {
+                let location = Range::new(start_position, self.position.clone());
+                self.tokens.push(Token::Operator(OperatorToken::new(OperatorCategory::Bitwise(OperatorBitwiseType::Not), location)));
+            }
             _ => ()
         }
     }
@@ -286,7 +400,7 @@ impl Lexer {
     
     fn is_parentheses(current_char: char) -> bool {
         match current_char {
-            '(' | ')' | '{' | '}' => true,
+            '(' | ')' | '{' | '}' | '[' | ']' => true,
             _ => false
         }
     }
@@ -307,13 +421,19 @@ impl Lexer {
             '}' => {
                 self.tokens.push(Token::Parentheses(ParenthesesToken::new(ParenthesesType::Curly(ParenthesesState::Closing), self.position.clone())))
             }
+            '[' => {
+                self.tokens.push(Token::Parentheses(ParenthesesToken::new(ParenthesesType::Square(ParenthesesState::Opening), self.position.clone())))
+            }
+            ']' => {
+                self.tokens.push(Token::Parentheses(ParenthesesToken::new(ParenthesesType::Square(ParenthesesState::Closing), self.position.clone())))
+            }
             _ => ()
         }
     }
     
     fn is_punctuation(current_char: char) -> bool {
         match current_char {
-            ',' | ':' | '?' => true,
+            ',' | ':' | '?' | '#' => true,
             _ => false
         }
     }
@@ -331,7 +451,163 @@ impl Lexer {
             '?' => {
                 self.tokens.push(Token::Punctuation(PunctuationToken::new(PunctuationType::QuestionMark, self.position.clone())))
             }
+            '#' => {
+                self.tokens.push(Token::Punctuation(PunctuationToken::new(PunctuationType::Hash, self.position.clone())))
+            }
             _ => ()
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::AxiomError;
+    use crate::error::location::{Position, Range};
+    use crate::lexer::Lexer;
+    use crate::token::{CharToken, StringToken, Token};
+    use crate::utils::test_support::analyze_with_errors;
+
+    fn lex(source: &str) -> (Vec<Token>, Vec<AxiomError>) {
+        Lexer::new(&source.to_string()).parse()
+    }
+
+    fn lex_char(literal: &str) -> CharToken {
+        let (tokens, _) = lex(&format!("return {}", literal));
+
+        match tokens.into_iter().nth(1) {
+            Some(Token::Char(char_token)) => char_token,
+            token => panic!("{} is not a char token: {:?}", literal, token),
+        }
+    }
+
+    fn lex_string(literal: &str) -> StringToken {
+        let (tokens, _) = lex(&format!("return {}", literal));
+
+        match tokens.into_iter().nth(1) {
+            Some(Token::String(string_token)) => string_token,
+            token => panic!("{} is not a string token: {:?}", literal, token),
+        }
+    }
+
+    fn return_errors(expression: &str) -> Vec<String> {
+        let source = format!("function main(): i32 {{\n    return {}\n}}\n", expression);
+        let (_, errors) = analyze_with_errors(&source);
+
+        errors.iter().map(|error| error.message()).collect()
+    }
+
+    #[test]
+    fn i32_bounds_are_checked_after_folding_the_sign() {
+        assert_eq!(return_errors("-2147483648"), Vec::<String>::new());
+        assert_eq!(return_errors("2147483647"), Vec::<String>::new());
+        assert_eq!(return_errors("2147483648"), vec!["Literal 2147483648 is out of range for i32"]);
+        assert_eq!(return_errors("-2147483649"), vec!["Literal -2147483649 is out of range for i32"]);
+    }
+
+    #[test]
+    fn radix_literals_wrap_into_i32() {
+        for (literal, value) in [("0xFFFFFFFF", -1), ("0x80000000", i32::MIN as i64), ("0o17", 15), ("0b1010", 10), ("0xFF_FF", 0xFFFF)] {
+            let (tokens, errors) = lex(literal);
+            assert!(errors.is_empty(), "{}: {:?}", literal, errors);
+
+            let Token::Number(number_token) = &tokens[0] else { panic!("{} is not a number token", literal) };
+            assert_eq!(number_token.parse_value(), Ok(value), "{}", literal);
+        }
+
+        assert_eq!(return_errors("0xFFFFFFFF"), Vec::<String>::new());
+        assert_eq!(return_errors("-0xFFFFFFFF"), Vec::<String>::new());
+        assert_eq!(return_errors("0x100000000"), vec!["Literal 0x100000000 is out of range for i32"]);
+    }
+
+    #[test]
+    fn malformed_literals_are_one_error() {
+        for (literal, message) in [
+            ("0x", "Malformed number literal 0x: expected at least one hexadecimal digit"),
+            ("0b102", "Malformed number literal 0b102: '2' is not a binary digit"),
+            ("1__0", "Malformed number literal 1__0: '_' must separate two digits"),
+            ("1_", "Malformed number literal 1_: '_' must separate two digits"),
+            ("12ab", "Malformed number literal 12ab: 'a' is not a decimal digit"),
+        ] {
+            let (tokens, errors) = lex(&format!("return {}", literal));
+
+            assert_eq!(tokens.len(), 2, "{}", literal);
+            assert_eq!(errors.len(), 1, "{}: {:?}", literal, errors);
+            assert_eq!(errors[0].message(), message);
+            assert_eq!(errors[0].location(), Range::new(Position::new(0, 7), Position::new(0, 6 + literal.len())));
+            assert_eq!(return_errors(literal), vec![message]);
+        }
+    }
+
+    #[test]
+    fn char_escapes() {
+        for (literal, value) in [("'\\n'", '\n'), ("'\\t'", '\t'), ("'\\r'", '\r'), ("'\\0'", '\0'), ("'\\\\'", '\\'), ("'\\''", '\''), ("'\\\"'", '"'), ("'\\u{E9}'", '\u{E9}'), ("'\\u{1F600}'", '\u{1F600}'), ("'\u{E9}'", '\u{E9}')] {
+            assert_eq!(lex_char(literal).parse_value().unwrap(), value, "{}", literal);
+        }
+    }
+
+    #[test]
+    fn malformed_char_literals_report_exact_ranges() {
+        for (literal, message, start_column, end_column) in [
+            ("''", "SyntaxError: Empty character literal", 7, 8),
+            ("'ab'", "SyntaxError: Character literal must contain exactly one character", 8, 9),
+            ("'\\q'", "SyntaxError: Unknown character escape '\\q'", 8, 9),
+            ("'\\u41'", "SyntaxError: Expected '{' after '\\u'", 8, 9),
+            ("'\\u{41'", "SyntaxError: Unterminated unicode escape", 8, 12),
+            ("'\\u{}'", "SyntaxError: Unicode escape must have between 1 and 6 hex digits", 8, 11),
+            ("'\\u{12G}'", "SyntaxError: Invalid hex digits '12G' in unicode escape", 8, 14),
+            ("'\\u{D800}'", "SyntaxError: 'D800' is not a unicode scalar value", 8, 15),
+            ("'a", "Unterminated character literal", 7, 8),
+        ] {
+            let (_, errors) = lex(&format!("return {}", literal));
+
+            assert_eq!(errors.len(), 1, "{}: {:?}", literal, errors);
+            assert_eq!(errors[0].message(), message, "{}", literal);
+            assert_eq!(errors[0].location(), Range::new(Position::new(0, start_column), Position::new(0, end_column)), "{}", literal);
+        }
+    }
+
+    #[test]
+    fn string_escapes() {
+        for (literal, value) in [("\"\"", ""), ("\"plain text\"", "plain text"), ("\"a\\tb\\n\"", "a\tb\n"), ("\"\\\"quoted\\\" 'single'\"", "\"quoted\" 'single'"), ("\"\\u{E9}t\u{E9}\"", "\u{E9}t\u{E9}")] {
+            assert_eq!(lex_string(literal).parse_value().unwrap(), value, "{}", literal);
+        }
+    }
+
+    #[test]
+    fn malformed_string_literals_report_exact_ranges() {
+        for (literal, message, start_column, end_column) in [
+            ("\"ab\\qc\"", "SyntaxError: Unknown character escape '\\q'", 10, 11),
+            ("\"a\\0\"", "SyntaxError: String literals cannot contain '\\0'", 9, 10),
+            ("\"\\u{110000}\"", "SyntaxError: '110000' is not a unicode scalar value", 8, 17),
+            ("\"open", "Unterminated string literal", 7, 11),
+        ] {
+            let (_, errors) = lex(&format!("return {}", literal));
+
+            assert_eq!(errors.len(), 1, "{}: {:?}", literal, errors);
+            assert_eq!(errors[0].message(), message, "{}", literal);
+            assert_eq!(errors[0].location(), Range::new(Position::new(0, start_column), Position::new(0, end_column)), "{}", literal);
+        }
+    }
+
+    #[test]
+    fn strings_only_take_builtin_operations() {
+        assert_eq!(return_errors("parse_i32(\"12\") + (str_eq(\"a\", \"b\") ? 1 : 0)"), Vec::<String>::new());
+        assert_eq!(return_errors("\"a\" == \"a\" ? 1 : 0"), vec!["Expected DataType: i32, but found: str"]);
+        assert_eq!(return_errors("\"1\""), vec!["Expected DataType: i32, but found: str"]);
+    }
+
+    #[test]
+    fn continues_after_unexpected_characters() {
+        let (tokens, errors) = lex("let $total = 5 @ 3");
+
+        assert_eq!(errors.len(), 2, "{:?}", errors);
+        assert_eq!(errors[0].message(), "Unexpected character '$'");
+        assert_eq!(errors[0].location(), Range::new(Position::new(0, 4), Position::new(0, 4)));
+        assert_eq!(errors[1].message(), "Unexpected character '@'");
+        assert_eq!(errors[1].location(), Range::new(Position::new(0, 15), Position::new(0, 15)));
+
+        assert_eq!(tokens.len(), 5, "{:?}", tokens);
+        assert!(matches!(&tokens[1], Token::Identifier(identifier_token) if identifier_token.name == "total"), "{:?}", tokens[1]);
+        assert!(matches!(&tokens[4], Token::Number(number_token) if number_token.value == "3"), "{:?}", tokens[4]);
+    }
+}
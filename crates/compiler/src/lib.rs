@@ -1,10 +1,15 @@
 pub mod lexer;
 mod token;
 pub mod parser;
-mod ast;
+pub mod ast;
 pub mod error;
 pub mod analyzer;
+pub mod lint;
+pub mod mir;
+pub mod optimizer;
 pub mod codegen;
+pub mod c_codegen;
+pub mod formatter;
 pub mod utils;
 pub mod datatype;
 
@@ -17,4 +22,4 @@ pub mod datatype;
 //         let result = add(2, 2);
 //         assert_eq!(result, 4);
 //     }
-// }
+// }
\ No newline at end of file
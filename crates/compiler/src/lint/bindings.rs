@@ -0,0 +1,124 @@
+use crate::ast::{FunctionNode, IdentifierNode, Node, ScopeNode};
+use crate::error::location::{Location, Range};
+
+pub struct Binding {
+    pub name: String,
+    pub location: Range,
+    pub is_parameter: bool,
+    pub is_used: bool,
+    pub shadowed_location: Option<Range>,
+}
+
+pub fn collect_bindings(function: &FunctionNode) -> Vec<Binding> {
+    let mut collector = BindingCollector {
+        bindings: vec![],
+        scopes: vec![vec![]],
+    };
+
+    for parameter in &function.parameters {
+        collector.declare(&parameter.identifier_node, true);
+    }
+
+    collector.visit_scope(&function.scope);
+
+    collector.bindings
+}
+
+struct BindingCollector {
+    bindings: Vec<Binding>,
+    scopes: Vec<Vec<usize>>,
+}
+
+impl BindingCollector {
+    fn lookup(&self, name: &str) -> Option<usize> {
+        self.scopes.iter().rev()
+            .flat_map(|scope| scope.iter().rev())
+            .find(|index| self.bindings[**index].name == name)
+            .copied()
+    }
+
+    fn declare(&mut self, identifier_node: &IdentifierNode, is_parameter: bool) {
+        let name = &identifier_node.identifier_token.name;
+        let shadowed_location = self.lookup(name).map(|index| self.bindings[index].location.clone());
+
+        self.bindings.push(Binding {
+            name: name.clone(),
+            location: identifier_node.location(),
+            is_parameter,
+            is_used: false,
+            shadowed_location,
+        });
+
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.push(self.bindings.len() - 1);
+        }
+    }
+
+    fn visit_scope(&mut self, scope_node: &ScopeNode) {
+        self.scopes.push(vec![]);
+
+        for statement in &scope_node.statements {
+            self.visit(statement);
+        }
+
+        self.scopes.pop();
+    }
+
+    fn visit(&mut self, node: &Node) {
+        match node {
+            Node::Scope(scope_node) => self.visit_scope(scope_node),
+            Node::IfElse(if_else_node) => {
+                self.visit(&if_else_node.condition);
+                self.visit_scope(&if_else_node.consequent);
+
+                for (condition, consequent) in &if_else_node.conditional_alternatives {
+                    self.visit(condition);
+                    self.visit_scope(consequent);
+                }
+
+                if let Some(alternative) = &if_else_node.alternative {
+                    self.visit_scope(alternative);
+                }
+            }
+            Node::Return(return_node) => self.visit(&return_node.expression),
+            Node::Declaration(declaration_node) => {
+                self.visit(&declaration_node.expression);
+
+                if !declaration_node.identifier_node.identifier_token.is_discard() {
+                    self.declare(&declaration_node.identifier_node, false);
+                }
+            }
+            Node::Destructuring(destructuring_node) => {
+                self.visit(&destructuring_node.expression);
+
+                for identifier_node in &destructuring_node.identifier_nodes {
+                    if !identifier_node.identifier_token.is_discard() {
+                        self.declare(identifier_node, false);
+                    }
+                }
+            }
+            Node::Assignment(assignment_node) => self.visit(&assignment_node.expression),
+            Node::Ternary(ternary_node) => {
+                self.visit(&ternary_node.condition);
+                self.visit(&ternary_node.consequent);
+                self.visit(&ternary_node.alternative);
+            }
+            Node::Binary(binary_node) => {
+                self.visit(&binary_node.left);
+                self.visit(&binary_node.right);
+            }
+            Node::Unary(unary_node) => self.visit(&unary_node.expression),
+            Node::Call(call_node) => {
+                for parameter in &call_node.parameters {
+                    self.visit(parameter);
+                }
+            }
+            Node::Identifier(identifier_node) => {
+                if let Some(index) = self.lookup(&identifier_node.identifier_token.name) {
+                    self.bindings[index].is_used = true;
+                }
+            }
+            Node::File(_) | Node::Function(_) | Node::Parameter(_) | Node::Number(_) | Node::Char(_) | Node::String(_) | Node::Error(_) => {}
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,32 @@
+use crate::ast::{FileNode, FunctionNode, Node};
+use crate::error::diagnostic::Diagnostic;
+use crate::lint::{walk_scope, Lint, LintPass};
+use crate::mir::Constant;
+use crate::optimizer::evaluate;
+
+pub struct ConstantConditions;
+
+impl LintPass for ConstantConditions {
+    fn lint(&self) -> Lint {
+        Lint::ConstantConditions
+    }
+
+    fn check(&self, function: &FunctionNode, _files: &[&FileNode], diagnostics: &mut Vec<Diagnostic>) {
+        walk_scope(&function.scope, &mut |node| {
+            if let Node::IfElse(if_else_node) = node {
+                let conditions = std::iter::once(&if_else_node.condition)
+                    .chain(if_else_node.conditional_alternatives.iter().map(|(condition, _)| condition));
+
+                for condition in conditions {
+                    if let Some(Constant::Bool(value)) = evaluate(condition) {
+                        let diagnostic = Diagnostic::new("Constant condition".to_string(), condition.location())
+                            .with_label(&format!("this condition is always {}", value))
+                            .with_help(if value { "remove the condition and keep the branch" } else { "remove the branch, it is never taken" });
+
+                        diagnostics.push(diagnostic);
+                    }
+                }
+            }
+        });
+    }
+}
\ No newline at end of file
@@ -0,0 +1,289 @@
+mod bindings;
+mod unused_variables;
+mod unused_functions;
+mod unused_parameters;
+mod shadowed_bindings;
+mod constant_conditions;
+
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter, Result};
+use crate::ast::{FileNode, FunctionNode, Node, ScopeNode};
+use crate::error::diagnostic::{Diagnostic, Severity};
+use crate::error::location::{Location, Range};
+use crate::lint::constant_conditions::ConstantConditions;
+use crate::lint::shadowed_bindings::ShadowedBindings;
+use crate::lint::unused_functions::UnusedFunctions;
+use crate::lint::unused_parameters::UnusedParameters;
+use crate::lint::unused_variables::UnusedVariables;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Lint {
+    UnusedVariables,
+    UnusedFunctions,
+    UnusedParameters,
+    ShadowedBindings,
+    ConstantConditions,
+}
+
+impl Lint {
+    pub const ALL: [Lint; 5] = [Lint::UnusedVariables, Lint::UnusedFunctions, Lint::UnusedParameters, Lint::ShadowedBindings, Lint::ConstantConditions];
+
+    pub fn parse(name: &str) -> Option<Lint> {
+        Lint::ALL.into_iter().find(|lint| lint.name() == name)
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Lint::UnusedVariables => "unused_variables",
+            Lint::UnusedFunctions => "unused_functions",
+            Lint::UnusedParameters => "unused_parameters",
+            Lint::ShadowedBindings => "shadowed_bindings",
+            Lint::ConstantConditions => "constant_conditions",
+        }
+    }
+
+    pub fn default_level(&self) -> LintLevel {
+        match self {
+            Lint::UnusedVariables => LintLevel::Warn,
+            Lint::UnusedFunctions => LintLevel::Warn,
+            Lint::UnusedParameters => LintLevel::Warn,
+            Lint::ShadowedBindings => LintLevel::Allow,
+            Lint::ConstantConditions => LintLevel::Warn,
+        }
+    }
+}
+
+impl Display for Lint {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LintLevel {
+    Allow,
+    Warn,
+    Deny,
+}
+
+impl LintLevel {
+    pub fn parse(name: &str) -> Option<LintLevel> {
+        match name {
+            "allow" => Some(LintLevel::Allow),
+            "warn" => Some(LintLevel::Warn),
+            "deny" => Some(LintLevel::Deny),
+            _ => None,
+        }
+    }
+}
+
+impl Display for LintLevel {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            LintLevel::Allow => write!(f, "allow"),
+            LintLevel::Warn => write!(f, "warn"),
+            LintLevel::Deny => write!(f, "deny"),
+        }
+    }
+}
+
+enum LevelSource {
+    Default,
+    Configuration,
+    Attribute(Range),
+    DenyWarnings,
+}
+
+pub trait LintPass {
+    fn lint(&self) -> Lint;
+
+    /// Checks one function. `files` holds every file of the program, including the one declaring `function`.
+    fn check(&self, function: &FunctionNode, files: &[&FileNode], diagnostics: &mut Vec<Diagnostic>);
+}
+
+pub struct LintRegistry {
+    passes: Vec<Box<dyn LintPass>>,
+    levels: HashMap<Lint, LintLevel>,
+    deny_warnings: bool,
+}
+
+impl LintRegistry {
+    pub fn new() -> LintRegistry {
+        let mut lint_registry = LintRegistry {
+            passes: vec![],
+            levels: HashMap::new(),
+            deny_warnings: false,
+        };
+
+        lint_registry.register(Box::from(UnusedVariables));
+        lint_registry.register(Box::from(UnusedFunctions));
+        lint_registry.register(Box::from(UnusedParameters));
+        lint_registry.register(Box::from(ShadowedBindings));
+        lint_registry.register(Box::from(ConstantConditions));
+
+        lint_registry
+    }
+
+    pub fn register(&mut self, pass: Box<dyn LintPass>) {
+        self.passes.push(pass);
+    }
+
+    pub fn set_level(&mut self, lint: Lint, level: LintLevel) {
+        self.levels.insert(lint, level);
+    }
+
+    pub fn set_deny_warnings(&mut self, deny_warnings: bool) {
+        self.deny_warnings = deny_warnings;
+    }
+
+    pub fn check(&self, ast: &Node) -> Vec<Diagnostic> {
+        self.check_files(&[ast]).remove(0)
+    }
+
+    /// Lints files that make up one program, returning the diagnostics of each file in the given order.
+    pub fn check_files(&self, asts: &[&Node]) -> Vec<Vec<Diagnostic>> {
+        let file_nodes: Vec<&FileNode> = asts.iter()
+            .filter_map(|ast| match ast {
+                Node::File(file_node) => Some(file_node),
+                _ => None,
+            })
+            .collect();
+
+        asts.iter().map(|ast| match ast {
+            Node::File(file_node) => self.check_file(file_node, &file_nodes),
+            _ => vec![],
+        }).collect()
+    }
+
+    fn check_file(&self, file_node: &FileNode, file_nodes: &[&FileNode]) -> Vec<Diagnostic> {
+        let mut diagnostics = vec![];
+
+        for function in &file_node.functions {
+            for pass in &self.passes {
+                let lint = pass.lint();
+                let (level, source) = self.level(lint, function);
+
+                if level == LintLevel::Allow {
+                    continue;
+                }
+
+                let mut findings = vec![];
+                pass.check(function, file_nodes, &mut findings);
+
+                diagnostics.extend(findings.into_iter().map(|diagnostic| LintRegistry::finish(diagnostic, lint, level, &source)));
+            }
+        }
+
+        diagnostics.sort_by(|left, right| left.location.start.cmp(&right.location.start));
+
+        diagnostics
+    }
+
+    fn level(&self, lint: Lint, function: &FunctionNode) -> (LintLevel, LevelSource) {
+        let mut level = lint.default_level();
+        let mut source = LevelSource::Default;
+
+        if let Some(configured_level) = self.levels.get(&lint) {
+            level = *configured_level;
+            source = LevelSource::Configuration;
+        }
+
+        for attribute in &function.attributes {
+            let attribute_level = match LintLevel::parse(&attribute.identifier_node.identifier_token.name) {
+                Some(attribute_level) => attribute_level,
+                None => continue,
+            };
+
+            if attribute.arguments.iter().any(|argument| argument.identifier_token.name == lint.name()) {
+                level = attribute_level;
+                source = LevelSource::Attribute(attribute.location());
+            }
+        }
+
+        if self.deny_warnings && level == LintLevel::Warn {
+            level = LintLevel::Deny;
+            source = LevelSource::DenyWarnings;
+        }
+
+        (level, source)
+    }
+
+    fn finish(diagnostic: Diagnostic, lint: Lint, level: LintLevel, source: &LevelSource) -> Diagnostic {
+        let severity = match level {
+            LintLevel::Deny => Severity::Error,
+            _ => Severity::Warning,
+        };
+
+        let diagnostic = diagnostic.with_severity(severity).with_lint(lint);
+
+        match source {
+            LevelSource::Default => diagnostic.with_note(&format!("'#[{}({})]' on by default", level, lint)),
+            LevelSource::Configuration => diagnostic.with_note(&format!("'{} = \"{}\"' set in the project configuration", lint, level)),
+            LevelSource::Attribute(location) => diagnostic.with_secondary_label(location.clone(), "the lint level is set here"),
+            LevelSource::DenyWarnings => diagnostic.with_note(&format!("'#[deny({})]' implied by '--deny-warnings'", lint)),
+        }
+    }
+}
+
+impl Default for LintRegistry {
+    fn default() -> Self {
+        LintRegistry::new()
+    }
+}
+
+fn walk(node: &Node, visitor: &mut dyn FnMut(&Node)) {
+    visitor(node);
+
+    match node {
+        Node::File(file_node) => {
+            for function in &file_node.functions {
+                walk_scope(&function.scope, visitor);
+            }
+        }
+        Node::Function(function_node) => walk_scope(&function_node.scope, visitor),
+        Node::Scope(scope_node) => walk_scope(scope_node, visitor),
+        Node::IfElse(if_else_node) => {
+            walk(&if_else_node.condition, visitor);
+            walk_scope(&if_else_node.consequent, visitor);
+
+            for (condition, consequent) in &if_else_node.conditional_alternatives {
+                walk(condition, visitor);
+                walk_scope(consequent, visitor);
+            }
+
+            if let Some(alternative) = &if_else_node.alternative {
+                walk_scope(alternative, visitor);
+            }
+        }
+        Node::Return(return_node) => walk(&return_node.expression, visitor),
+        Node::Declaration(declaration_node) => walk(&declaration_node.expression, visitor),
+        Node::Destructuring(destructuring_node) => walk(&destructuring_node.expression, visitor),
+        Node::Assignment(assignment_node) => walk(&assignment_node.expression, visitor),
+        Node::Ternary(ternary_node) => {
+            walk(&ternary_node.condition, visitor);
+            walk(&ternary_node.consequent, visitor);
+            walk(&ternary_node.alternative, visitor);
+        }
+        Node::Binary(binary_node) => {
+            walk(&binary_node.left, visitor);
+            walk(&binary_node.right, visitor);
+        }
+        Node::Unary(unary_node) => walk(&unary_node.expression, visitor),
+        Node::Call(call_node) => {
+            for parameter in &call_node.parameters {
+                walk(parameter, visitor);
+            }
+        }
+        Node::Parameter(_) | Node::Number(_) | Node::Char(_) | Node::String(_) | Node::Identifier(_) | Node::Error(_) => {}
+    }
+}
+
+fn walk_scope(scope_node: &ScopeNode, visitor: &mut dyn FnMut(&Node)) {
+    for statement in &scope_node.statements {
+        walk(statement, visitor);
+    }
+}
+
+fn underscore_help(name: &str) -> String {
+    format!("if this is intentional, prefix it with an underscore: '_{}'", name)
+}
\ No newline at end of file
@@ -0,0 +1,28 @@
+use crate::ast::{FileNode, FunctionNode};
+use crate::error::diagnostic::Diagnostic;
+use crate::lint::{Lint, LintPass};
+use crate::lint::bindings::collect_bindings;
+
+pub struct ShadowedBindings;
+
+impl LintPass for ShadowedBindings {
+    fn lint(&self) -> Lint {
+        Lint::ShadowedBindings
+    }
+
+    fn check(&self, function: &FunctionNode, _files: &[&FileNode], diagnostics: &mut Vec<Diagnostic>) {
+        for binding in collect_bindings(function) {
+            let shadowed_location = match binding.shadowed_location {
+                Some(shadowed_location) => shadowed_location,
+                None => continue,
+            };
+
+            let diagnostic = Diagnostic::new(format!("Shadowed binding: {}", binding.name), binding.location)
+                .with_label(&format!("'{}' is declared again here", binding.name))
+                .with_secondary_label(shadowed_location, "previous binding here")
+                .with_help("rename one of the bindings");
+
+            diagnostics.push(diagnostic);
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,66 @@
+use std::collections::HashSet;
+use crate::ast::{FileNode, FunctionNode, Node};
+use crate::error::diagnostic::Diagnostic;
+use crate::error::location::Location;
+use crate::lint::{underscore_help, walk_scope, Lint, LintPass};
+
+pub struct UnusedFunctions;
+
+impl LintPass for UnusedFunctions {
+    fn lint(&self) -> Lint {
+        Lint::UnusedFunctions
+    }
+
+    fn check(&self, function: &FunctionNode, files: &[&FileNode], diagnostics: &mut Vec<Diagnostic>) {
+        let name = &function.identifier_node.identifier_token.name;
+
+        if name == "main" || name.starts_with('_') || function.is_test() {
+            return;
+        }
+
+        let reachable = match reachable_functions(files) {
+            Some(reachable) => reachable,
+            None => return,
+        };
+
+        if reachable.contains(name) {
+            return;
+        }
+
+        let diagnostic = Diagnostic::new(format!("Unused function: {}", name), function.identifier_node.location())
+            .with_label("never called from 'main' or a test")
+            .with_help(&underscore_help(name));
+
+        diagnostics.push(diagnostic);
+    }
+}
+
+fn reachable_functions(files: &[&FileNode]) -> Option<HashSet<String>> {
+    let functions: Vec<&FunctionNode> = files.iter().flat_map(|file| file.functions.iter().map(|function| function.as_ref())).collect();
+    let main = functions.iter().find(|function| function.identifier_node.identifier_token.name == "main")?;
+
+    let mut reachable = HashSet::new();
+    let mut pending = vec![*main];
+    pending.extend(functions.iter().filter(|function| function.is_test()));
+
+    while let Some(function) = pending.pop() {
+        let mut callees = vec![];
+        walk_scope(&function.scope, &mut |node| {
+            if let Node::Call(call_node) = node {
+                callees.push(call_node.identifier_node.identifier_token.name.clone());
+            }
+        });
+
+        for callee in callees {
+            if !reachable.insert(callee.clone()) {
+                continue;
+            }
+
+            if let Some(function) = functions.iter().find(|function| function.identifier_node.identifier_token.name == callee) {
+                pending.push(function);
+            }
+        }
+    }
+
+    Some(reachable)
+}
\ No newline at end of file
@@ -0,0 +1,26 @@
+use crate::ast::{FileNode, FunctionNode};
+use crate::error::diagnostic::Diagnostic;
+use crate::lint::{underscore_help, Lint, LintPass};
+use crate::lint::bindings::collect_bindings;
+
+pub struct UnusedParameters;
+
+impl LintPass for UnusedParameters {
+    fn lint(&self) -> Lint {
+        Lint::UnusedParameters
+    }
+
+    fn check(&self, function: &FunctionNode, _files: &[&FileNode], diagnostics: &mut Vec<Diagnostic>) {
+        for binding in collect_bindings(function) {
+            if !binding.is_parameter || binding.is_used || binding.name.starts_with('_') {
+                continue;
+            }
+
+            let diagnostic = Diagnostic::new(format!("Unused parameter: {}", binding.name), binding.location)
+                .with_label("never read in the function body")
+                .with_help(&underscore_help(&binding.name));
+
+            diagnostics.push(diagnostic);
+        }
+    }
+}
\ No newline at end of file
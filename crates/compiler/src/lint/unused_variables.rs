@@ -0,0 +1,26 @@
+use crate::ast::{FileNode, FunctionNode};
+use crate::error::diagnostic::Diagnostic;
+use crate::lint::{underscore_help, Lint, LintPass};
+use crate::lint::bindings::collect_bindings;
+
+pub struct UnusedVariables;
+
+impl LintPass for UnusedVariables {
+    fn lint(&self) -> Lint {
+        Lint::UnusedVariables
+    }
+
+    fn check(&self, function: &FunctionNode, _files: &[&FileNode], diagnostics: &mut Vec<Diagnostic>) {
+        for binding in collect_bindings(function) {
+            if binding.is_parameter || binding.is_used || binding.name.starts_with('_') {
+                continue;
+            }
+
+            let diagnostic = Diagnostic::new(format!("Unused variable: {}", binding.name), binding.location)
+                .with_label("declared here but never read")
+                .with_help(&underscore_help(&binding.name));
+
+            diagnostics.push(diagnostic);
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,285 @@
+use crate::error::location::Range;
+use crate::mir::{BasicBlock, BinaryOperation, BlockId, Constant, Instruction, Local, LocalId, MirFunction, MirModule, MirType, Operand, PanicKind, Terminator, UnaryOperation, ValueId};
+use crate::utils::SymbolTable;
+
+pub struct MirBuilder {
+    module: MirModule,
+    source: String,
+    function: Option<MirFunction>,
+    current_block: BlockId,
+    next_parameter: usize,
+    runtime_checks: bool,
+    pub variables: SymbolTable<String, LocalId>,
+}
+
+impl MirBuilder {
+    pub(crate) fn new(source_name: &str, source: &str, runtime_checks: bool) -> MirBuilder {
+        MirBuilder {
+            module: MirModule {
+                source_name: source_name.to_string(),
+                functions: vec![],
+            },
+            source: source.to_string(),
+            function: None,
+            current_block: BlockId(0),
+            next_parameter: 0,
+            runtime_checks,
+            variables: SymbolTable::new(),
+        }
+    }
+
+    pub(crate) fn finish(self) -> MirModule {
+        self.module
+    }
+
+    fn function(&mut self) -> &mut MirFunction {
+        self.function.as_mut().expect("MIR instruction built outside of a function")
+    }
+
+    pub fn begin_function(&mut self, name: &str, parameters: Vec<MirType>, return_type: MirType) {
+        self.function = Some(MirFunction {
+            name: name.to_string(),
+            parameters,
+            return_type,
+            locals: vec![],
+            values: vec![],
+            blocks: vec![],
+        });
+
+        self.next_parameter = 0;
+
+        let entry_block = self.new_block("entry");
+        self.switch_to(entry_block);
+    }
+
+    pub fn end_function(&mut self) {
+        let mut function = self.function.take().expect("No function to end");
+
+        let default_terminator = match function.return_type {
+            MirType::Void => Terminator::Return(None),
+            _ => Terminator::Unreachable,
+        };
+
+        for block in &mut function.blocks {
+            if block.terminator.is_none() {
+                block.terminator = Some(default_terminator.clone());
+            }
+        }
+
+        self.module.functions.push(function);
+    }
+
+    pub fn add_local(&mut self, name: &str, mir_type: MirType) -> LocalId {
+        let function = self.function();
+        let local = LocalId(function.locals.len());
+
+        function.locals.push(Local {
+            name: name.to_string(),
+            mir_type,
+        });
+
+        self.variables.add(name.to_string(), local);
+
+        local
+    }
+
+    pub fn add_parameter(&mut self, name: &str) -> LocalId {
+        let index = self.next_parameter;
+        self.next_parameter += 1;
+
+        let mir_type = self.function().parameters[index];
+        let local = self.add_local(name, mir_type);
+
+        self.build_store(local, Operand::Parameter(index));
+
+        local
+    }
+
+    pub fn new_block(&mut self, label: &str) -> BlockId {
+        let function = self.function();
+        let block = BlockId(function.blocks.len());
+
+        function.blocks.push(BasicBlock {
+            label: label.to_string(),
+            instructions: vec![],
+            terminator: None,
+        });
+
+        block
+    }
+
+    pub fn switch_to(&mut self, block: BlockId) {
+        self.current_block = block;
+    }
+
+    pub fn current_block(&self) -> BlockId {
+        self.current_block
+    }
+
+    pub fn is_terminated(&self) -> bool {
+        let function = self.function.as_ref().expect("No function is being built");
+
+        function.blocks[self.current_block.0].terminator.is_some()
+    }
+
+    pub fn terminate(&mut self, terminator: Terminator) {
+        if self.is_terminated() {
+            return;
+        }
+
+        let current_block = self.current_block;
+        self.function().blocks[current_block.0].terminator = Some(terminator);
+    }
+
+    fn new_value(&mut self, mir_type: MirType) -> ValueId {
+        let function = self.function();
+        let value = ValueId(function.values.len());
+
+        function.values.push(mir_type);
+
+        value
+    }
+
+    fn push(&mut self, instruction: Instruction) {
+        if self.is_terminated() {
+            let unreachable_block = self.new_block("unreachable");
+            self.switch_to(unreachable_block);
+        }
+
+        let current_block = self.current_block;
+        self.function().blocks[current_block.0].instructions.push(instruction);
+    }
+
+    pub fn build_binary(&mut self, operation: BinaryOperation, left: Operand, right: Operand) -> Operand {
+        let mir_type = if operation.is_comparison() {
+            MirType::Bool
+        } else {
+            self.function().operand_type(&left)
+        };
+
+        let result = self.new_value(mir_type);
+        self.push(Instruction::Binary { result, operation, left, right });
+
+        Operand::Value(result)
+    }
+
+    pub fn build_checked_binary(&mut self, operation: BinaryOperation, left: Operand, right: Operand, location: &Range) -> Operand {
+        if !self.runtime_checks {
+            return self.build_binary(operation, left, right);
+        }
+
+        let panic_kind = match operation {
+            BinaryOperation::Add => PanicKind::AddOverflow,
+            BinaryOperation::Subtract => PanicKind::SubtractOverflow,
+            BinaryOperation::Multiply => PanicKind::MultiplyOverflow,
+            BinaryOperation::Divide => {
+                self.build_division_checks(left.clone(), right.clone(), PanicKind::DivisionByZero, PanicKind::DivisionOverflow, location);
+
+                return self.build_binary(operation, left, right);
+            }
+            BinaryOperation::Remainder => {
+                self.build_division_checks(left.clone(), right.clone(), PanicKind::RemainderByZero, PanicKind::RemainderOverflow, location);
+
+                return self.build_binary(operation, left, right);
+            }
+            BinaryOperation::Power => {
+                let is_negative = self.build_binary(BinaryOperation::LessThan, right.clone(), Operand::Constant(Constant::I32(0)));
+                self.build_panic_branch(is_negative, PanicKind::NegativeExponent, location);
+
+                PanicKind::PowerOverflow
+            }
+            _ => return self.build_binary(operation, left, right),
+        };
+
+        let (overflowed, result) = self.build_overflowing_binary(operation, left, right);
+        self.build_panic_branch(overflowed, panic_kind, location);
+
+        result
+    }
+
+    pub fn build_overflowing_binary(&mut self, operation: BinaryOperation, left: Operand, right: Operand) -> (Operand, Operand) {
+        let mir_type = self.function().operand_type(&left);
+        let result = self.new_value(mir_type);
+        let overflowed = self.new_value(MirType::Bool);
+
+        self.push(Instruction::CheckedBinary { result, overflowed, operation, left, right });
+
+        (Operand::Value(overflowed), Operand::Value(result))
+    }
+
+    fn build_division_checks(&mut self, left: Operand, right: Operand, zero_kind: PanicKind, overflow_kind: PanicKind, location: &Range) {
+        let is_zero = self.build_binary(BinaryOperation::Equal, right.clone(), Operand::Constant(Constant::I32(0)));
+        self.build_panic_branch(is_zero, zero_kind, location);
+
+        let is_minimum = self.build_binary(BinaryOperation::Equal, left, Operand::Constant(Constant::I32(i32::MIN)));
+        let is_minus_one = self.build_binary(BinaryOperation::Equal, right, Operand::Constant(Constant::I32(-1)));
+        let overflows = self.build_binary(BinaryOperation::BitwiseAnd, is_minimum, is_minus_one);
+        self.build_panic_branch(overflows, overflow_kind, location);
+    }
+
+    pub fn build_assertion(&mut self, condition: Operand, location: &Range) {
+        let failed = self.build_unary(UnaryOperation::Not, condition);
+        let expression = location.text(&self.source);
+
+        self.build_panic_branch(failed, PanicKind::AssertionFailed(expression), location);
+    }
+
+    pub fn build_equality_assertion(&mut self, left: Operand, right: Operand, location: &Range) {
+        let failed = self.build_binary(BinaryOperation::NotEqual, left.clone(), right.clone());
+        let expression = location.text(&self.source);
+
+        self.build_panic_branch(failed, PanicKind::AssertionNotEqual(expression, left, right), location);
+    }
+
+    fn build_panic_branch(&mut self, condition: Operand, kind: PanicKind, location: &Range) {
+        let panic_block = self.new_block("panic");
+        let continue_block = self.new_block("checked");
+
+        self.terminate(Terminator::Branch { condition, then_block: panic_block, else_block: continue_block });
+
+        self.switch_to(panic_block);
+        self.terminate(Terminator::Panic { kind, location: location.clone() });
+
+        self.switch_to(continue_block);
+    }
+
+    pub fn build_unary(&mut self, operation: UnaryOperation, operand: Operand) -> Operand {
+        let mir_type = self.function().operand_type(&operand);
+
+        let result = self.new_value(mir_type);
+        self.push(Instruction::Unary { result, operation, operand });
+
+        Operand::Value(result)
+    }
+
+    pub fn build_load(&mut self, local: LocalId) -> Operand {
+        let mir_type = self.function().locals[local.0].mir_type;
+
+        let result = self.new_value(mir_type);
+        self.push(Instruction::Load { result, local });
+
+        Operand::Value(result)
+    }
+
+    pub fn build_store(&mut self, local: LocalId, value: Operand) {
+        self.push(Instruction::Store { local, value });
+    }
+
+    pub fn build_call(&mut self, function: &str, arguments: Vec<Operand>, return_type: MirType) -> Option<Operand> {
+        let result = match return_type {
+            MirType::Void => None,
+            mir_type => Some(self.new_value(mir_type)),
+        };
+
+        self.push(Instruction::Call { result, function: function.to_string(), arguments });
+
+        result.map(Operand::Value)
+    }
+
+    pub fn build_phi(&mut self, incoming: Vec<(Operand, BlockId)>, mir_type: MirType) -> Operand {
+        let result = self.new_value(mir_type);
+        self.push(Instruction::Phi { result, incoming });
+
+        Operand::Value(result)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,164 @@
+use std::fmt::{Display, Formatter};
+use crate::mir::{BinaryOperation, BlockId, Constant, Instruction, LocalId, MirFunction, MirModule, MirType, Operand, PanicKind, Terminator, UnaryOperation, ValueId};
+
+impl Display for MirType {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            MirType::Void => write!(f, "void"),
+            MirType::Bool => write!(f, "bool"),
+            MirType::I32 => write!(f, "i32"),
+            MirType::Char => write!(f, "char"),
+            MirType::Str => write!(f, "str"),
+        }
+    }
+}
+
+impl Display for ValueId {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "%{}", self.0)
+    }
+}
+
+impl Display for BlockId {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "bb{}", self.0)
+    }
+}
+
+impl Display for LocalId {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "_{}", self.0)
+    }
+}
+
+impl Display for Constant {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Constant::I32(value) => write!(f, "{}", value),
+            Constant::Bool(value) => write!(f, "{}", value),
+            Constant::Char(value) => write!(f, "{:?}", value),
+            Constant::Str(value) => write!(f, "{:?}", value),
+        }
+    }
+}
+
+impl Display for Operand {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Operand::Constant(constant) => write!(f, "{}", constant),
+            Operand::Value(value) => write!(f, "{}", value),
+            Operand::Parameter(index) => write!(f, "arg{}", index),
+        }
+    }
+}
+
+impl Display for BinaryOperation {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            BinaryOperation::Add => write!(f, "add"),
+            BinaryOperation::Subtract => write!(f, "sub"),
+            BinaryOperation::Multiply => write!(f, "mul"),
+            BinaryOperation::Divide => write!(f, "div"),
+            BinaryOperation::Remainder => write!(f, "rem"),
+            BinaryOperation::Power => write!(f, "pow"),
+            BinaryOperation::Equal => write!(f, "eq"),
+            BinaryOperation::NotEqual => write!(f, "ne"),
+            BinaryOperation::GreaterThan => write!(f, "gt"),
+            BinaryOperation::GreaterThanOrEqual => write!(f, "ge"),
+            BinaryOperation::LessThan => write!(f, "lt"),
+            BinaryOperation::LessThanOrEqual => write!(f, "le"),
+            BinaryOperation::ShiftLeft => write!(f, "shl"),
+            BinaryOperation::ShiftRight => write!(f, "shr"),
+            BinaryOperation::BitwiseOr => write!(f, "or"),
+            BinaryOperation::BitwiseAnd => write!(f, "and"),
+            BinaryOperation::BitwiseXor => write!(f, "xor"),
+        }
+    }
+}
+
+impl Display for UnaryOperation {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            UnaryOperation::Negate => write!(f, "neg"),
+            UnaryOperation::Absolute => write!(f, "abs"),
+            UnaryOperation::Not => write!(f, "not"),
+            UnaryOperation::BitwiseNot => write!(f, "bitnot"),
+        }
+    }
+}
+
+impl MirFunction {
+    fn display_instruction(&self, instruction: &Instruction) -> String {
+        match instruction {
+            Instruction::Binary { result, operation, left, right } => format!("{}: {} = {} {}, {}", result, self.values[result.0], operation, left, right),
+            Instruction::CheckedBinary { result, overflowed, operation, left, right } => {
+                format!("{}: {}, {}: {} = {}.checked {}, {}", result, self.values[result.0], overflowed, self.values[overflowed.0], operation, left, right)
+            }
+            Instruction::Unary { result, operation, operand } => format!("{}: {} = {} {}", result, self.values[result.0], operation, operand),
+            Instruction::Load { result, local } => format!("{}: {} = load {}", result, self.values[result.0], local),
+            Instruction::Store { local, value } => format!("store {}, {}", local, value),
+            Instruction::Call { result, function, arguments } => {
+                let arguments: Vec<String> = arguments.iter().map(|argument| argument.to_string()).collect();
+
+                match result {
+                    Some(result) => format!("{}: {} = call {}({})", result, self.values[result.0], function, arguments.join(", ")),
+                    None => format!("call {}({})", function, arguments.join(", ")),
+                }
+            }
+            Instruction::Phi { result, incoming } => {
+                let incoming: Vec<String> = incoming.iter().map(|(operand, block)| format!("[{}, {}]", operand, block)).collect();
+
+                format!("{}: {} = phi {}", result, self.values[result.0], incoming.join(", "))
+            }
+        }
+    }
+}
+
+impl Display for Terminator {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Terminator::Return(Some(operand)) => write!(f, "return {}", operand),
+            Terminator::Return(None) => write!(f, "return"),
+            Terminator::Jump(block) => write!(f, "jump {}", block),
+            Terminator::Branch { condition, then_block, else_block } => write!(f, "branch {}, {}, {}", condition, then_block, else_block),
+            Terminator::Panic { kind: kind @ PanicKind::AssertionNotEqual(_, left, right), location } => write!(f, "panic \"{}\" ({}, {}) at {}:{}", kind.message(), left, right, location.start.line + 1, location.start.column + 1),
+            Terminator::Panic { kind, location } => write!(f, "panic \"{}\" at {}:{}", kind.message(), location.start.line + 1, location.start.column + 1),
+            Terminator::Unreachable => write!(f, "unreachable"),
+        }
+    }
+}
+
+impl Display for MirFunction {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let parameters: Vec<String> = self.parameters.iter().enumerate().map(|(index, parameter)| format!("arg{}: {}", index, parameter)).collect();
+
+        writeln!(f, "function {}({}) -> {} {{", self.name, parameters.join(", "), self.return_type)?;
+
+        for (index, local) in self.locals.iter().enumerate() {
+            writeln!(f, "    let {}: {}; // {}", LocalId(index), local.mir_type, local.name)?;
+        }
+
+        for (index, block) in self.blocks.iter().enumerate() {
+            writeln!(f)?;
+            writeln!(f, "  {} ({}):", BlockId(index), block.label)?;
+
+            for instruction in &block.instructions {
+                writeln!(f, "    {}", self.display_instruction(instruction))?;
+            }
+
+            if let Some(terminator) = &block.terminator {
+                writeln!(f, "    {}", terminator)?;
+            }
+        }
+
+        writeln!(f, "}}")
+    }
+}
+
+impl Display for MirModule {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let functions: Vec<String> = self.functions.iter().map(|function| function.to_string()).collect();
+
+        write!(f, "{}", functions.join("\n"))
+    }
+}
\ No newline at end of file
@@ -0,0 +1,368 @@
+mod builder;
+mod display;
+mod nodes;
+
+pub use crate::mir::builder::MirBuilder;
+use crate::ast::Node;
+use crate::datatype::DataType;
+use crate::error::location::Range;
+
+pub trait Lower {
+    fn lower(&self, mir_builder: &mut MirBuilder) -> Option<Operand>;
+}
+
+pub fn lower(ast: &Node, source_name: &str, source: &str, runtime_checks: bool) -> MirModule {
+    let mut mir_builder = MirBuilder::new(source_name, source, runtime_checks);
+
+    ast.lower(&mut mir_builder);
+
+    mir_builder.finish()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MirType {
+    Void,
+    Bool,
+    I32,
+    Char,
+    Str,
+}
+
+impl MirType {
+    pub fn from_data_type(data_type: &DataType) -> MirType {
+        match data_type {
+            DataType::None => MirType::Void,
+            DataType::Bool => MirType::Bool,
+            DataType::I32 => MirType::I32,
+            DataType::Char => MirType::Char,
+            DataType::Str => MirType::Str,
+            // The argument list is global, so at runtime it is only its length
+            DataType::Args => MirType::I32,
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn is_signed(&self) -> bool {
+        matches!(self, MirType::I32)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ValueId(pub usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BlockId(pub usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LocalId(pub usize);
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Constant {
+    I32(i32),
+    Bool(bool),
+    Char(char),
+    Str(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Operand {
+    Constant(Constant),
+    Value(ValueId),
+    Parameter(usize),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BinaryOperation {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Remainder,
+    Power,
+    Equal,
+    NotEqual,
+    GreaterThan,
+    GreaterThanOrEqual,
+    LessThan,
+    LessThanOrEqual,
+    ShiftLeft,
+    ShiftRight,
+    BitwiseOr,
+    BitwiseAnd,
+    BitwiseXor,
+}
+
+impl BinaryOperation {
+    pub fn is_comparison(&self) -> bool {
+        matches!(self, BinaryOperation::Equal | BinaryOperation::NotEqual | BinaryOperation::GreaterThan | BinaryOperation::GreaterThanOrEqual | BinaryOperation::LessThan | BinaryOperation::LessThanOrEqual)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UnaryOperation {
+    Negate,
+    Absolute,
+    Not,
+    BitwiseNot,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PanicKind {
+    AddOverflow,
+    SubtractOverflow,
+    MultiplyOverflow,
+    DivisionByZero,
+    DivisionOverflow,
+    RemainderByZero,
+    RemainderOverflow,
+    PowerOverflow,
+    NegativeExponent,
+    AssertionFailed(String),
+    AssertionNotEqual(String, Operand, Operand),
+}
+
+impl PanicKind {
+    pub fn message(&self) -> String {
+        match self {
+            PanicKind::AddOverflow => "attempt to add with overflow".to_string(),
+            PanicKind::SubtractOverflow => "attempt to subtract with overflow".to_string(),
+            PanicKind::MultiplyOverflow => "attempt to multiply with overflow".to_string(),
+            PanicKind::DivisionByZero => "attempt to divide by zero".to_string(),
+            PanicKind::DivisionOverflow => "attempt to divide with overflow".to_string(),
+            PanicKind::RemainderByZero => "attempt to calculate the remainder with a divisor of zero".to_string(),
+            PanicKind::RemainderOverflow => "attempt to calculate the remainder with overflow".to_string(),
+            PanicKind::PowerOverflow => "attempt to raise to a power with overflow".to_string(),
+            PanicKind::NegativeExponent => "attempt to raise to a negative power".to_string(),
+            PanicKind::AssertionFailed(expression) => format!("assertion failed: {}", expression),
+            PanicKind::AssertionNotEqual(expression, _, _) => format!("assertion failed: {}", expression),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Instruction {
+    Binary { result: ValueId, operation: BinaryOperation, left: Operand, right: Operand },
+    CheckedBinary { result: ValueId, overflowed: ValueId, operation: BinaryOperation, left: Operand, right: Operand },
+    Unary { result: ValueId, operation: UnaryOperation, operand: Operand },
+    Load { result: ValueId, local: LocalId },
+    Store { local: LocalId, value: Operand },
+    Call { result: Option<ValueId>, function: String, arguments: Vec<Operand> },
+    Phi { result: ValueId, incoming: Vec<(Operand, BlockId)> },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Terminator {
+    Return(Option<Operand>),
+    Jump(BlockId),
+    Branch { condition: Operand, then_block: BlockId, else_block: BlockId },
+    Panic { kind: PanicKind, location: Range },
+    Unreachable,
+}
+
+impl Terminator {
+    pub fn successors(&self) -> Vec<BlockId> {
+        match self {
+            Terminator::Return(_) | Terminator::Panic { .. } | Terminator::Unreachable => vec![],
+            Terminator::Jump(block) => vec![*block],
+            Terminator::Branch { then_block, else_block, .. } => vec![*then_block, *else_block],
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BasicBlock {
+    pub label: String,
+    pub instructions: Vec<Instruction>,
+    pub terminator: Option<Terminator>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Local {
+    pub name: String,
+    pub mir_type: MirType,
+}
+
+#[derive(Debug, Clone)]
+pub struct MirFunction {
+    pub name: String,
+    pub parameters: Vec<MirType>,
+    pub return_type: MirType,
+    pub locals: Vec<Local>,
+    pub values: Vec<MirType>,
+    pub blocks: Vec<BasicBlock>,
+}
+
+impl MirFunction {
+    pub fn operand_type(&self, operand: &Operand) -> MirType {
+        match operand {
+            Operand::Constant(Constant::I32(_)) => MirType::I32,
+            Operand::Constant(Constant::Bool(_)) => MirType::Bool,
+            Operand::Constant(Constant::Char(_)) => MirType::Char,
+            Operand::Constant(Constant::Str(_)) => MirType::Str,
+            Operand::Value(value) => self.values[value.0],
+            Operand::Parameter(index) => self.parameters[*index],
+        }
+    }
+
+    /// Orders blocks so that every block comes after all of its dominators, unreachable blocks last.
+    pub fn block_order(&self) -> Vec<BlockId> {
+        let mut visited = vec![false; self.blocks.len()];
+        let mut postorder = vec![];
+        let mut stack = vec![(BlockId(0), false)];
+
+        while let Some((block, children_visited)) = stack.pop() {
+            if children_visited {
+                postorder.push(block);
+                continue;
+            }
+
+            if visited[block.0] {
+                continue;
+            }
+            visited[block.0] = true;
+
+            stack.push((block, true));
+
+            if let Some(terminator) = &self.blocks[block.0].terminator {
+                for successor in terminator.successors().into_iter().rev() {
+                    if !visited[successor.0] {
+                        stack.push((successor, false));
+                    }
+                }
+            }
+        }
+
+        postorder.reverse();
+
+        let unreachable_blocks = (0..self.blocks.len()).filter(|index| !visited[*index]).map(BlockId);
+        postorder.extend(unreachable_blocks);
+
+        postorder
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct MirModule {
+    pub source_name: String,
+    pub functions: Vec<MirFunction>,
+}
+#[cfg(test)]
+mod tests {
+    use crate::mir::lower;
+    use crate::utils::test_support::analyze;
+
+    fn lower_source(source: &str) -> String {
+        lower(&analyze(source), "test.axiom", source, false).to_string()
+    }
+
+    #[test]
+    fn if_else() {
+        let source = "function sign(n: i32): i32 {
+    let result = 0
+    if n < 0 {
+        result = -1
+    } else {
+        result = 1
+    }
+    return result
+}
+";
+
+        let expected = "function sign(arg0: i32) -> i32 {
+    let _0: i32; // n
+    let _1: i32; // result
+
+  bb0 (entry):
+    store _0, arg0
+    store _1, 0
+    %0: i32 = load _0
+    %1: bool = lt %0, 0
+    branch %1, bb2, bb3
+
+  bb1 (if.merge):
+    %2: i32 = load _1
+    return %2
+
+  bb2 (if.then):
+    store _1, -1
+    jump bb1
+
+  bb3 (if.else):
+    store _1, 1
+    jump bb1
+}
+";
+
+        assert_eq!(lower_source(source), expected);
+    }
+
+    #[test]
+    fn ternary() {
+        let source = "function max(a: i32, b: i32): i32 {
+    return a > b ? a : b
+}
+";
+
+        let expected = "function max(arg0: i32, arg1: i32) -> i32 {
+    let _0: i32; // a
+    let _1: i32; // b
+
+  bb0 (entry):
+    store _0, arg0
+    store _1, arg1
+    %0: i32 = load _0
+    %1: i32 = load _1
+    %2: bool = gt %0, %1
+    branch %2, bb1, bb2
+
+  bb1 (ternary.then):
+    %3: i32 = load _0
+    jump bb3
+
+  bb2 (ternary.else):
+    %4: i32 = load _1
+    jump bb3
+
+  bb3 (ternary.merge):
+    %5: i32 = phi [%3, bb1], [%4, bb2]
+    return %5
+}
+";
+
+        assert_eq!(lower_source(source), expected);
+    }
+
+    #[test]
+    fn logical_and_short_circuits() {
+        let source = "function guarded(n: i32, d: i32): bool {
+    return d != 0 && n / d > 1
+}
+";
+
+        let expected = "function guarded(arg0: i32, arg1: i32) -> bool {
+    let _0: i32; // n
+    let _1: i32; // d
+
+  bb0 (entry):
+    store _0, arg0
+    store _1, arg1
+    %0: i32 = load _1
+    %1: bool = ne %0, 0
+    branch %1, bb1, bb2
+
+  bb1 (and.rhs):
+    %2: i32 = load _0
+    %3: i32 = load _1
+    %4: i32 = div %2, %3
+    %5: bool = gt %4, 1
+    jump bb2
+
+  bb2 (and.merge):
+    %6: bool = phi [false, bb0], [%5, bb1]
+    return %6
+}
+";
+
+        assert_eq!(lower_source(source), expected);
+    }
+}
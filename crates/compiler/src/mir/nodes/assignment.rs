@@ -0,0 +1,13 @@
+use crate::ast::AssignmentNode;
+use crate::mir::{Lower, MirBuilder, Operand};
+
+impl Lower for AssignmentNode {
+    fn lower(&self, mir_builder: &mut MirBuilder) -> Option<Operand> {
+        let expression = self.expression.lower(mir_builder).unwrap();
+        let local = *mir_builder.variables.get(&self.identifier_node.identifier_token.name).unwrap();
+
+        mir_builder.build_store(local, expression);
+
+        None
+    }
+}
\ No newline at end of file
@@ -0,0 +1,64 @@
+use crate::ast::{BinaryNode, BinaryType};
+use crate::error::location::Location;
+use crate::mir::{BinaryOperation, Constant, Lower, MirBuilder, MirType, Operand, Terminator};
+
+impl BinaryNode {
+    /// Lowers `&&` and `||` so that the right operand only runs when the left one does not decide the result.
+    fn lower_short_circuit(&self, mir_builder: &mut MirBuilder) -> Operand {
+        let is_and = self.operation_type == BinaryType::And;
+
+        let left = self.left.lower(mir_builder).unwrap();
+        let left_block = mir_builder.current_block();
+
+        let right_block = mir_builder.new_block(if is_and { "and.rhs" } else { "or.rhs" });
+        let merge_block = mir_builder.new_block(if is_and { "and.merge" } else { "or.merge" });
+
+        let (then_block, else_block) = if is_and { (right_block, merge_block) } else { (merge_block, right_block) };
+        mir_builder.terminate(Terminator::Branch { condition: left, then_block, else_block });
+
+        mir_builder.switch_to(right_block);
+        let right = self.right.lower(mir_builder).unwrap();
+        let right_end_block = mir_builder.current_block();
+        mir_builder.terminate(Terminator::Jump(merge_block));
+
+        mir_builder.switch_to(merge_block);
+
+        let incoming = vec![(Operand::Constant(Constant::Bool(!is_and)), left_block), (right, right_end_block)];
+
+        mir_builder.build_phi(incoming, MirType::Bool)
+    }
+}
+
+impl Lower for BinaryNode {
+    fn lower(&self, mir_builder: &mut MirBuilder) -> Option<Operand> {
+        if matches!(self.operation_type, BinaryType::And | BinaryType::Or) {
+            return Some(self.lower_short_circuit(mir_builder));
+        }
+
+        let left = self.left.lower(mir_builder).unwrap();
+        let right = self.right.lower(mir_builder).unwrap();
+
+        let operation = match self.operation_type {
+            BinaryType::Addition => BinaryOperation::Add,
+            BinaryType::Subtraction => BinaryOperation::Subtract,
+            BinaryType::Multiplication => BinaryOperation::Multiply,
+            BinaryType::Division => BinaryOperation::Divide,
+            BinaryType::Modulo => BinaryOperation::Remainder,
+            BinaryType::Exponentiation => BinaryOperation::Power,
+            BinaryType::Equal => BinaryOperation::Equal,
+            BinaryType::NotEqual => BinaryOperation::NotEqual,
+            BinaryType::GreaterThan => BinaryOperation::GreaterThan,
+            BinaryType::GreaterThanOrEqual => BinaryOperation::GreaterThanOrEqual,
+            BinaryType::LessThan => BinaryOperation::LessThan,
+            BinaryType::LessThanOrEqual => BinaryOperation::LessThanOrEqual,
+            BinaryType::ShiftLeft => BinaryOperation::ShiftLeft,
+            BinaryType::ShiftRight => BinaryOperation::ShiftRight,
+            BinaryType::BitwiseOr => BinaryOperation::BitwiseOr,
+            BinaryType::BitwiseAnd => BinaryOperation::BitwiseAnd,
+            BinaryType::And | BinaryType::Or => unreachable!(),
+            BinaryType::BitwiseXor => BinaryOperation::BitwiseXor,
+        };
+
+        Some(mir_builder.build_checked_binary(operation, left, right, &self.location()))
+    }
+}
\ No newline at end of file
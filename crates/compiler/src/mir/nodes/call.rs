@@ -0,0 +1,45 @@
+use crate::ast::CallNode;
+use crate::error::location::Location;
+use crate::mir::{BinaryOperation, Lower, MirBuilder, MirType, Operand};
+
+impl CallNode {
+    pub(crate) fn lower_overflowing(&self, mir_builder: &mut MirBuilder) -> Option<(Operand, Operand)> {
+        let operation = match self.identifier_node.identifier_token.name.as_str() {
+            "checked_add" => BinaryOperation::Add,
+            "checked_sub" => BinaryOperation::Subtract,
+            "checked_mul" => BinaryOperation::Multiply,
+            _ => return None,
+        };
+
+        let left = self.parameters[0].lower(mir_builder).unwrap();
+        let right = self.parameters[1].lower(mir_builder).unwrap();
+
+        Some(mir_builder.build_overflowing_binary(operation, left, right))
+    }
+}
+
+impl Lower for CallNode {
+    fn lower(&self, mir_builder: &mut MirBuilder) -> Option<Operand> {
+        if self.lower_overflowing(mir_builder).is_some() {
+            return None;
+        }
+
+        let mut arguments: Vec<Operand> = self.parameters.iter().map(|parameter| parameter.lower(mir_builder).unwrap()).collect();
+
+        match self.identifier_node.identifier_token.name.as_str() {
+            "assert" => {
+                mir_builder.build_assertion(arguments.remove(0), &self.location());
+
+                None
+            }
+            "assert_eq" => {
+                let right = arguments.pop().unwrap();
+                let left = arguments.pop().unwrap();
+                mir_builder.build_equality_assertion(left, right, &self.location());
+
+                None
+            }
+            name => mir_builder.build_call(name, arguments, MirType::from_data_type(&self.data_type)),
+        }
+    }
+}
@@ -0,0 +1,8 @@
+use crate::ast::CharNode;
+use crate::mir::{Constant, Lower, MirBuilder, Operand};
+
+impl Lower for CharNode {
+    fn lower(&self, _mir_builder: &mut MirBuilder) -> Option<Operand> {
+        Some(Operand::Constant(Constant::Char(self.value)))
+    }
+}
\ No newline at end of file
@@ -0,0 +1,21 @@
+use crate::ast::DeclarationNode;
+use crate::mir::{Lower, MirBuilder, MirType, Operand};
+
+impl Lower for DeclarationNode {
+    fn lower(&self, mir_builder: &mut MirBuilder) -> Option<Operand> {
+        let expression = self.expression.lower(mir_builder);
+
+        if self.identifier_node.identifier_token.is_discard() {
+            return None;
+        }
+
+        let expression = expression.unwrap();
+
+        let mir_type = MirType::from_data_type(self.expression.data_type());
+        let local = mir_builder.add_local(&self.identifier_node.identifier_token.name, mir_type);
+
+        mir_builder.build_store(local, expression);
+
+        None
+    }
+}
\ No newline at end of file
@@ -0,0 +1,23 @@
+use crate::ast::{DestructuringNode, Node};
+use crate::mir::{Lower, MirBuilder, MirType, Operand};
+
+impl Lower for DestructuringNode {
+    fn lower(&self, mir_builder: &mut MirBuilder) -> Option<Operand> {
+        let Node::Call(call_node) = &*self.expression else {
+            unreachable!()
+        };
+
+        let (overflowed, result) = call_node.lower_overflowing(mir_builder).unwrap();
+
+        for (identifier_node, element) in self.identifier_nodes.iter().zip([overflowed, result]) {
+            if identifier_node.identifier_token.is_discard() {
+                continue;
+            }
+
+            let local = mir_builder.add_local(&identifier_node.identifier_token.name, MirType::from_data_type(&identifier_node.data_type));
+            mir_builder.build_store(local, element);
+        }
+
+        None
+    }
+}
@@ -0,0 +1,12 @@
+use crate::ast::FileNode;
+use crate::mir::{Lower, MirBuilder, Operand};
+
+impl Lower for FileNode {
+    fn lower(&self, mir_builder: &mut MirBuilder) -> Option<Operand> {
+        for function in &self.functions {
+            function.lower(mir_builder);
+        }
+
+        None
+    }
+}
\ No newline at end of file
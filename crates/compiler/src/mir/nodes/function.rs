@@ -0,0 +1,28 @@
+use crate::ast::FunctionNode;
+use crate::datatype::DataType;
+use crate::mir::{Lower, MirBuilder, MirType, Operand};
+
+impl Lower for FunctionNode {
+    fn lower(&self, mir_builder: &mut MirBuilder) -> Option<Operand> {
+        let (parameter_types, return_type) = match &self.data_type {
+            DataType::Function(parameter_types, return_type) => (parameter_types, return_type),
+            _ => unreachable!()
+        };
+
+        let parameter_types = parameter_types.iter().map(MirType::from_data_type).collect();
+
+        mir_builder.begin_function(&self.identifier_node.identifier_token.name, parameter_types, MirType::from_data_type(return_type));
+        mir_builder.variables.push();
+
+        for parameter in &self.parameters {
+            parameter.lower(mir_builder);
+        }
+
+        self.scope.lower(mir_builder);
+
+        mir_builder.variables.pop();
+        mir_builder.end_function();
+
+        None
+    }
+}
\ No newline at end of file
@@ -0,0 +1,10 @@
+use crate::ast::IdentifierNode;
+use crate::mir::{Lower, MirBuilder, Operand};
+
+impl Lower for IdentifierNode {
+    fn lower(&self, mir_builder: &mut MirBuilder) -> Option<Operand> {
+        let local = *mir_builder.variables.get(&self.identifier_token.name).unwrap();
+
+        Some(mir_builder.build_load(local))
+    }
+}
\ No newline at end of file
@@ -0,0 +1,41 @@
+use crate::ast::{IfElseNode, Node, ScopeNode};
+use crate::mir::{BlockId, Lower, MirBuilder, Operand, Terminator};
+
+impl Lower for IfElseNode {
+    fn lower(&self, mir_builder: &mut MirBuilder) -> Option<Operand> {
+        let merge_block = mir_builder.new_block("if.merge");
+
+        IfElseNode::lower_single_if(mir_builder, &self.condition, &self.consequent, merge_block);
+
+        for (condition, consequent) in &self.conditional_alternatives {
+            IfElseNode::lower_single_if(mir_builder, condition, consequent, merge_block);
+        }
+
+        if let Some(alternative) = &self.alternative {
+            alternative.lower(mir_builder);
+        }
+
+        mir_builder.terminate(Terminator::Jump(merge_block));
+
+        mir_builder.switch_to(merge_block);
+
+        None
+    }
+}
+
+impl IfElseNode {
+    fn lower_single_if(mir_builder: &mut MirBuilder, condition: &Node, consequent: &ScopeNode, merge_block: BlockId) {
+        let condition = condition.lower(mir_builder).unwrap();
+
+        let then_block = mir_builder.new_block("if.then");
+        let else_block = mir_builder.new_block("if.else");
+
+        mir_builder.terminate(Terminator::Branch { condition, then_block, else_block });
+
+        mir_builder.switch_to(then_block);
+        consequent.lower(mir_builder);
+        mir_builder.terminate(Terminator::Jump(merge_block));
+
+        mir_builder.switch_to(else_block);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,17 @@
+mod number;
+mod char;
+mod string;
+mod binary;
+mod identifier;
+mod assignment;
+mod scope;
+mod function;
+mod file;
+mod r#return;
+mod declaration;
+mod destructuring;
+mod call;
+mod parameter;
+mod ternary;
+mod if_else;
+mod unary;
\ No newline at end of file
@@ -0,0 +1,10 @@
+use crate::ast::NumberNode;
+use crate::mir::{Constant, Lower, MirBuilder, Operand};
+
+impl Lower for NumberNode {
+    fn lower(&self, _mir_builder: &mut MirBuilder) -> Option<Operand> {
+        let number = self.number_token.parse_value().unwrap();
+
+        Some(Operand::Constant(Constant::I32(number as i32)))
+    }
+}
\ No newline at end of file
@@ -0,0 +1,10 @@
+use crate::ast::ParameterNode;
+use crate::mir::{Lower, MirBuilder, Operand};
+
+impl Lower for ParameterNode {
+    fn lower(&self, mir_builder: &mut MirBuilder) -> Option<Operand> {
+        mir_builder.add_parameter(&self.identifier_node.identifier_token.name);
+
+        None
+    }
+}
\ No newline at end of file
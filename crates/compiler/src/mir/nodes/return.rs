@@ -0,0 +1,12 @@
+use crate::ast::ReturnNode;
+use crate::mir::{Lower, MirBuilder, Operand, Terminator};
+
+impl Lower for ReturnNode {
+    fn lower(&self, mir_builder: &mut MirBuilder) -> Option<Operand> {
+        let expression = self.expression.lower(mir_builder);
+
+        mir_builder.terminate(Terminator::Return(expression));
+
+        None
+    }
+}
\ No newline at end of file
@@ -0,0 +1,16 @@
+use crate::ast::ScopeNode;
+use crate::mir::{Lower, MirBuilder, Operand};
+
+impl Lower for ScopeNode {
+    fn lower(&self, mir_builder: &mut MirBuilder) -> Option<Operand> {
+        mir_builder.variables.push();
+
+        for statement in &self.statements {
+            statement.lower(mir_builder);
+        }
+
+        mir_builder.variables.pop();
+
+        None
+    }
+}
\ No newline at end of file
@@ -0,0 +1,8 @@
+use crate::ast::StringNode;
+use crate::mir::{Constant, Lower, MirBuilder, Operand};
+
+impl Lower for StringNode {
+    fn lower(&self, _mir_builder: &mut MirBuilder) -> Option<Operand> {
+        Some(Operand::Constant(Constant::Str(self.value.clone())))
+    }
+}
\ No newline at end of file
@@ -0,0 +1,35 @@
+use crate::ast::TernaryNode;
+use crate::datatype::DataType;
+use crate::mir::{Lower, MirBuilder, MirType, Operand, Terminator};
+
+impl Lower for TernaryNode {
+    fn lower(&self, mir_builder: &mut MirBuilder) -> Option<Operand> {
+        let condition = self.condition.lower(mir_builder).unwrap();
+
+        let then_block = mir_builder.new_block("ternary.then");
+        let else_block = mir_builder.new_block("ternary.else");
+        let merge_block = mir_builder.new_block("ternary.merge");
+
+        mir_builder.terminate(Terminator::Branch { condition, then_block, else_block });
+
+        mir_builder.switch_to(then_block);
+        let consequent = self.consequent.lower(mir_builder);
+        let consequent_block = mir_builder.current_block();
+        mir_builder.terminate(Terminator::Jump(merge_block));
+
+        mir_builder.switch_to(else_block);
+        let alternative = self.alternative.lower(mir_builder);
+        let alternative_block = mir_builder.current_block();
+        mir_builder.terminate(Terminator::Jump(merge_block));
+
+        mir_builder.switch_to(merge_block);
+
+        if self.data_type == DataType::None {
+            return None;
+        }
+
+        let incoming = vec![(consequent.unwrap(), consequent_block), (alternative.unwrap(), alternative_block)];
+
+        Some(mir_builder.build_phi(incoming, MirType::from_data_type(&self.data_type)))
+    }
+}
\ No newline at end of file
@@ -0,0 +1,53 @@
+use crate::ast::{Node, UnaryNode, UnaryType};
+use crate::error::location::Location;
+use crate::mir::{BinaryOperation, Constant, Lower, MirBuilder, Operand, UnaryOperation};
+
+impl Lower for UnaryNode {
+    fn lower(&self, mir_builder: &mut MirBuilder) -> Option<Operand> {
+        match self.operation_type {
+            UnaryType::PreIncrement => Some(self.lower_step(mir_builder, BinaryOperation::Add, false)),
+            UnaryType::PreDecrement => Some(self.lower_step(mir_builder, BinaryOperation::Subtract, false)),
+            UnaryType::PostIncrement => Some(self.lower_step(mir_builder, BinaryOperation::Add, true)),
+            UnaryType::PostDecrement => Some(self.lower_step(mir_builder, BinaryOperation::Subtract, true)),
+            UnaryType::Minus => {
+                let expression = self.expression.lower(mir_builder).unwrap();
+                Some(mir_builder.build_unary(UnaryOperation::Negate, expression))
+            }
+            UnaryType::Absolute => {
+                let expression = self.expression.lower(mir_builder).unwrap();
+                Some(mir_builder.build_unary(UnaryOperation::Absolute, expression))
+            }
+            UnaryType::Not => {
+                let expression = self.expression.lower(mir_builder).unwrap();
+                Some(mir_builder.build_unary(UnaryOperation::Not, expression))
+            }
+            UnaryType::BitwiseNot => {
+                let expression = self.expression.lower(mir_builder).unwrap();
+                Some(mir_builder.build_unary(UnaryOperation::BitwiseNot, expression))
+            }
+        }
+    }
+}
+
+impl UnaryNode {
+    fn lower_step(&self, mir_builder: &mut MirBuilder, operation: BinaryOperation, is_postfix: bool) -> Operand {
+        let expression = self.expression.lower(mir_builder).unwrap();
+
+        let local = match &*self.expression {
+            Node::Identifier(identifier_node) => Some(*mir_builder.variables.get(&identifier_node.identifier_token.name).unwrap()),
+            _ => None,
+        };
+
+        if is_postfix && local.is_none() {
+            return expression;
+        }
+
+        let stepped = mir_builder.build_checked_binary(operation, expression.clone(), Operand::Constant(Constant::I32(1)), &self.location());
+
+        if let Some(local) = local {
+            mir_builder.build_store(local, stepped.clone());
+        }
+
+        if is_postfix { expression } else { stepped }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,120 @@
+use crate::ast::{BinaryType, DeclarationNode, IdentifierNode, Node, ScopeNode};
+use crate::optimizer::{contains_step, for_each_scope_in, is_pure, same_expression};
+use crate::token::IdentifierToken;
+
+pub fn eliminate_common_subexpressions(node: &mut Node, runtime_checks: bool) {
+    let file_node = match node {
+        Node::File(file_node) => file_node,
+        _ => return,
+    };
+
+    for function in &mut file_node.functions {
+        let mut temporary_count = 0;
+
+        for_each_scope_in(&mut function.scope, &mut |scope_node| eliminate_in_scope(scope_node, &mut temporary_count, runtime_checks));
+    }
+}
+
+fn eliminate_in_scope(scope_node: &mut ScopeNode, temporary_count: &mut usize, runtime_checks: bool) {
+    let statements = std::mem::take(&mut scope_node.statements);
+
+    for mut statement in statements {
+        if !contains_step(&statement) {
+            while let Some(expression) = find_common_subexpression(&statement, runtime_checks) {
+                let location = statement.location();
+
+                let mut identifier_node = IdentifierNode::new(IdentifierToken::new(format!("cse.{}", temporary_count), location.clone()));
+                identifier_node.data_type = expression.data_type().clone();
+                *temporary_count += 1;
+
+                replace_expression(&mut statement, &expression, &Node::Identifier(identifier_node.clone()));
+
+                let declaration_node = DeclarationNode::new(location, Box::from(identifier_node), Box::from(expression));
+                scope_node.statements.push(Box::from(Node::Declaration(declaration_node)));
+            }
+        }
+
+        scope_node.statements.push(statement);
+    }
+}
+
+fn find_common_subexpression(statement: &Node, runtime_checks: bool) -> Option<Node> {
+    let mut candidates = vec![];
+
+    let root = match statement {
+        Node::Declaration(declaration_node) => &declaration_node.expression,
+        Node::Destructuring(destructuring_node) => &destructuring_node.expression,
+        Node::Assignment(assignment_node) => &assignment_node.expression,
+        Node::Return(return_node) => &return_node.expression,
+        Node::IfElse(if_else_node) => &if_else_node.condition,
+        Node::Scope(_) => return None,
+        statement => statement,
+    };
+
+    collect_candidates(root, &mut candidates, runtime_checks);
+
+    candidates.iter()
+        .find(|candidate| candidates.iter().filter(|other| same_expression(candidate, other)).count() > 1)
+        .map(|candidate| (*candidate).clone())
+}
+
+fn collect_candidates<'node>(node: &'node Node, candidates: &mut Vec<&'node Node>, runtime_checks: bool) {
+    match node {
+        Node::Binary(binary_node) => {
+            if is_pure(node, runtime_checks) {
+                candidates.push(node);
+            }
+
+            collect_candidates(&binary_node.left, candidates, runtime_checks);
+
+            if !matches!(binary_node.operation_type, BinaryType::And | BinaryType::Or) {
+                collect_candidates(&binary_node.right, candidates, runtime_checks);
+            }
+        }
+        Node::Unary(unary_node) => {
+            if is_pure(node, runtime_checks) {
+                candidates.push(node);
+            }
+
+            collect_candidates(&unary_node.expression, candidates, runtime_checks);
+        }
+        Node::Ternary(ternary_node) => collect_candidates(&ternary_node.condition, candidates, runtime_checks),
+        Node::Call(call_node) => {
+            for parameter in &call_node.parameters {
+                collect_candidates(parameter, candidates, runtime_checks);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn replace_expression(node: &mut Node, target: &Node, replacement: &Node) {
+    if same_expression(node, target) {
+        *node = replacement.clone();
+        return;
+    }
+
+    match node {
+        Node::Declaration(declaration_node) => replace_expression(&mut declaration_node.expression, target, replacement),
+        Node::Destructuring(destructuring_node) => replace_expression(&mut destructuring_node.expression, target, replacement),
+        Node::Assignment(assignment_node) => replace_expression(&mut assignment_node.expression, target, replacement),
+        Node::Return(return_node) => replace_expression(&mut return_node.expression, target, replacement),
+        Node::IfElse(if_else_node) => replace_expression(&mut if_else_node.condition, target, replacement),
+        Node::Ternary(ternary_node) => {
+            replace_expression(&mut ternary_node.condition, target, replacement);
+            replace_expression(&mut ternary_node.consequent, target, replacement);
+            replace_expression(&mut ternary_node.alternative, target, replacement);
+        }
+        Node::Binary(binary_node) => {
+            replace_expression(&mut binary_node.left, target, replacement);
+            replace_expression(&mut binary_node.right, target, replacement);
+        }
+        Node::Unary(unary_node) => replace_expression(&mut unary_node.expression, target, replacement),
+        Node::Call(call_node) => {
+            for parameter in &mut call_node.parameters {
+                replace_expression(parameter, target, replacement);
+            }
+        }
+        _ => {}
+    }
+}
\ No newline at end of file
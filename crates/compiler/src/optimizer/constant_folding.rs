@@ -0,0 +1,153 @@
+use crate::ast::{BinaryType, Node, NumberNode, UnaryType};
+use crate::mir::Constant;
+use crate::token::NumberToken;
+
+pub fn fold_constants(node: &mut Node) {
+    if let Some(folded) = fold(node) {
+        *node = folded;
+        return;
+    }
+
+    match node {
+        Node::File(file_node) => {
+            for function in &mut file_node.functions {
+                for statement in &mut function.scope.statements {
+                    fold_constants(statement);
+                }
+            }
+        }
+        Node::Scope(scope_node) => {
+            for statement in &mut scope_node.statements {
+                fold_constants(statement);
+            }
+        }
+        Node::IfElse(if_else_node) => {
+            fold_constants(&mut if_else_node.condition);
+            if_else_node.consequent.statements.iter_mut().for_each(|statement| fold_constants(statement));
+
+            for (condition, consequent) in &mut if_else_node.conditional_alternatives {
+                fold_constants(condition);
+                consequent.statements.iter_mut().for_each(|statement| fold_constants(statement));
+            }
+
+            if let Some(alternative) = &mut if_else_node.alternative {
+                alternative.statements.iter_mut().for_each(|statement| fold_constants(statement));
+            }
+        }
+        Node::Return(return_node) => fold_constants(&mut return_node.expression),
+        Node::Declaration(declaration_node) => fold_constants(&mut declaration_node.expression),
+        Node::Destructuring(destructuring_node) => fold_constants(&mut destructuring_node.expression),
+        Node::Assignment(assignment_node) => fold_constants(&mut assignment_node.expression),
+        Node::Ternary(ternary_node) => {
+            fold_constants(&mut ternary_node.condition);
+            fold_constants(&mut ternary_node.consequent);
+            fold_constants(&mut ternary_node.alternative);
+        }
+        Node::Binary(binary_node) => {
+            fold_constants(&mut binary_node.left);
+            fold_constants(&mut binary_node.right);
+        }
+        Node::Unary(unary_node) => fold_constants(&mut unary_node.expression),
+        Node::Call(call_node) => {
+            for parameter in &mut call_node.parameters {
+                fold_constants(parameter);
+            }
+        }
+        Node::Function(_) | Node::Parameter(_) | Node::Number(_) | Node::Char(_) | Node::String(_) | Node::Identifier(_) | Node::Error(_) => {}
+    }
+}
+
+fn fold(node: &Node) -> Option<Node> {
+    match node {
+        Node::Binary(_) | Node::Unary(_) => match evaluate(node)? {
+            Constant::I32(value) => Some(number_node(node, value)),
+            Constant::Bool(_) | Constant::Char(_) | Constant::Str(_) => None,
+        },
+        Node::Ternary(ternary_node) => {
+            if let Some(Constant::I32(value)) = evaluate(node) {
+                return Some(number_node(node, value));
+            }
+
+            match evaluate(&ternary_node.condition)? {
+                Constant::Bool(true) => Some((*ternary_node.consequent).clone()),
+                Constant::Bool(false) => Some((*ternary_node.alternative).clone()),
+                Constant::I32(_) | Constant::Char(_) | Constant::Str(_) => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+fn number_node(node: &Node, value: i32) -> Node {
+    let number_token = NumberToken::new(value.to_string(), node.location());
+
+    Node::Number(NumberNode::new(node.location(), number_token))
+}
+
+pub fn evaluate(node: &Node) -> Option<Constant> {
+    match node {
+        Node::Number(number_node) => Some(Constant::I32(i32::try_from(number_node.number_token.parse_value().ok()?).ok()?)),
+        Node::Char(char_node) => Some(Constant::Char(char_node.value)),
+        Node::Binary(binary_node) => evaluate_binary(&binary_node.operation_type, evaluate(&binary_node.left)?, evaluate(&binary_node.right)?),
+        Node::Unary(unary_node) => evaluate_unary(&unary_node.operation_type, evaluate(&unary_node.expression)?),
+        Node::Ternary(ternary_node) => match evaluate(&ternary_node.condition)? {
+            Constant::Bool(true) => evaluate(&ternary_node.consequent),
+            Constant::Bool(false) => evaluate(&ternary_node.alternative),
+            Constant::I32(_) | Constant::Char(_) | Constant::Str(_) => None,
+        },
+        _ => None,
+    }
+}
+
+fn evaluate_binary(operation_type: &BinaryType, left: Constant, right: Constant) -> Option<Constant> {
+    match (left, right) {
+        (Constant::I32(left), Constant::I32(right)) => match operation_type {
+            BinaryType::Addition => left.checked_add(right).map(Constant::I32),
+            BinaryType::Subtraction => left.checked_sub(right).map(Constant::I32),
+            BinaryType::Multiplication => left.checked_mul(right).map(Constant::I32),
+            BinaryType::Division => left.checked_div(right).map(Constant::I32),
+            BinaryType::Modulo => left.checked_rem(right).map(Constant::I32),
+            BinaryType::Exponentiation => left.checked_pow(u32::try_from(right).ok()?).map(Constant::I32),
+            BinaryType::Equal => Some(Constant::Bool(left == right)),
+            BinaryType::NotEqual => Some(Constant::Bool(left != right)),
+            BinaryType::GreaterThan => Some(Constant::Bool(left > right)),
+            BinaryType::GreaterThanOrEqual => Some(Constant::Bool(left >= right)),
+            BinaryType::LessThan => Some(Constant::Bool(left < right)),
+            BinaryType::LessThanOrEqual => Some(Constant::Bool(left <= right)),
+            BinaryType::ShiftLeft => left.checked_shl(u32::try_from(right).ok()?).map(Constant::I32),
+            BinaryType::ShiftRight => left.checked_shr(u32::try_from(right).ok()?).map(Constant::I32),
+            BinaryType::BitwiseOr => Some(Constant::I32(left | right)),
+            BinaryType::BitwiseAnd => Some(Constant::I32(left & right)),
+            BinaryType::BitwiseXor => Some(Constant::I32(left ^ right)),
+            BinaryType::Or | BinaryType::And => None,
+        },
+        (Constant::Bool(left), Constant::Bool(right)) => match operation_type {
+            BinaryType::Equal => Some(Constant::Bool(left == right)),
+            BinaryType::NotEqual => Some(Constant::Bool(left != right)),
+            BinaryType::BitwiseOr | BinaryType::Or => Some(Constant::Bool(left || right)),
+            BinaryType::BitwiseAnd | BinaryType::And => Some(Constant::Bool(left && right)),
+            BinaryType::BitwiseXor => Some(Constant::Bool(left != right)),
+            _ => None,
+        },
+        (Constant::Char(left), Constant::Char(right)) => match operation_type {
+            BinaryType::Equal => Some(Constant::Bool(left == right)),
+            BinaryType::NotEqual => Some(Constant::Bool(left != right)),
+            BinaryType::GreaterThan => Some(Constant::Bool(left > right)),
+            BinaryType::GreaterThanOrEqual => Some(Constant::Bool(left >= right)),
+            BinaryType::LessThan => Some(Constant::Bool(left < right)),
+            BinaryType::LessThanOrEqual => Some(Constant::Bool(left <= right)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn evaluate_unary(operation_type: &UnaryType, operand: Constant) -> Option<Constant> {
+    match (operation_type, operand) {
+        (UnaryType::Minus, Constant::I32(value)) => Some(Constant::I32(value.wrapping_neg())),
+        (UnaryType::Absolute, Constant::I32(value)) => Some(Constant::I32(value.wrapping_abs())),
+        (UnaryType::Not, Constant::Bool(value)) => Some(Constant::Bool(!value)),
+        (UnaryType::BitwiseNot, Constant::I32(value)) => Some(Constant::I32(!value)),
+        _ => None,
+    }
+}
\ No newline at end of file
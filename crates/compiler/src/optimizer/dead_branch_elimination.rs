@@ -0,0 +1,50 @@
+use crate::ast::{IfElseNode, Node, ScopeNode};
+use crate::mir::Constant;
+use crate::optimizer::constant_folding::evaluate;
+
+pub fn eliminate_dead_branches(scope_node: &mut ScopeNode) {
+    let statements = std::mem::take(&mut scope_node.statements);
+
+    for statement in statements {
+        match *statement {
+            Node::IfElse(if_else_node) => {
+                if let Some(statement) = eliminate_if_else(if_else_node) {
+                    scope_node.statements.push(Box::from(statement));
+                }
+            }
+            statement => scope_node.statements.push(Box::from(statement)),
+        }
+    }
+}
+
+fn eliminate_if_else(mut if_else_node: IfElseNode) -> Option<Node> {
+    let mut branches = vec![(if_else_node.condition, if_else_node.consequent)];
+    branches.append(&mut if_else_node.conditional_alternatives);
+
+    let mut live_branches = vec![];
+    let mut alternative = if_else_node.alternative.take();
+
+    for (condition, consequent) in branches {
+        match evaluate(&condition) {
+            Some(Constant::Bool(false)) => continue,
+            Some(Constant::Bool(true)) => {
+                alternative = Some(consequent);
+                break;
+            }
+            _ => live_branches.push((condition, consequent)),
+        }
+    }
+
+    if live_branches.is_empty() {
+        return alternative.map(|alternative| Node::Scope(*alternative));
+    }
+
+    let (condition, consequent) = live_branches.remove(0);
+
+    if_else_node.condition = condition;
+    if_else_node.consequent = consequent;
+    if_else_node.conditional_alternatives = live_branches;
+    if_else_node.alternative = alternative;
+
+    Some(Node::IfElse(if_else_node))
+}
\ No newline at end of file
@@ -0,0 +1,153 @@
+use std::collections::HashMap;
+use crate::ast::{FunctionNode, Node};
+use crate::optimizer::{contains_step, is_pure};
+
+const INLINE_THRESHOLD: usize = 16;
+
+struct InlineCandidate {
+    parameter_names: Vec<String>,
+    expression: Node,
+}
+
+pub fn inline_functions(node: &mut Node, runtime_checks: bool) {
+    let file_node = match node {
+        Node::File(file_node) => file_node,
+        _ => return,
+    };
+
+    let candidates: HashMap<String, InlineCandidate> = file_node.functions.iter()
+        .filter_map(|function| inline_candidate(function).map(|candidate| (function.identifier_node.identifier_token.name.clone(), candidate)))
+        .collect();
+
+    if candidates.is_empty() {
+        return;
+    }
+
+    for function in &mut file_node.functions {
+        for statement in &mut function.scope.statements {
+            inline_calls(statement, &candidates, runtime_checks);
+        }
+    }
+}
+
+fn inline_candidate(function: &FunctionNode) -> Option<InlineCandidate> {
+    let expression = match function.scope.statements.as_slice() {
+        [statement] => match &**statement {
+            Node::Return(return_node) => &return_node.expression,
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    let name = &function.identifier_node.identifier_token.name;
+
+    if node_count(expression) > INLINE_THRESHOLD || contains_step(expression) || calls_function(expression, name) {
+        return None;
+    }
+
+    Some(InlineCandidate {
+        parameter_names: function.parameters.iter().map(|parameter| parameter.identifier_node.identifier_token.name.clone()).collect(),
+        expression: (**expression).clone(),
+    })
+}
+
+fn inline_calls(node: &mut Node, candidates: &HashMap<String, InlineCandidate>, runtime_checks: bool) {
+    match node {
+        Node::Scope(scope_node) => scope_node.statements.iter_mut().for_each(|statement| inline_calls(statement, candidates, runtime_checks)),
+        Node::IfElse(if_else_node) => {
+            inline_calls(&mut if_else_node.condition, candidates, runtime_checks);
+            if_else_node.consequent.statements.iter_mut().for_each(|statement| inline_calls(statement, candidates, runtime_checks));
+
+            for (condition, consequent) in &mut if_else_node.conditional_alternatives {
+                inline_calls(condition, candidates, runtime_checks);
+                consequent.statements.iter_mut().for_each(|statement| inline_calls(statement, candidates, runtime_checks));
+            }
+
+            if let Some(alternative) = &mut if_else_node.alternative {
+                alternative.statements.iter_mut().for_each(|statement| inline_calls(statement, candidates, runtime_checks));
+            }
+        }
+        Node::Return(return_node) => inline_calls(&mut return_node.expression, candidates, runtime_checks),
+        Node::Declaration(declaration_node) => inline_calls(&mut declaration_node.expression, candidates, runtime_checks),
+        Node::Destructuring(destructuring_node) => inline_calls(&mut destructuring_node.expression, candidates, runtime_checks),
+        Node::Assignment(assignment_node) => inline_calls(&mut assignment_node.expression, candidates, runtime_checks),
+        Node::Ternary(ternary_node) => {
+            inline_calls(&mut ternary_node.condition, candidates, runtime_checks);
+            inline_calls(&mut ternary_node.consequent, candidates, runtime_checks);
+            inline_calls(&mut ternary_node.alternative, candidates, runtime_checks);
+        }
+        Node::Binary(binary_node) => {
+            inline_calls(&mut binary_node.left, candidates, runtime_checks);
+            inline_calls(&mut binary_node.right, candidates, runtime_checks);
+        }
+        Node::Unary(unary_node) => inline_calls(&mut unary_node.expression, candidates, runtime_checks),
+        Node::Call(call_node) => {
+            for parameter in &mut call_node.parameters {
+                inline_calls(parameter, candidates, runtime_checks);
+            }
+
+            let candidate = match candidates.get(&call_node.identifier_node.identifier_token.name) {
+                Some(candidate) => candidate,
+                None => return,
+            };
+
+            if !call_node.parameters.iter().all(|parameter| is_pure(parameter, runtime_checks)) {
+                return;
+            }
+
+            let arguments: HashMap<&String, &Node> = candidate.parameter_names.iter().zip(call_node.parameters.iter().map(|parameter| &**parameter)).collect();
+
+            let mut expression = candidate.expression.clone();
+            substitute(&mut expression, &arguments);
+
+            *node = expression;
+        }
+        Node::File(_) | Node::Function(_) | Node::Parameter(_) | Node::Number(_) | Node::Char(_) | Node::String(_) | Node::Identifier(_) | Node::Error(_) => {}
+    }
+}
+
+fn substitute(node: &mut Node, arguments: &HashMap<&String, &Node>) {
+    match node {
+        Node::Identifier(identifier_node) => {
+            if let Some(argument) = arguments.get(&identifier_node.identifier_token.name) {
+                *node = (*argument).clone();
+            }
+        }
+        Node::Ternary(ternary_node) => {
+            substitute(&mut ternary_node.condition, arguments);
+            substitute(&mut ternary_node.consequent, arguments);
+            substitute(&mut ternary_node.alternative, arguments);
+        }
+        Node::Binary(binary_node) => {
+            substitute(&mut binary_node.left, arguments);
+            substitute(&mut binary_node.right, arguments);
+        }
+        Node::Unary(unary_node) => substitute(&mut unary_node.expression, arguments),
+        Node::Call(call_node) => {
+            for parameter in &mut call_node.parameters {
+                substitute(parameter, arguments);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn node_count(node: &Node) -> usize {
+    match node {
+        Node::Ternary(ternary_node) => 1 + node_count(&ternary_node.condition) + node_count(&ternary_node.consequent) + node_count(&ternary_node.alternative),
+        Node::Binary(binary_node) => 1 + node_count(&binary_node.left) + node_count(&binary_node.right),
+        Node::Unary(unary_node) => 1 + node_count(&unary_node.expression),
+        Node::Call(call_node) => 1 + call_node.parameters.iter().map(|parameter| node_count(parameter)).sum::<usize>(),
+        _ => 1,
+    }
+}
+
+fn calls_function(node: &Node, name: &str) -> bool {
+    match node {
+        Node::Ternary(ternary_node) => calls_function(&ternary_node.condition, name) || calls_function(&ternary_node.consequent, name) || calls_function(&ternary_node.alternative, name),
+        Node::Binary(binary_node) => calls_function(&binary_node.left, name) || calls_function(&binary_node.right, name),
+        Node::Unary(unary_node) => calls_function(&unary_node.expression, name),
+        Node::Call(call_node) => call_node.identifier_node.identifier_token.name == name || call_node.parameters.iter().any(|parameter| calls_function(parameter, name)),
+        _ => false,
+    }
+}
\ No newline at end of file
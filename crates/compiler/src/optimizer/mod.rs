@@ -0,0 +1,325 @@
+mod constant_folding;
+mod dead_branch_elimination;
+mod inlining;
+mod common_subexpression_elimination;
+
+use crate::ast::{BinaryType, Node, ScopeNode, UnaryType};
+
+pub(crate) use constant_folding::evaluate;
+
+#[derive(Debug, Clone)]
+pub struct OptimizationOptions {
+    pub constant_folding: bool,
+    pub dead_branch_elimination: bool,
+    pub inlining: bool,
+    pub common_subexpression_elimination: bool,
+    /// Whether the lowered code panics on overflow and division by zero, in which case those operations must not be
+    /// dropped or moved.
+    pub runtime_checks: bool,
+}
+
+impl Default for OptimizationOptions {
+    fn default() -> Self {
+        OptimizationOptions {
+            constant_folding: true,
+            dead_branch_elimination: true,
+            inlining: true,
+            common_subexpression_elimination: true,
+            runtime_checks: true,
+        }
+    }
+}
+
+impl OptimizationOptions {
+    pub fn none() -> OptimizationOptions {
+        OptimizationOptions {
+            constant_folding: false,
+            dead_branch_elimination: false,
+            inlining: false,
+            common_subexpression_elimination: false,
+            runtime_checks: true,
+        }
+    }
+}
+
+pub fn optimize(ast: &mut Node, options: &OptimizationOptions) {
+    if options.inlining {
+        inlining::inline_functions(ast, options.runtime_checks);
+    }
+
+    if options.constant_folding {
+        constant_folding::fold_constants(ast);
+    }
+
+    if options.dead_branch_elimination {
+        for_each_scope(ast, &mut dead_branch_elimination::eliminate_dead_branches);
+    }
+
+    if options.common_subexpression_elimination {
+        common_subexpression_elimination::eliminate_common_subexpressions(ast, options.runtime_checks);
+    }
+}
+
+fn for_each_scope(node: &mut Node, visitor: &mut dyn FnMut(&mut ScopeNode)) {
+    match node {
+        Node::File(file_node) => {
+            for function in &mut file_node.functions {
+                for_each_scope_in(&mut function.scope, visitor);
+            }
+        }
+        Node::Function(function_node) => for_each_scope_in(&mut function_node.scope, visitor),
+        Node::Scope(scope_node) => for_each_scope_in(scope_node, visitor),
+        Node::IfElse(if_else_node) => {
+            for_each_scope_in(&mut if_else_node.consequent, visitor);
+
+            for (_, consequent) in &mut if_else_node.conditional_alternatives {
+                for_each_scope_in(consequent, visitor);
+            }
+
+            if let Some(alternative) = &mut if_else_node.alternative {
+                for_each_scope_in(alternative, visitor);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn for_each_scope_in(scope_node: &mut ScopeNode, visitor: &mut dyn FnMut(&mut ScopeNode)) {
+    for statement in &mut scope_node.statements {
+        for_each_scope(statement, visitor);
+    }
+
+    visitor(scope_node);
+}
+
+fn is_pure(node: &Node, runtime_checks: bool) -> bool {
+    match node {
+        Node::Number(_) | Node::Char(_) | Node::String(_) | Node::Identifier(_) => true,
+        Node::Binary(binary_node) => {
+            !(runtime_checks && can_trap(&binary_node.operation_type)) && is_pure(&binary_node.left, runtime_checks) && is_pure(&binary_node.right, runtime_checks)
+        }
+        Node::Unary(unary_node) => !is_step(&unary_node.operation_type) && is_pure(&unary_node.expression, runtime_checks),
+        Node::Ternary(ternary_node) => {
+            is_pure(&ternary_node.condition, runtime_checks) && is_pure(&ternary_node.consequent, runtime_checks) && is_pure(&ternary_node.alternative, runtime_checks)
+        }
+        _ => false,
+    }
+}
+
+fn can_trap(operation_type: &BinaryType) -> bool {
+    matches!(operation_type, BinaryType::Addition | BinaryType::Subtraction | BinaryType::Multiplication | BinaryType::Division | BinaryType::Modulo | BinaryType::Exponentiation)
+}
+
+fn contains_step(node: &Node) -> bool {
+    match node {
+        Node::Binary(binary_node) => contains_step(&binary_node.left) || contains_step(&binary_node.right),
+        Node::Unary(unary_node) => is_step(&unary_node.operation_type) || contains_step(&unary_node.expression),
+        Node::Ternary(ternary_node) => contains_step(&ternary_node.condition) || contains_step(&ternary_node.consequent) || contains_step(&ternary_node.alternative),
+        Node::Call(call_node) => call_node.parameters.iter().any(|parameter| contains_step(parameter)),
+        Node::Declaration(declaration_node) => contains_step(&declaration_node.expression),
+        Node::Destructuring(destructuring_node) => contains_step(&destructuring_node.expression),
+        Node::Assignment(assignment_node) => contains_step(&assignment_node.expression),
+        Node::Return(return_node) => contains_step(&return_node.expression),
+        Node::IfElse(if_else_node) => contains_step(&if_else_node.condition),
+        _ => false,
+    }
+}
+
+fn is_step(operation_type: &UnaryType) -> bool {
+    matches!(operation_type, UnaryType::PreIncrement | UnaryType::PreDecrement | UnaryType::PostIncrement | UnaryType::PostDecrement)
+}
+
+fn same_expression(left: &Node, right: &Node) -> bool {
+    match (left, right) {
+        (Node::Number(left), Node::Number(right)) => left.number_token.parse_value().ok() == right.number_token.parse_value().ok(),
+        (Node::Char(left), Node::Char(right)) => left.value == right.value,
+        (Node::String(left), Node::String(right)) => left.value == right.value,
+        (Node::Identifier(left), Node::Identifier(right)) => left.identifier_token.name == right.identifier_token.name,
+        (Node::Binary(left), Node::Binary(right)) => {
+            left.operation_type == right.operation_type && same_expression(&left.left, &right.left) && same_expression(&left.right, &right.right)
+        }
+        (Node::Unary(left), Node::Unary(right)) => left.operation_type == right.operation_type && same_expression(&left.expression, &right.expression),
+        (Node::Ternary(left), Node::Ternary(right)) => {
+            same_expression(&left.condition, &right.condition) && same_expression(&left.consequent, &right.consequent) && same_expression(&left.alternative, &right.alternative)
+        }
+        _ => false,
+    }
+}
+#[cfg(test)]
+mod tests {
+    use crate::optimizer::{optimize, OptimizationOptions};
+    use crate::utils::test_support::{analyze, render};
+
+    fn assert_snapshot(source: &str, options: OptimizationOptions, before: &str, after: &str) {
+        let mut ast = analyze(source);
+        assert_eq!(render(&ast), before);
+
+        optimize(&mut ast, &options);
+        assert_eq!(render(&ast), after);
+    }
+
+    #[test]
+    fn constant_folding() {
+        let source = "function main(): i32 {
+    let small = 2 + 3 * 4
+    let large = 2147483647 + 1
+    return small > 10 ? small - -2 : 0
+}
+";
+
+        let before = "function main() {
+    let small = (2 + (3 * 4))
+    let large = (2147483647 + 1)
+    return ((small > 10) ? (small - -2) : 0)
+}
+";
+
+        let after = "function main() {
+    let small = 14
+    let large = (2147483647 + 1)
+    return ((small > 10) ? (small - -2) : 0)
+}
+";
+
+        assert_snapshot(source, OptimizationOptions { constant_folding: true, ..OptimizationOptions::none() }, before, after);
+    }
+
+    #[test]
+    fn dead_branch_elimination() {
+        let source = "function main(): i32 {
+    if 1 > 2 {
+        print(1)
+    } else if 2 > 1 {
+        print(2)
+    } else {
+        print(3)
+    }
+    return 0
+}
+";
+
+        let before = "function main() {
+    if (1 > 2) {
+        print(1)
+    }
+    else if (2 > 1) {
+        print(2)
+    }
+    else {
+        print(3)
+    }
+    return 0
+}
+";
+
+        let after = "function main() {
+    {
+        print(2)
+    }
+    return 0
+}
+";
+
+        assert_snapshot(source, OptimizationOptions { dead_branch_elimination: true, ..OptimizationOptions::none() }, before, after);
+    }
+
+    #[test]
+    fn inlining() {
+        let source = "function double(value: i32): i32 {
+    return value * 2
+}
+
+function main(): i32 {
+    return double(20) + double(1)
+}
+";
+
+        let before = "function double(value) {
+    return (value * 2)
+}
+function main() {
+    return (double(20) + double(1))
+}
+";
+
+        let after = "function double(value) {
+    return (value * 2)
+}
+function main() {
+    return ((20 * 2) + (1 * 2))
+}
+";
+
+        assert_snapshot(source, OptimizationOptions { inlining: true, ..OptimizationOptions::none() }, before, after);
+    }
+
+    #[test]
+    fn common_subexpression_elimination() {
+        let source = "function square_sum(a: i32, b: i32): i32 {
+    return (a + b) * (a + b)
+}
+";
+
+        let before = "function square_sum(a, b) {
+    return ((a + b) * (a + b))
+}
+";
+
+        let after = "function square_sum(a, b) {
+    let cse.0 = (a + b)
+    return (cse.0 * cse.0)
+}
+";
+
+        assert_snapshot(source, OptimizationOptions { common_subexpression_elimination: true, runtime_checks: false, ..OptimizationOptions::none() }, before, after);
+    }
+
+    #[test]
+    fn common_subexpression_elimination_skips_short_circuited_operands() {
+        let source = "function ratio_in_range(n: i32, d: i32): bool {
+    return d != 0 && n / d > 1 && n / d < 5
+}
+";
+
+        let before = "function ratio_in_range(n, d) {
+    return (((d != 0) && ((n / d) > 1)) && ((n / d) < 5))
+}
+";
+
+        assert_snapshot(source, OptimizationOptions { common_subexpression_elimination: true, runtime_checks: false, ..OptimizationOptions::none() }, before, before);
+    }
+
+    #[test]
+    fn inlining_keeps_trapping_arguments() {
+        let source = "function first(a: i32, b: i32): i32 {
+    return a
+}
+
+function main(): i32 {
+    let zero = 0
+    return first(1, 1 / zero)
+}
+";
+
+        let before = "function first(a, b) {
+    return a
+}
+function main() {
+    let zero = 0
+    return first(1, (1 / zero))
+}
+";
+
+        let after = "function first(a, b) {
+    return a
+}
+function main() {
+    let zero = 0
+    return 1
+}
+";
+
+        assert_snapshot(source, OptimizationOptions { inlining: true, ..OptimizationOptions::none() }, before, before);
+        assert_snapshot(source, OptimizationOptions { inlining: true, runtime_checks: false, ..OptimizationOptions::none() }, before, after);
+    }
+}
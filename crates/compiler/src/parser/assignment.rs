@@ -33,6 +33,9 @@ impl Parser {
             Token::Operator(operator_token) if matches!(operator_token.operator_type, OperatorCategory::Assignment(OperatorAssignmentType::DivisionAssignment)) => {
                 Ok(self.assignment_operation(identifier_node, operator_token.location(), BinaryType::Division, |_self| {_self.expression()})?)
             }
+            Token::Operator(operator_token) if matches!(operator_token.operator_type, OperatorCategory::Assignment(OperatorAssignmentType::ModuloAssignment)) => {
+                Ok(self.assignment_operation(identifier_node, operator_token.location(), BinaryType::Modulo, |_self| {_self.expression()})?)
+            }
             Token::Operator(operator_token) if matches!(operator_token.operator_type, OperatorCategory::Assignment(OperatorAssignmentType::ShiftLeftAssignment)) => {
                 Ok(self.assignment_operation(identifier_node, operator_token.location(), BinaryType::ShiftLeft, |_self| {_self.expression()})?)
             }
@@ -42,6 +45,9 @@ impl Parser {
             Token::Operator(operator_token) if matches!(operator_token.operator_type, OperatorCategory::Assignment(OperatorAssignmentType::BitwiseOrAssignment)) => {
                 Ok(self.assignment_operation(identifier_node, operator_token.location(), BinaryType::BitwiseOr, |_self| {_self.expression()})?)
             }
+            Token::Operator(operator_token) if matches!(operator_token.operator_type, OperatorCategory::Assignment(OperatorAssignmentType::BitwiseXorAssignment)) => {
+                Ok(self.assignment_operation(identifier_node, operator_token.location(), BinaryType::BitwiseXor, |_self| {_self.expression()})?)
+            }
             Token::Operator(operator_token) if matches!(operator_token.operator_type, OperatorCategory::Assignment(OperatorAssignmentType::BitwiseAndAssignment)) => {
                 Ok(self.assignment_operation(identifier_node, operator_token.location(), BinaryType::BitwiseAnd, |_self| {_self.expression()})?)
             }
@@ -0,0 +1,89 @@
+use crate::ast::{AttributeNode, IdentifierNode};
+use crate::error::AxiomError;
+use crate::error::location::{Location, Range};
+use crate::parser::Parser;
+use crate::token::{ParenthesesState, ParenthesesType, PunctuationType, Token};
+
+impl Parser {
+    pub fn attribute(&mut self) -> Result<Box<AttributeNode>, AxiomError> {
+        let token = self.current_token.clone().ok_or(AxiomError::UnexpectedEOF(self.get_next_position_from_last_token_location()))?;
+
+        if !matches!(&token, Token::Punctuation(punctuation_token) if matches!(punctuation_token.punctuation_type, PunctuationType::Hash)) {
+            return Err(AxiomError::SyntaxError(self.get_current_location_from_current_token(), "Expected '#'".into()))
+        }
+
+        let mut locations = vec![token.location()];
+
+        self.step();
+
+        let token = self.current_token.clone().ok_or(AxiomError::UnexpectedEOF(self.get_next_position_from_last_token_location()))?;
+
+        if !matches!(&token, Token::Parentheses(parentheses_token) if matches!(parentheses_token.parentheses_type, ParenthesesType::Square(ParenthesesState::Opening))) {
+            return Err(AxiomError::SyntaxError(self.get_current_location_from_current_token(), "Expected '['".into()))
+        }
+
+        self.step();
+
+        let token = self.current_token.clone().ok_or(AxiomError::UnexpectedEOF(self.get_next_position_from_last_token_location()))?;
+
+        let identifier_node = match token {
+            Token::Identifier(identifier_token) => IdentifierNode::new(identifier_token),
+            _ => return Err(AxiomError::SyntaxError(self.get_current_location_from_current_token(), "Expected identifier".into()))
+        };
+
+        self.step();
+
+        let token = self.current_token.clone().ok_or(AxiomError::UnexpectedEOF(self.get_next_position_from_last_token_location()))?;
+
+        let mut arguments = vec![];
+
+        if matches!(&token, Token::Parentheses(parentheses_token) if matches!(parentheses_token.parentheses_type, ParenthesesType::Round(ParenthesesState::Opening))) {
+            self.step();
+
+            let mut first_loop = true;
+            loop {
+                let token = self.current_token.clone().ok_or(AxiomError::UnexpectedEOF(self.get_next_position_from_last_token_location()))?;
+
+                if let Token::Parentheses(parentheses_token) = &token && matches!(parentheses_token.parentheses_type, ParenthesesType::Round(ParenthesesState::Closing)) {
+                    self.step();
+
+                    break
+                }
+
+                if !first_loop {
+                    if !matches!(&token, Token::Punctuation(punctuation_token) if matches!(punctuation_token.punctuation_type, PunctuationType::Comma)) {
+                        return Err(AxiomError::SyntaxError(self.get_current_location_from_current_token(), "Expected ',' or ')'".into()))
+                    }
+
+                    self.step()
+                }
+
+                let token = self.current_token.clone().ok_or(AxiomError::UnexpectedEOF(self.get_next_position_from_last_token_location()))?;
+
+                match token {
+                    Token::Identifier(identifier_token) => arguments.push(Box::from(IdentifierNode::new(identifier_token))),
+                    _ => return Err(AxiomError::SyntaxError(self.get_current_location_from_current_token(), "Expected identifier".into()))
+                }
+
+                self.step();
+
+                first_loop = false;
+            }
+        }
+
+        let token = self.current_token.clone().ok_or(AxiomError::UnexpectedEOF(self.get_next_position_from_last_token_location()))?;
+
+        if !matches!(&token, Token::Parentheses(parentheses_token) if matches!(parentheses_token.parentheses_type, ParenthesesType::Square(ParenthesesState::Closing))) {
+            return Err(AxiomError::SyntaxError(self.get_current_location_from_current_token(), "Expected '(' or ']'".into()))
+        }
+
+        locations.push(token.location());
+
+        self.step();
+
+        let location = Range::from_ranges(locations);
+        let attribute_node = AttributeNode::new(location, Box::from(identifier_node), arguments);
+
+        Ok(Box::from(attribute_node))
+    }
+}
\ No newline at end of file
@@ -20,6 +20,9 @@ impl Parser {
                         OperatorCategory::Bitwise(OperatorBitwiseType::And) => {
                             left = self.binary_operation(left, operator_token.location(), BinaryType::BitwiseAnd, |_self| {_self.shift()})?;
                         }
+                        OperatorCategory::Bitwise(OperatorBitwiseType::Xor) => {
+                            left = self.binary_operation(left, operator_token.location(), BinaryType::BitwiseXor, |_self| {_self.shift()})?;
+                        }
                         _ => break
                     }
                 }
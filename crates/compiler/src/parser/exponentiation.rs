@@ -0,0 +1,18 @@
+use crate::ast::{BinaryType, Node};
+use crate::error::AxiomError;
+use crate::parser::Parser;
+use crate::token::{OperatorArithmeticType, OperatorCategory, Token};
+use crate::error::location::{Location};
+
+impl Parser {
+    pub fn exponentiation(&mut self) -> Result<Box<Node>, AxiomError> {
+        let left = self.post_unary()?;
+
+        match self.current_token.clone() {
+            Some(Token::Operator(operator_token)) if matches!(operator_token.operator_type, OperatorCategory::Arithmetic(OperatorArithmeticType::Exponentiation)) => {
+                self.binary_operation(left, operator_token.location(), BinaryType::Exponentiation, |_self| {_self.pre_unary()})
+            }
+            _ => Ok(left)
+        }
+    }
+}
\ No newline at end of file
@@ -5,119 +5,145 @@ use crate::parser::Parser;
 use crate::token::{KeywordType, ParenthesesState, ParenthesesType, PunctuationType, Token};
 
 impl Parser {
-    pub fn file(&mut self) -> Result<Box<Node>, AxiomError> {
+    pub fn file(&mut self) -> Box<Node> {
         let mut functions = vec![];
         let mut file_locations = vec![];
 
-        loop {
-            let token = match self.current_token.clone() {
-                Some(token) => token,
-                None => break,
-            };
+        while self.current_token.is_some() {
+            let start_index = self.index;
 
-            match token {
-                Token::Keyword(keyword_token) if matches!(keyword_token.keyword_type, KeywordType::Function) => {
-                    self.step();
+            match self.function() {
+                Ok(function_node) => {
+                    file_locations.push(function_node.location());
+                    functions.push(function_node);
+                }
+                Err(error @ AxiomError::UnexpectedEOF(_)) => {
+                    self.errors.push(error);
+                    break;
+                }
+                Err(error) => {
+                    self.errors.push(error);
+                    self.synchronize_function(start_index);
+                }
+            }
+        }
+
+        let location = Range::from_ranges(file_locations);
+        let file_node = FileNode::new(location, functions);
+        let node = Node::File(file_node);
 
-                    let token = self.current_token.clone().ok_or(AxiomError::UnexpectedEOF(self.get_next_position_from_last_token_location()))?;
+        Box::from(node)
+    }
 
-                    match token {
-                        Token::Identifier(identifier_token) => {
-                            let identifier_node = IdentifierNode::new(identifier_token);
+    fn function(&mut self) -> Result<Box<FunctionNode>, AxiomError> {
+        let mut doc_comments = vec![];
+        while let Some(Token::DocComment(doc_comment_token)) = &self.current_token {
+            doc_comments.push(doc_comment_token.text.clone());
+            self.step();
+        }
 
-                            self.step();
+        let mut attributes = vec![];
+        while let Some(Token::Punctuation(punctuation_token)) = &self.current_token && matches!(punctuation_token.punctuation_type, PunctuationType::Hash) {
+            attributes.push(self.attribute()?);
+        }
 
-                            let token = self.current_token.clone().ok_or(AxiomError::UnexpectedEOF(self.get_next_position_from_last_token_location()))?;
+        let token = self.current_token.clone().ok_or(AxiomError::UnexpectedEOF(self.get_next_position_from_last_token_location()))?;
 
-                            match token {
-                                Token::Parentheses(parentheses_token) if matches!(parentheses_token.parentheses_type, ParenthesesType::Round(ParenthesesState::Opening)) => {
-                                    self.step();
+        match token {
+            Token::Keyword(keyword_token) if matches!(keyword_token.keyword_type, KeywordType::Function) => {
+                self.step();
 
-                                    let mut parameters = vec![];
-                                    let mut locations = vec![keyword_token.location(), identifier_node.location(), parentheses_token.location()];
+                let token = self.current_token.clone().ok_or(AxiomError::UnexpectedEOF(self.get_next_position_from_last_token_location()))?;
 
-                                    let mut first_loop = true;
-                                    loop {
-                                        let token = self.current_token.clone().ok_or(AxiomError::UnexpectedEOF(self.get_next_position_from_last_token_location()))?;
+                match token {
+                    Token::Identifier(identifier_token) => {
+                        let identifier_node = IdentifierNode::new(identifier_token);
 
-                                        if let Token::Parentheses(parentheses_token) = &token {
-                                            if matches!(parentheses_token.parentheses_type, ParenthesesType::Round(ParenthesesState::Closing)) {
-                                                locations.push(parentheses_token.location());
+                        self.step();
 
-                                                self.step();
+                        let token = self.current_token.clone().ok_or(AxiomError::UnexpectedEOF(self.get_next_position_from_last_token_location()))?;
 
-                                                break
-                                            }
-                                        }
+                        match token {
+                            Token::Parentheses(parentheses_token) if matches!(parentheses_token.parentheses_type, ParenthesesType::Round(ParenthesesState::Opening)) => {
+                                self.step();
 
-                                        if !first_loop {
-                                            if !matches!(&token, Token::Punctuation(punctuation_token) if matches!(punctuation_token.punctuation_type, PunctuationType::Comma)) {
-                                                return Err(AxiomError::SyntaxError(self.get_current_location_from_current_token(), "Expected ',' or ')'".into()))
-                                            }
+                                let mut parameters = vec![];
+                                let mut locations = vec![keyword_token.location(), identifier_node.location(), parentheses_token.location()];
 
-                                            locations.push(token.location());
+                                let mut first_loop = true;
+                                loop {
+                                    let token = self.current_token.clone().ok_or(AxiomError::UnexpectedEOF(self.get_next_position_from_last_token_location()))?;
+
+                                    if let Token::Parentheses(parentheses_token) = &token {
+                                        if matches!(parentheses_token.parentheses_type, ParenthesesType::Round(ParenthesesState::Closing)) {
+                                            locations.push(parentheses_token.location());
+
+                                            self.step();
 
-                                            self.step()
+                                            break
                                         }
+                                    }
 
-                                        let parameter = self.parameter()?;
-                                        let parameter_location = parameter.location();
+                                    if !first_loop {
+                                        if !matches!(&token, Token::Punctuation(punctuation_token) if matches!(punctuation_token.punctuation_type, PunctuationType::Comma)) {
+                                            return Err(AxiomError::SyntaxError(self.get_current_location_from_current_token(), "Expected ',' or ')'".into()))
+                                        }
 
-                                        parameters.push(parameter);
-                                        locations.push(parameter_location);
+                                        locations.push(token.location());
 
-                                        first_loop = false;
+                                        self.step()
                                     }
 
-                                    let token = self.current_token.clone().ok_or(AxiomError::UnexpectedEOF(self.get_next_position_from_last_token_location()))?;
+                                    let parameter = self.parameter()?;
+                                    let parameter_location = parameter.location();
 
-                                    let mut output_type = None;
-                                    if let Token::Punctuation(punctuation_token) = &token {
-                                        if matches!(punctuation_token.punctuation_type, PunctuationType::Colon) {
-                                            self.step();
+                                    parameters.push(parameter);
+                                    locations.push(parameter_location);
 
-                                            let token = self.current_token.clone().ok_or(AxiomError::UnexpectedEOF(self.get_next_position_from_last_token_location()))?;
+                                    first_loop = false;
+                                }
 
-                                            if let Token::Identifier(type_identifier_token) = token {
-                                                let type_identifier_location = type_identifier_token.location();
-                                                let type_identifier_node = IdentifierNode::new(type_identifier_token);
+                                let token = self.current_token.clone().ok_or(AxiomError::UnexpectedEOF(self.get_next_position_from_last_token_location()))?;
 
-                                                self.step();
+                                let mut output_type = None;
+                                if let Token::Punctuation(punctuation_token) = &token {
+                                    if matches!(punctuation_token.punctuation_type, PunctuationType::Colon) {
+                                        self.step();
 
-                                                locations.push(punctuation_token.location());
-                                                locations.push(type_identifier_location);
+                                        let token = self.current_token.clone().ok_or(AxiomError::UnexpectedEOF(self.get_next_position_from_last_token_location()))?;
+
+                                        if let Token::Identifier(type_identifier_token) = token {
+                                            let type_identifier_location = type_identifier_token.location();
+                                            let type_identifier_node = IdentifierNode::new(type_identifier_token);
+
+                                            self.step();
 
-                                                output_type = Some(Box::from(type_identifier_node));
-                                            } else {
-                                                return Err(AxiomError::SyntaxError(self.get_current_location_from_current_token(), "Expected identifier".into()))
-                                            }
+                                            locations.push(punctuation_token.location());
+                                            locations.push(type_identifier_location);
+
+                                            output_type = Some(Box::from(type_identifier_node));
+                                        } else {
+                                            return Err(AxiomError::SyntaxError(self.get_current_location_from_current_token(), "Expected identifier".into()))
                                         }
                                     }
+                                }
 
-                                    let scope = self.scope()?;
-                                    let scope_location = scope.location();
-                                    locations.push(scope_location.clone());
+                                let scope = self.scope()?;
+                                let scope_location = scope.location();
+                                locations.push(scope_location.clone());
 
-                                    let location = Range::from_ranges(locations);
-                                    let function_node = FunctionNode::new(location.clone(), Box::from(identifier_node), parameters, output_type, scope);
+                                let location = Range::from_ranges(locations);
+                                let function_node = FunctionNode::new(location, doc_comments, attributes, Box::from(identifier_node), parameters, output_type, scope);
 
-                                    file_locations.push(location.clone());
-                                    functions.push(Box::from(function_node));
-                                }
-                                _ => return Err(AxiomError::SyntaxError(self.get_current_location_from_current_token(), "Expected '('".into()))
+                                Ok(Box::from(function_node))
                             }
+                            _ => Err(AxiomError::SyntaxError(self.get_current_location_from_current_token(), "Expected '('".into()))
                         }
-                        _ => return Err(AxiomError::SyntaxError(self.get_current_location_from_current_token(), "Expected identifier".into()))
                     }
+                    _ => Err(AxiomError::SyntaxError(self.get_current_location_from_current_token(), "Expected identifier".into()))
                 }
-                _ => return Err(AxiomError::SyntaxError(self.get_current_location_from_current_token(), "Unexpected tokens".into()))
             }
+            _ => Err(AxiomError::SyntaxError(self.get_current_location_from_current_token(), "Unexpected tokens".into()))
         }
-
-        let location = Range::from_ranges(file_locations);
-        let file_node = FileNode::new(location, functions);
-        let node = Node::File(file_node);
-
-        Ok(Box::from(node))
     }
 }
\ No newline at end of file
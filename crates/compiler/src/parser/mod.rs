@@ -1,4 +1,5 @@
 mod file;
+mod attribute;
 mod parameter;
 mod scope;
 mod statement;
@@ -13,31 +14,59 @@ mod shift;
 mod additive;
 mod multiplicative;
 mod pre_unary;
+mod exponentiation;
 mod post_unary;
 mod primary;
 
 use crate::ast::{AssignmentNode, BinaryNode, BinaryType, IdentifierNode, Node};
 use crate::error::{AxiomError};
 use crate::error::location::{Location, Position, Range};
-use crate::token::{Token};
+use crate::token::{KeywordType, ParenthesesState, ParenthesesType, PunctuationType, Token};
 
 pub struct Parser {
     index: usize,
     current_token: Option<Token>,
     previous_token: Option<Token>,
-    tokens: Vec<Token>
+    tokens: Vec<Token>,
+    errors: Vec<AxiomError>
 }
 
 impl Parser {
     pub fn new(tokens: Vec<Token>) -> Parser {
+        let tokens = Parser::retain_item_doc_comments(tokens);
+
         Parser {
             index: 0,
             current_token: tokens.get(0).cloned(),
             previous_token: None,
-            tokens
+            tokens,
+            errors: vec![]
         }
     }
     
+    fn retain_item_doc_comments(tokens: Vec<Token>) -> Vec<Token> {
+        let mut retained_tokens = Vec::with_capacity(tokens.len());
+
+        for (index, token) in tokens.iter().enumerate() {
+            if matches!(token, Token::DocComment(_)) {
+                let documented_token = tokens[index + 1..].iter().find(|token| !matches!(token, Token::DocComment(_)));
+                let documents_item = match documented_token {
+                    Some(Token::Keyword(keyword_token)) => matches!(keyword_token.keyword_type, KeywordType::Function),
+                    Some(Token::Punctuation(punctuation_token)) => matches!(punctuation_token.punctuation_type, PunctuationType::Hash),
+                    _ => false,
+                };
+
+                if !documents_item {
+                    continue;
+                }
+            }
+
+            retained_tokens.push(token.clone());
+        }
+
+        retained_tokens
+    }
+
     fn step(&mut self) {
         self.previous_token = self.current_token.clone();
         self.index += 1;
@@ -56,8 +85,66 @@ impl Parser {
         self.current_token.clone().unwrap_or(Token::Unknown(Position::new(0, 0), ' ')).location()
     }
     
-    pub fn parse(mut self) -> Result<Box<Node>, AxiomError> {
-        self.file()
+    pub fn parse(mut self) -> (Box<Node>, Vec<AxiomError>) {
+        let file = self.file();
+
+        (file, self.errors)
+    }
+
+    fn is_on_new_line(&self, token: &Token) -> bool {
+        match &self.previous_token {
+            Some(previous_token) => token.location().start.line > previous_token.location().end.line,
+            None => false
+        }
+    }
+
+    fn synchronize_statement(&mut self, start_index: usize) {
+        if self.index == start_index {
+            self.step();
+        }
+
+        let mut depth = 0;
+
+        while let Some(token) = self.current_token.clone() {
+            match &token {
+                Token::Keyword(keyword_token) if matches!(keyword_token.keyword_type, KeywordType::Function) => break,
+                Token::Keyword(keyword_token) if depth == 0 && matches!(keyword_token.keyword_type, KeywordType::Let | KeywordType::Return | KeywordType::If) => break,
+                Token::Parentheses(parentheses_token) if matches!(parentheses_token.parentheses_type, ParenthesesType::Curly(ParenthesesState::Opening)) => depth += 1,
+                Token::Parentheses(parentheses_token) if matches!(parentheses_token.parentheses_type, ParenthesesType::Curly(ParenthesesState::Closing)) => {
+                    if depth == 0 {
+                        break;
+                    }
+
+                    depth -= 1;
+                }
+                token if depth == 0 && self.is_on_new_line(token) => break,
+                _ => {}
+            }
+
+            self.step();
+        }
+    }
+
+    fn synchronize_function(&mut self, start_index: usize) {
+        if self.index == start_index {
+            self.step();
+        }
+
+        while let Some(token) = &self.current_token {
+            if matches!(token, Token::Keyword(keyword_token) if matches!(keyword_token.keyword_type, KeywordType::Function)) {
+                break;
+            }
+
+            if matches!(token, Token::Punctuation(punctuation_token) if matches!(punctuation_token.punctuation_type, PunctuationType::Hash)) {
+                break;
+            }
+
+            if matches!(token, Token::DocComment(_)) {
+                break;
+            }
+
+            self.step();
+        }
     }
 
     fn binary_operation<F>(&mut self, left: Box<Node>, operator_location: Range, binary_operation_type: BinaryType, right_fn: F) -> Result<Box<Node>, AxiomError> where F: Fn(&mut Self) -> Result<Box<Node>, AxiomError> {
@@ -87,4 +174,69 @@ impl Parser {
 
         Ok(Box::from(node))
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::Node;
+    use crate::utils::test_support::{analyze_with_errors, parse, render};
+
+    fn render_expression(expression: &str) -> String {
+        let source = format!("function main(): i32 {{\n    return {}\n}}\n", expression);
+        let (ast, errors) = parse(&source);
+        assert!(errors.is_empty(), "{:?}", errors);
+
+        let rendered = render(&ast);
+        rendered.lines().nth(1).unwrap().trim().trim_start_matches("return ").to_string()
+    }
+
+    #[test]
+    fn exponentiation_binds_tighter_than_unary_minus() {
+        assert_eq!(render_expression("-2 ** 2"), "-(2 ** 2)");
+        assert_eq!(render_expression("-x ** 2"), "-(x ** 2)");
+        assert_eq!(render_expression("2 ** -1"), "(2 ** -1)");
+    }
+
+    #[test]
+    fn exponentiation_is_right_associative() {
+        assert_eq!(render_expression("2 ** 3 ** 2"), "(2 ** (3 ** 2))");
+        assert_eq!(render_expression("2 * 3 ** 2"), "(2 * (3 ** 2))");
+    }
+
+    #[test]
+    fn new_operators_follow_the_precedence_chain() {
+        assert_eq!(render_expression("1 + 7 % 4"), "(1 + (7 % 4))");
+        assert_eq!(render_expression("1 | 2 ^ 3 & 4"), "(((1 | 2) ^ 3) & 4)");
+        assert_eq!(render_expression("~1 + 2"), "(~1 + 2)");
+    }
+
+    #[test]
+    fn reports_every_syntax_error_of_a_file() {
+        let source = "function main(): i32 {
+    print(1 +)
+    return helper(2)
+
+function helper(n: i32): i32 {
+    let y = )
+    return n * 2
+}
+
+function other(): i32 {
+    if 1 < 2 {
+        return helper(
+";
+        let (ast, errors) = analyze_with_errors(source);
+        let messages: Vec<String> = errors.iter().map(|error| error.to_string()).collect();
+
+        assert_eq!(messages, vec![
+            "2:14: SyntaxError: Unexpected tokens",
+            "5:1: SyntaxError: Expected '}'",
+            "6:13: SyntaxError: Unexpected tokens",
+            "12:23: Unexpected EOF",
+        ]);
+
+        let Node::File(file_node) = ast.as_ref() else { panic!("expected a file node") };
+        let names: Vec<&str> = file_node.functions.iter().map(|function| function.identifier_node.identifier_token.name.as_str()).collect();
+        assert_eq!(names, vec!["main", "helper", "other"]);
+    }
+}
@@ -20,6 +20,9 @@ impl Parser {
                         OperatorCategory::Arithmetic(OperatorArithmeticType::Division) => {
                             left = self.binary_operation(left, operator_token.location(), BinaryType::Division, |_self| {_self.pre_unary()})?;
                         }
+                        OperatorCategory::Arithmetic(OperatorArithmeticType::Modulo) => {
+                            left = self.binary_operation(left, operator_token.location(), BinaryType::Modulo, |_self| {_self.pre_unary()})?;
+                        }
                         _ => break
                     }
                 }
@@ -1,8 +1,8 @@
-use crate::ast::{Node, UnaryNode, UnaryType};
+use crate::ast::{Node, NumberNode, UnaryNode, UnaryType};
 use crate::error::AxiomError;
 use crate::parser::Parser;
 use crate::error::location::{Location, Range};
-use crate::token::{OperatorArithmeticType, OperatorCategory, OperatorLogicalType, Token};
+use crate::token::{OperatorArithmeticType, OperatorBitwiseType, OperatorCategory, OperatorLogicalType, Token};
 
 impl Parser {
     pub fn pre_unary(&mut self) -> Result<Box<Node>, AxiomError> {
@@ -48,6 +48,14 @@ impl Parser {
                 let expression = self.pre_unary()?;
 
                 let location = Range::from_ranges(vec![expression.location(), operator_token.location()]);
+
+                if let Node::Number(number_node) = &*expression {
+                    let number_token = number_node.number_token.negated(location.clone());
+                    let node = Node::Number(NumberNode::new(location, number_token));
+
+                    return Ok(Box::new(node));
+                }
+
                 let unary_node = UnaryNode::new(location, expression, UnaryType::Minus);
                 let node = Node::Unary(unary_node);
 
@@ -64,8 +72,19 @@ impl Parser {
 
                 Ok(Box::new(node))
             }
+            Token::Operator(operator_token) if matches!(operator_token.operator_type, OperatorCategory::Bitwise(OperatorBitwiseType::Not)) => {
+                self.step();
+
+                let expression = self.pre_unary()?;
+
+                let location = Range::from_ranges(vec![expression.location(), operator_token.location()]);
+                let unary_node = UnaryNode::new(location, expression, UnaryType::BitwiseNot);
+                let node = Node::Unary(unary_node);
+
+                Ok(Box::new(node))
+            }
             _ => {
-                self.post_unary()
+                self.exponentiation()
             }
         }
     }
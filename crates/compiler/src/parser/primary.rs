@@ -1,4 +1,4 @@
-use crate::ast::{CallNode, IdentifierNode, Node, NumberNode};
+use crate::ast::{CallNode, CharNode, IdentifierNode, Node, NumberNode, StringNode};
 use crate::error::AxiomError;
 use crate::error::location::{Location, Range};
 use crate::parser::Parser;
@@ -17,6 +17,26 @@ impl Parser {
 
                 Ok(Box::from(node))
             }
+            Token::Char(char_token) => {
+                let value = char_token.parse_value().unwrap_or(char::REPLACEMENT_CHARACTER);
+
+                self.step();
+
+                let char_node = CharNode::new(char_token.location(), char_token, value);
+                let node = Node::Char(char_node);
+
+                Ok(Box::from(node))
+            }
+            Token::String(string_token) => {
+                let value = string_token.parse_value().unwrap_or_default();
+
+                self.step();
+
+                let string_node = StringNode::new(string_token.location(), string_token, value);
+                let node = Node::String(string_node);
+
+                Ok(Box::from(node))
+            }
             Token::Identifier(identifier_token) => {
                 self.step();
 
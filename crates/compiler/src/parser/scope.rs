@@ -1,8 +1,8 @@
-use crate::ast::ScopeNode;
+use crate::ast::{ErrorNode, Node, ScopeNode};
 use crate::error::AxiomError;
 use crate::parser::Parser;
 use crate::error::location::{Location, Range};
-use crate::token::{ParenthesesState, ParenthesesType, Token};
+use crate::token::{KeywordType, ParenthesesState, ParenthesesType, Token};
 
 impl Parser {
     pub fn scope(&mut self) -> Result<Box<ScopeNode>, AxiomError> {
@@ -16,9 +16,14 @@ impl Parser {
                 let mut locations = vec![parentheses_token.location()];
 
                 loop {
-                    let token = self.current_token.clone().ok_or(AxiomError::UnexpectedEOF(self.get_next_position_from_last_token_location()))?;
+                    let Some(token) = self.current_token.clone() else {
+                        self.report_unclosed_scope(AxiomError::UnexpectedEOF(self.get_next_position_from_last_token_location()));
+                        statements.push(self.missing_body_placeholder());
 
-                    if let Token::Parentheses(parentheses_token) = token {
+                        break
+                    };
+
+                    if let Token::Parentheses(parentheses_token) = &token {
                         if matches!(parentheses_token.parentheses_type, ParenthesesType::Curly(ParenthesesState::Closing)) {
                             locations.push(parentheses_token.location());
 
@@ -28,10 +33,42 @@ impl Parser {
                         }
                     }
 
-                    let statement = self.statement()?;
+                    if let Token::Keyword(keyword_token) = &token && matches!(keyword_token.keyword_type, KeywordType::Function) {
+                        self.report_unclosed_scope(AxiomError::SyntaxError(keyword_token.location(), "Expected '}'".into()));
+                        statements.push(self.missing_body_placeholder());
+
+                        break
+                    }
+
+                    let start_index = self.index;
+
+                    match self.statement() {
+                        Ok(statement) => {
+                            locations.push(statement.location());
+                            statements.push(statement);
+                        }
+                        Err(error @ AxiomError::UnexpectedEOF(_)) => {
+                            self.report_unclosed_scope(error);
+
+                            let end_position = self.previous_token.as_ref().unwrap().location().end;
+                            let location = Range::new(token.location().start, end_position);
+
+                            locations.push(location.clone());
+                            statements.push(Box::from(Node::Error(ErrorNode::new(location))));
+
+                            break
+                        }
+                        Err(error) => {
+                            self.errors.push(error);
+                            self.synchronize_statement(start_index);
+
+                            let end_position = self.previous_token.as_ref().unwrap().location().end;
+                            let location = Range::new(token.location().start, end_position);
 
-                    locations.push(statement.location());
-                    statements.push(statement);
+                            locations.push(location.clone());
+                            statements.push(Box::from(Node::Error(ErrorNode::new(location))));
+                        }
+                    }
                 }
 
                 let location = Range::from_ranges(locations);
@@ -42,4 +79,18 @@ impl Parser {
             _ => Err(AxiomError::SyntaxError(self.get_current_location_from_current_token(), "Expected '{'".into()))
         }
     }
-}
\ No newline at end of file
+
+    /// Nested scopes that run into the same EOF or `function` keyword only report it once.
+    fn report_unclosed_scope(&mut self, error: AxiomError) {
+        if self.errors.last().map(|last_error| last_error.location()) != Some(error.location()) {
+            self.errors.push(error);
+        }
+    }
+
+    /// Stands in for the rest of a body that was cut off, so the function and its signature are kept.
+    fn missing_body_placeholder(&self) -> Box<Node> {
+        let end_position = self.previous_token.as_ref().unwrap().location().end;
+
+        Box::from(Node::Error(ErrorNode::new(Range::new(end_position.clone(), end_position))))
+    }
+}
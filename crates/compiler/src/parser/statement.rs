@@ -1,8 +1,8 @@
-use crate::ast::{CallNode, DeclarationNode, IdentifierNode, IfElseNode, Node, ReturnNode, ScopeNode, UnaryNode, UnaryType};
+use crate::ast::{CallNode, DeclarationNode, DestructuringNode, IdentifierNode, IfElseNode, Node, ReturnNode, ScopeNode, UnaryNode, UnaryType};
 use crate::error::AxiomError;
 use crate::error::location::{Location, Range};
 use crate::parser::Parser;
-use crate::token::{KeywordType, OperatorArithmeticType, OperatorAssignmentType, OperatorCategory, ParenthesesState, ParenthesesType, PunctuationType, Token};
+use crate::token::{KeywordToken, KeywordType, OperatorArithmeticType, OperatorAssignmentType, OperatorCategory, ParenthesesState, ParenthesesType, PunctuationType, Token};
 
 impl Parser {
     pub fn statement(&mut self) -> Result<Box<Node>, AxiomError> {
@@ -38,6 +38,7 @@ impl Parser {
                             _ => Err(AxiomError::SyntaxError(self.get_current_location_from_current_token(), "Expected '='".into()))
                         }
                     }
+                    Token::Parentheses(parentheses_token) if matches!(parentheses_token.parentheses_type, ParenthesesType::Round(ParenthesesState::Opening)) => self.destructuring(keyword_token),
                     _ => Err(AxiomError::SyntaxError(self.get_current_location_from_current_token(), "Expected identifier".into()))
                 }
             }
@@ -185,4 +186,65 @@ impl Parser {
             _ => Err(AxiomError::SyntaxError(self.get_current_location_from_current_token(), "Unexpected tokens".into()))
         }
     }
+
+    fn destructuring(&mut self, keyword_token: KeywordToken) -> Result<Box<Node>, AxiomError> {
+        let mut identifier_nodes = vec![];
+        let mut locations = vec![keyword_token.location(), self.get_current_location_from_current_token()];
+
+        self.step();
+
+        loop {
+            let token = self.current_token.clone().ok_or(AxiomError::UnexpectedEOF(self.get_next_position_from_last_token_location()))?;
+
+            if let Token::Parentheses(parentheses_token) = &token && matches!(parentheses_token.parentheses_type, ParenthesesType::Round(ParenthesesState::Closing)) && !identifier_nodes.is_empty() {
+                locations.push(parentheses_token.location());
+
+                self.step();
+
+                break
+            }
+
+            if !identifier_nodes.is_empty() {
+                if !matches!(&token, Token::Punctuation(punctuation_token) if matches!(punctuation_token.punctuation_type, PunctuationType::Comma)) {
+                    return Err(AxiomError::SyntaxError(self.get_current_location_from_current_token(), "Expected ',' or ')'".into()))
+                }
+
+                locations.push(token.location());
+
+                self.step()
+            }
+
+            let token = self.current_token.clone().ok_or(AxiomError::UnexpectedEOF(self.get_next_position_from_last_token_location()))?;
+
+            let Token::Identifier(identifier_token) = token else {
+                return Err(AxiomError::SyntaxError(self.get_current_location_from_current_token(), "Expected identifier".into()))
+            };
+
+            let identifier_node = IdentifierNode::new(identifier_token);
+            locations.push(identifier_node.location());
+            identifier_nodes.push(Box::from(identifier_node));
+
+            self.step();
+        }
+
+        let token = self.current_token.clone().ok_or(AxiomError::UnexpectedEOF(self.get_next_position_from_last_token_location()))?;
+
+        match token {
+            Token::Operator(operator_token) if matches!(operator_token.operator_type, OperatorCategory::Assignment(OperatorAssignmentType::Assignment)) => {
+                self.step();
+
+                let expression = self.expression()?;
+
+                locations.push(operator_token.location());
+                locations.push(expression.location());
+
+                let location = Range::from_ranges(locations);
+                let destructuring_node = DestructuringNode::new(location, identifier_nodes, expression);
+                let node = Node::Destructuring(destructuring_node);
+
+                Ok(Box::from(node))
+            }
+            _ => Err(AxiomError::SyntaxError(self.get_current_location_from_current_token(), "Expected '='".into()))
+        }
+    }
 }
\ No newline at end of file
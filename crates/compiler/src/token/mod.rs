@@ -6,11 +6,14 @@ mod tokens;
 #[derive(Debug, PartialEq, Clone)]
 pub enum Token {
     Number(NumberToken),
+    Char(CharToken),
+    String(StringToken),
     Identifier(IdentifierToken),
     Keyword(KeywordToken),
     Operator(OperatorToken),
     Parentheses(ParenthesesToken),
     Punctuation(PunctuationToken),
+    DocComment(DocCommentToken),
     Unknown(Position, char),
 }
 
@@ -18,11 +21,14 @@ impl Location for Token {
     fn location(&self) -> Range {
         match self {
             Token::Number(number_token) => number_token.location(),
+            Token::Char(char_token) => char_token.location(),
+            Token::String(string_token) => string_token.location(),
             Token::Identifier(identifier_token) => identifier_token.location(),
             Token::Keyword(keyword_token) => keyword_token.location(),
             Token::Operator(operator_token) => operator_token.location(),
             Token::Parentheses(parentheses_token) => parentheses_token.location(),
             Token::Punctuation(punctuation_token) => punctuation_token.location(),
+            Token::DocComment(doc_comment_token) => doc_comment_token.location(),
             Token::Unknown(position, _) => Range {
                 start: position.clone(),
                 end: position.clone(),
@@ -0,0 +1,93 @@
+use crate::error::AxiomError;
+use crate::error::location::{Location, Position, Range};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct CharToken {
+    pub literal: String,
+    pub is_terminated: bool,
+    location: Range
+}
+
+impl CharToken {
+    pub fn new(literal: String, is_terminated: bool, location: Range) -> CharToken {
+        CharToken {
+            literal,
+            is_terminated,
+            location
+        }
+    }
+
+    fn range(&self, start_offset: usize, end_offset: usize) -> Range {
+        let start = &self.location.start;
+
+        Range::new(Position::new(start.line, start.column + start_offset), Position::new(start.line, start.column + end_offset))
+    }
+
+    pub fn parse_value(&self) -> Result<char, AxiomError> {
+        let chars: Vec<char> = self.literal.chars().collect();
+
+        if !self.is_terminated {
+            return Err(AxiomError::UnterminatedLiteral(self.location(), "character".into()));
+        }
+
+        let content = &chars[1..chars.len() - 1];
+
+        let (value, length) = match content {
+            [] => return Err(AxiomError::SyntaxError(self.location(), "Empty character literal".into())),
+            ['\\', ..] => parse_escape(content, |start, end| self.range(start + 1, end + 1))?,
+            [value, ..] => (*value, 1),
+        };
+
+        if length < content.len() {
+            return Err(AxiomError::SyntaxError(self.range(1, content.len()), "Character literal must contain exactly one character".into()));
+        }
+
+        Ok(value)
+    }
+}
+
+/// Parses the escape sequence at the start of `sequence`, which begins with the backslash, into
+/// its value and the number of characters it spans. `range` maps offsets within the sequence to
+/// source ranges.
+pub(crate) fn parse_escape(sequence: &[char], range: impl Fn(usize, usize) -> Range) -> Result<(char, usize), AxiomError> {
+    let value = match &sequence[1..] {
+        ['u', rest @ ..] => return parse_unicode_escape(rest, range),
+        ['n', ..] => '\n',
+        ['t', ..] => '\t',
+        ['r', ..] => '\r',
+        ['0', ..] => '\0',
+        ['\\', ..] => '\\',
+        ['\'', ..] => '\'',
+        ['"', ..] => '"',
+        [escape, ..] => return Err(AxiomError::SyntaxError(range(0, 1), format!("Unknown character escape '\\{}'", escape))),
+        [] => return Err(AxiomError::SyntaxError(range(0, 0), "Expected a character after '\\'".into())),
+    };
+
+    Ok((value, 2))
+}
+
+fn parse_unicode_escape(rest: &[char], range: impl Fn(usize, usize) -> Range) -> Result<(char, usize), AxiomError> {
+    if rest.first() != Some(&'{') {
+        return Err(AxiomError::SyntaxError(range(0, 1), "Expected '{' after '\\u'".into()));
+    }
+
+    let closing_index = rest.iter().position(|char| *char == '}').ok_or(AxiomError::SyntaxError(range(0, rest.len() + 1), "Unterminated unicode escape".into()))?;
+    let escape_range = range(0, closing_index + 2);
+
+    let digits: String = rest[1..closing_index].iter().collect();
+
+    if digits.is_empty() || digits.len() > 6 {
+        return Err(AxiomError::SyntaxError(escape_range, "Unicode escape must have between 1 and 6 hex digits".into()));
+    }
+
+    let code_point = u32::from_str_radix(&digits, 16).map_err(|_| AxiomError::SyntaxError(escape_range.clone(), format!("Invalid hex digits '{}' in unicode escape", digits)))?;
+    let value = char::from_u32(code_point).ok_or(AxiomError::SyntaxError(escape_range, format!("'{:X}' is not a unicode scalar value", code_point)))?;
+
+    Ok((value, closing_index + 3))
+}
+
+impl Location for CharToken {
+    fn location(&self) -> Range {
+        self.location.clone()
+    }
+}
\ No newline at end of file
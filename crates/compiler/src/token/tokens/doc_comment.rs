@@ -0,0 +1,22 @@
+use crate::error::location::{Location, Range};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct DocCommentToken {
+    pub text: String,
+    location: Range
+}
+
+impl DocCommentToken {
+    pub fn new(text: String, location: Range) -> DocCommentToken {
+        DocCommentToken {
+            text,
+            location
+        }
+    }
+}
+
+impl Location for DocCommentToken {
+    fn location(&self) -> Range {
+        self.location.clone()
+    }
+}
\ No newline at end of file
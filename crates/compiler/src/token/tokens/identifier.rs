@@ -13,6 +13,10 @@ impl IdentifierToken {
             location
         }
     }
+
+    pub fn is_discard(&self) -> bool {
+        self.name == "_"
+    }
 }
 
 impl Location for IdentifierToken {
@@ -1,13 +1,19 @@
 mod number;
+mod char;
+mod string;
 mod identifier;
 mod operator;
 mod parentheses;
 mod keyword;
 mod punctuation;
+mod doc_comment;
 
 pub use number::*;
+pub use char::*;
+pub use string::*;
 pub use identifier::*;
 pub use operator::*;
 pub use parentheses::*;
 pub use keyword::*;
-pub use punctuation::*;
\ No newline at end of file
+pub use punctuation::*;
+pub use doc_comment::*;
\ No newline at end of file
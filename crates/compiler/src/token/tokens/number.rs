@@ -1,3 +1,5 @@
+use std::num::ParseIntError;
+use crate::error::AxiomError;
 use crate::error::location::{Location, Range};
 
 #[derive(Debug, PartialEq, Clone)]
@@ -13,6 +15,69 @@ impl NumberToken {
             location
         }
     }
+
+    pub fn negated(&self, location: Range) -> NumberToken {
+        let value = match self.value.strip_prefix('-') {
+            Some(value) => value.to_string(),
+            None => format!("-{}", self.value),
+        };
+
+        NumberToken::new(value, location)
+    }
+
+    fn split_radix(literal: &str) -> (u32, &str, &str) {
+        match literal.get(0..2) {
+            Some("0x" | "0X") => (16, "hexadecimal", &literal[2..]),
+            Some("0o" | "0O") => (8, "octal", &literal[2..]),
+            Some("0b" | "0B") => (2, "binary", &literal[2..]),
+            _ => (10, "decimal", literal),
+        }
+    }
+
+    /// Checks the literal as written by the lexer, before any sign is folded into it.
+    pub fn check_digits(&self) -> Result<(), AxiomError> {
+        let (radix, radix_name, digits) = NumberToken::split_radix(&self.value);
+        let malformed = |reason: String| AxiomError::MalformedNumberLiteral(self.location(), self.value.clone(), reason);
+
+        if digits.is_empty() {
+            return Err(malformed(format!("expected at least one {} digit", radix_name)));
+        }
+
+        let chars: Vec<char> = digits.chars().collect();
+        for (index, char) in chars.iter().enumerate() {
+            if *char == '_' {
+                let is_between_digits = index > 0 && chars[index - 1] != '_' && chars.get(index + 1).is_some_and(|next| *next != '_');
+
+                if !is_between_digits {
+                    return Err(malformed("'_' must separate two digits".into()));
+                }
+            } else if !char.is_digit(radix) {
+                return Err(malformed(format!("'{}' is not a {} digit", char, radix_name)));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Hexadecimal, octal and binary literals describe a 32-bit pattern, so values up to `0xFFFFFFFF` wrap
+    /// into the negative range of i32. A folded minus sign negates the wrapped value.
+    pub fn parse_value(&self) -> Result<i64, ParseIntError> {
+        let (is_negative, literal) = match self.value.strip_prefix('-') {
+            Some(literal) => (true, literal),
+            None => (false, self.value.as_str()),
+        };
+
+        let literal: String = literal.chars().filter(|char| *char != '_').collect();
+        let (radix, _, digits) = NumberToken::split_radix(&literal);
+
+        let mut value = i64::from_str_radix(digits, radix)?;
+
+        if radix != 10 && value <= u32::MAX as i64 {
+            value = value as u32 as i32 as i64;
+        }
+
+        Ok(if is_negative { -value } else { value })
+    }
 }
 
 impl Location for NumberToken {
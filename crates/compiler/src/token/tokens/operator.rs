@@ -16,6 +16,8 @@ pub enum OperatorArithmeticType {
     Subtraction,
     Multiplication,
     Division,
+    Modulo,
+    Exponentiation,
     Increment,
     Decrement,
 }
@@ -25,7 +27,9 @@ pub enum OperatorBitwiseType {
     ShiftLeft,
     ShiftRight,
     Or,
-    And
+    And,
+    Xor,
+    Not
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -42,10 +46,12 @@ pub enum OperatorAssignmentType {
     SubtractionAssignment,
     MultiplicationAssignment,
     DivisionAssignment,
+    ModuloAssignment,
     ShiftLeftAssignment,
     ShiftRightAssignment,
     BitwiseAndAssignment,
     BitwiseOrAssignment,
+    BitwiseXorAssignment,
     AndAssignment,
     OrAssignment,
 }
@@ -9,7 +9,8 @@ pub enum ParenthesesState {
 #[derive(Debug, PartialEq, Clone)]
 pub enum ParenthesesType {
     Round(ParenthesesState),
-    Curly(ParenthesesState)
+    Curly(ParenthesesState),
+    Square(ParenthesesState)
 }
 
 #[derive(Debug, PartialEq, Clone)]
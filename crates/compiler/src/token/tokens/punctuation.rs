@@ -4,7 +4,8 @@ use crate::error::location::{Location, Position, Range};
 pub enum PunctuationType {
     Comma,
     Colon,
-    QuestionMark
+    QuestionMark,
+    Hash
 }
 
 #[derive(Debug, PartialEq, Clone)]
@@ -0,0 +1,60 @@
+use crate::error::AxiomError;
+use crate::error::location::{Location, Position, Range};
+use crate::token::tokens::char::parse_escape;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct StringToken {
+    pub literal: String,
+    pub is_terminated: bool,
+    location: Range
+}
+
+impl StringToken {
+    pub fn new(literal: String, is_terminated: bool, location: Range) -> StringToken {
+        StringToken {
+            literal,
+            is_terminated,
+            location
+        }
+    }
+
+    pub fn parse_value(&self) -> Result<String, AxiomError> {
+        let chars: Vec<char> = self.literal.chars().collect();
+
+        if !self.is_terminated {
+            return Err(AxiomError::UnterminatedLiteral(self.location(), "string".into()));
+        }
+
+        let content = &chars[1..chars.len() - 1];
+        let mut value = String::new();
+        let mut index = 0;
+
+        while index < content.len() {
+            if content[index] != '\\' {
+                value.push(content[index]);
+                index += 1;
+                continue;
+            }
+
+            let start = &self.location.start;
+            let (char, length) = parse_escape(&content[index..], |start_offset, end_offset| {
+                Range::new(Position::new(start.line, start.column + index + 1 + start_offset), Position::new(start.line, start.column + index + 1 + end_offset))
+            })?;
+
+            if char == '\0' {
+                return Err(AxiomError::SyntaxError(Range::new(Position::new(start.line, start.column + index + 1), Position::new(start.line, start.column + index + 2)), "String literals cannot contain '\\0'".into()));
+            }
+
+            value.push(char);
+            index += length;
+        }
+
+        Ok(value)
+    }
+}
+
+impl Location for StringToken {
+    fn location(&self) -> Range {
+        self.location.clone()
+    }
+}
\ No newline at end of file
@@ -1,5 +1,7 @@
 pub mod symbol_table;
 mod user_input;
+#[cfg(test)]
+pub(crate) mod test_support;
 
 pub use crate::utils::symbol_table::*;
 pub use crate::utils::user_input::*;
\ No newline at end of file
@@ -32,11 +32,48 @@ impl<K: Eq + Hash, V> SymbolTable<K, V> {
     pub fn has(&self, identifier: &K) -> bool {
         self.symbol_tables.iter().any(|symbol_table| symbol_table.contains_key(identifier))
     }
+
+    pub fn has_in_current_scope(&self, identifier: &K) -> bool {
+        self.symbol_tables.last().unwrap().contains_key(identifier)
+    }
 }
 
 impl SymbolTable<String, DataType> {
     pub fn add_build_in_types(&mut self) {
         self.add("i32".to_string(), DataType::Type(Box::from(DataType::I32)));
         self.add("bool".to_string(), DataType::Type(Box::from(DataType::Bool)));
+        self.add("char".to_string(), DataType::Type(Box::from(DataType::Char)));
+        self.add("str".to_string(), DataType::Type(Box::from(DataType::Str)));
+        self.add("args".to_string(), DataType::Type(Box::from(DataType::Args)));
+    }
+
+    pub fn add_build_in_functions(&mut self) {
+        self.add("print".to_string(), DataType::Function(vec![DataType::I32], Box::from(DataType::None)));
+        self.add("print_str".to_string(), DataType::Function(vec![DataType::Str], Box::from(DataType::None)));
+
+        for operation in ["add", "sub", "mul"] {
+            self.add(format!("wrapping_{}", operation), DataType::Function(vec![DataType::I32, DataType::I32], Box::from(DataType::I32)));
+            self.add(format!("saturating_{}", operation), DataType::Function(vec![DataType::I32, DataType::I32], Box::from(DataType::I32)));
+            self.add(format!("checked_{}", operation), DataType::Function(vec![DataType::I32, DataType::I32], Box::from(DataType::Tuple(vec![DataType::Bool, DataType::I32]))));
+        }
+
+        self.add("assert".to_string(), DataType::Function(vec![DataType::Bool], Box::from(DataType::None)));
+        self.add("assert_eq".to_string(), DataType::Function(vec![DataType::I32, DataType::I32], Box::from(DataType::None)));
+
+        self.add("arg_count".to_string(), DataType::Function(vec![], Box::from(DataType::I32)));
+        self.add("arg".to_string(), DataType::Function(vec![DataType::I32], Box::from(DataType::Str)));
+        self.add("len".to_string(), DataType::Function(vec![DataType::Args], Box::from(DataType::I32)));
+        self.add("get".to_string(), DataType::Function(vec![DataType::Args, DataType::I32], Box::from(DataType::Str)));
+        self.add("env".to_string(), DataType::Function(vec![DataType::Str], Box::from(DataType::Str)));
+
+        self.add("parse_i32".to_string(), DataType::Function(vec![DataType::Str], Box::from(DataType::I32)));
+        self.add("str_eq".to_string(), DataType::Function(vec![DataType::Str, DataType::Str], Box::from(DataType::Bool)));
+
+        self.add("to_i32".to_string(), DataType::Function(vec![DataType::Char], Box::from(DataType::I32)));
+        self.add("from_i32".to_string(), DataType::Function(vec![DataType::I32], Box::from(DataType::Char)));
+
+        for classification in ["is_ascii_digit", "is_ascii_alpha", "is_ascii_alphanumeric", "is_ascii_whitespace", "is_ascii_upper", "is_ascii_lower"] {
+            self.add(classification.to_string(), DataType::Function(vec![DataType::Char], Box::from(DataType::Bool)));
+        }
     }
 }
\ No newline at end of file
@@ -0,0 +1,216 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use crate::analyzer::Analyzer;
+use crate::ast::{BinaryType, Node, ScopeNode, UnaryType};
+use crate::error::AxiomError;
+use crate::lexer::Lexer;
+use crate::parser::Parser;
+use crate::utils::SymbolTable;
+
+static NEXT_DIRECTORY: AtomicUsize = AtomicUsize::new(0);
+
+pub fn parse(source: &str) -> (Box<Node>, Vec<AxiomError>) {
+    let (tokens, mut errors) = Lexer::new(&source.to_string()).parse();
+
+    let (ast, parser_errors) = Parser::new(tokens).parse();
+    errors.extend(parser_errors);
+
+    (ast, errors)
+}
+
+pub fn analyze_with_errors(source: &str) -> (Box<Node>, Vec<AxiomError>) {
+    let (mut ast, mut errors) = parse(source);
+
+    let mut symbol_table = SymbolTable::new();
+    symbol_table.add_build_in_types();
+    symbol_table.add_build_in_functions();
+
+    ast.analyze(&mut symbol_table, &mut errors);
+
+    (ast, errors)
+}
+
+pub fn analyze(source: &str) -> Box<Node> {
+    let (ast, errors) = analyze_with_errors(source);
+    assert!(errors.is_empty(), "test source must analyze cleanly: {:?}", errors);
+
+    ast
+}
+
+pub fn analyze_files(sources: &[&str]) -> Vec<Box<Node>> {
+    let mut symbol_table = SymbolTable::new();
+    symbol_table.add_build_in_types();
+    symbol_table.add_build_in_functions();
+
+    let mut errors = vec![];
+    let mut asts: Vec<Box<Node>> = sources.iter().map(|source| {
+        let (tokens, lexer_errors) = Lexer::new(&source.to_string()).parse();
+        errors.extend(lexer_errors);
+
+        let (ast, parser_errors) = Parser::new(tokens).parse();
+        errors.extend(parser_errors);
+
+        ast
+    }).collect();
+
+    for ast in &mut asts {
+        if let Node::File(file_node) = ast.as_mut() {
+            file_node.declare_functions(&mut symbol_table, &mut errors);
+        }
+    }
+
+    for ast in &mut asts {
+        if let Node::File(file_node) = ast.as_mut() {
+            file_node.analyze_functions(&mut symbol_table, &mut errors);
+        }
+    }
+
+    assert!(errors.is_empty(), "test sources must analyze cleanly: {:?}", errors);
+
+    asts
+}
+
+/// A directory under the system temp directory that is removed again when dropped, also when a test panics.
+pub struct TemporaryDirectory {
+    pub path: PathBuf,
+}
+
+impl TemporaryDirectory {
+    pub fn new(name: &str) -> TemporaryDirectory {
+        let index = NEXT_DIRECTORY.fetch_add(1, Ordering::Relaxed);
+        let path = env::temp_dir().join(format!("axiom-{}-{}-{}", name, process::id(), index));
+
+        fs::create_dir_all(&path).unwrap();
+
+        TemporaryDirectory {
+            path,
+        }
+    }
+
+    pub fn join(&self, path: &str) -> PathBuf {
+        self.path.join(path)
+    }
+}
+
+impl Drop for TemporaryDirectory {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
+
+pub fn render(node: &Node) -> String {
+    let mut output = String::new();
+    render_statement(node, 0, &mut output);
+
+    output
+}
+
+fn render_statement(node: &Node, indent: usize, output: &mut String) {
+    let padding = "    ".repeat(indent);
+
+    match node {
+        Node::File(file_node) => {
+            for function in &file_node.functions {
+                render_statement(&Node::Function((**function).clone()), indent, output);
+            }
+        }
+        Node::Function(function_node) => {
+            let parameters: Vec<&str> = function_node.parameters.iter().map(|parameter| parameter.identifier_node.identifier_token.name.as_str()).collect();
+
+            output.push_str(&format!("{}function {}({}) ", padding, function_node.identifier_node.identifier_token.name, parameters.join(", ")));
+            render_scope(&function_node.scope, indent, output);
+        }
+        Node::Scope(scope_node) => {
+            output.push_str(&padding);
+            render_scope(scope_node, indent, output);
+        }
+        Node::IfElse(if_else_node) => {
+            output.push_str(&format!("{}if {} ", padding, render_expression(&if_else_node.condition)));
+            render_scope(&if_else_node.consequent, indent, output);
+
+            for (condition, consequent) in &if_else_node.conditional_alternatives {
+                output.push_str(&format!("{}else if {} ", padding, render_expression(condition)));
+                render_scope(consequent, indent, output);
+            }
+
+            if let Some(alternative) = &if_else_node.alternative {
+                output.push_str(&format!("{}else ", padding));
+                render_scope(alternative, indent, output);
+            }
+        }
+        Node::Return(return_node) => output.push_str(&format!("{}return {}\n", padding, render_expression(&return_node.expression))),
+        Node::Declaration(declaration_node) => output.push_str(&format!("{}let {} = {}\n", padding, declaration_node.identifier_node.identifier_token.name, render_expression(&declaration_node.expression))),
+        Node::Destructuring(destructuring_node) => {
+            let names: Vec<&str> = destructuring_node.identifier_nodes.iter().map(|identifier_node| identifier_node.identifier_token.name.as_str()).collect();
+
+            output.push_str(&format!("{}let ({}) = {}\n", padding, names.join(", "), render_expression(&destructuring_node.expression)));
+        }
+        Node::Assignment(assignment_node) => output.push_str(&format!("{}{} = {}\n", padding, assignment_node.identifier_node.identifier_token.name, render_expression(&assignment_node.expression))),
+        expression => output.push_str(&format!("{}{}\n", padding, render_expression(expression))),
+    }
+}
+
+fn render_scope(scope_node: &ScopeNode, indent: usize, output: &mut String) {
+    output.push_str("{\n");
+
+    for statement in &scope_node.statements {
+        render_statement(statement, indent + 1, output);
+    }
+
+    output.push_str(&format!("{}}}\n", "    ".repeat(indent)));
+}
+
+fn render_expression(node: &Node) -> String {
+    match node {
+        Node::Number(number_node) => number_node.number_token.value.clone(),
+        Node::Char(char_node) => format!("{:?}", char_node.value),
+        Node::String(string_node) => format!("{:?}", string_node.value),
+        Node::Identifier(identifier_node) => identifier_node.identifier_token.name.clone(),
+        Node::Call(call_node) => {
+            let parameters: Vec<String> = call_node.parameters.iter().map(|parameter| render_expression(parameter)).collect();
+
+            format!("{}({})", call_node.identifier_node.identifier_token.name, parameters.join(", "))
+        }
+        Node::Binary(binary_node) => format!("({} {} {})", render_expression(&binary_node.left), binary_operator(&binary_node.operation_type), render_expression(&binary_node.right)),
+        Node::Unary(unary_node) => match unary_node.operation_type {
+            UnaryType::PreIncrement => format!("++{}", render_expression(&unary_node.expression)),
+            UnaryType::PreDecrement => format!("--{}", render_expression(&unary_node.expression)),
+            UnaryType::PostIncrement => format!("{}++", render_expression(&unary_node.expression)),
+            UnaryType::PostDecrement => format!("{}--", render_expression(&unary_node.expression)),
+            UnaryType::Minus => format!("-{}", render_expression(&unary_node.expression)),
+            UnaryType::Absolute => format!("+{}", render_expression(&unary_node.expression)),
+            UnaryType::Not => format!("!{}", render_expression(&unary_node.expression)),
+            UnaryType::BitwiseNot => format!("~{}", render_expression(&unary_node.expression)),
+        },
+        Node::Ternary(ternary_node) => format!("({} ? {} : {})", render_expression(&ternary_node.condition), render_expression(&ternary_node.consequent), render_expression(&ternary_node.alternative)),
+        Node::Error(_) => "<error>".to_string(),
+        node => format!("<{:?}>", std::mem::discriminant(node)),
+    }
+}
+
+fn binary_operator(operation_type: &BinaryType) -> &'static str {
+    match operation_type {
+        BinaryType::Addition => "+",
+        BinaryType::Subtraction => "-",
+        BinaryType::Multiplication => "*",
+        BinaryType::Division => "/",
+        BinaryType::Modulo => "%",
+        BinaryType::Exponentiation => "**",
+        BinaryType::Equal => "==",
+        BinaryType::NotEqual => "!=",
+        BinaryType::GreaterThan => ">",
+        BinaryType::LessThan => "<",
+        BinaryType::GreaterThanOrEqual => ">=",
+        BinaryType::LessThanOrEqual => "<=",
+        BinaryType::ShiftLeft => "<<",
+        BinaryType::ShiftRight => ">>",
+        BinaryType::BitwiseOr => "|",
+        BinaryType::BitwiseAnd => "&",
+        BinaryType::BitwiseXor => "^",
+        BinaryType::Or => "||",
+        BinaryType::And => "&&",
+    }
+}
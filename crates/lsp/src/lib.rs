@@ -3,9 +3,9 @@ use std::error::Error;
 use std::io::Write;
 use std::net::SocketAddr;
 use lsp_server::{Connection, Message, Notification, Request as ServerRequest, RequestId, Response};
-use lsp_types::{DidChangeTextDocumentParams, DidOpenTextDocumentParams, Hover, HoverContents, HoverParams, HoverProviderCapability, InitializeParams, MarkedString, ServerCapabilities, TextDocumentSyncCapability, TextDocumentSyncKind, Uri};
+use lsp_types::{DidChangeTextDocumentParams, DidOpenTextDocumentParams, DocumentFormattingParams, Hover, HoverContents, HoverParams, HoverProviderCapability, InitializeParams, MarkedString, OneOf, ServerCapabilities, TextDocumentSyncCapability, TextDocumentSyncKind, TextEdit, Uri};
 use lsp_types::notification::{DidChangeTextDocument, DidOpenTextDocument, Notification as _};
-use lsp_types::request::{HoverRequest, Request};
+use lsp_types::request::{Formatting, HoverRequest, Request};
 
 fn log(message: &str) -> Result<(), Box<dyn Error>> {
     std::io::stderr().write_all((message.to_owned() + "\n").as_bytes())?;
@@ -21,6 +21,7 @@ pub fn start() -> Result<(), Box<dyn Error>> {
     let capabilities = ServerCapabilities {
         text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
         hover_provider: Some(HoverProviderCapability::Simple(true)),
+        document_formatting_provider: Some(OneOf::Left(true)),
         ..Default::default()
     };
 
@@ -76,12 +77,13 @@ fn handle_request(connection: &Connection, req: &ServerRequest, files: &mut Hash
 
             let file_content = files.get(&uri).ok_or(anyhow!("File not found!"))?;
 
-            let tokens = Lexer::new(file_content).parse().unwrap();
+            let (tokens, _) = Lexer::new(file_content).parse();
 
-            let mut ast = Parser::new(tokens).parse()?;
+            let (mut ast, _) = Parser::new(tokens).parse();
 
             let mut symbol_table = SymbolTable::new();
             symbol_table.add_build_in_types();
+            symbol_table.add_build_in_functions();
 
             let mut errors = vec![];
 
@@ -101,6 +103,24 @@ fn handle_request(connection: &Connection, req: &ServerRequest, files: &mut Hash
                 send_ok(connection, req.id.clone(), &None::<String>)?;
             }
         }
+        Formatting::METHOD => {
+            log("[Axiom LSP] - Formatting")?;
+            let params: DocumentFormattingParams = serde_json::from_value(req.params.clone())?;
+
+            let file_content = files.get(&params.text_document.uri).ok_or(anyhow!("File not found!"))?;
+
+            let format_options = FormatOptions {
+                indent_width: params.options.tab_size as usize,
+                hard_tabs: !params.options.insert_spaces,
+                ..Default::default()
+            };
+
+            match format(file_content, &format_options) {
+                Ok(formatted) if formatted == *file_content => send_ok(connection, req.id.clone(), &Vec::<TextEdit>::new())?,
+                Ok(formatted) => send_ok(connection, req.id.clone(), &vec![TextEdit::new(full_range(file_content), formatted)])?,
+                Err(_) => send_ok(connection, req.id.clone(), &None::<Vec<TextEdit>>)?,
+            }
+        }
         _ => {}
     }
 
@@ -142,7 +162,10 @@ fn handle_notification(connection: &Connection, note: &Notification, files: &mut
 use anyhow::{Result, anyhow};
 use lsp_types::{
     Diagnostic,
+    DiagnosticRelatedInformation,
     DiagnosticSeverity,
+    Location,
+    NumberOrString,
     Position,
     PublishDiagnosticsParams,
     Range,
@@ -153,6 +176,9 @@ use compiler::parser::Parser;
 use compiler::utils::SymbolTable;
 use compiler::analyzer::Analyzer;
 use compiler::error::AxiomError;
+use compiler::error::diagnostic::{Diagnostic as AxiomDiagnostic, Severity};
+use compiler::formatter::{format, FormatOptions};
+use compiler::lint::LintRegistry;
 
 // for METHOD consts
 //
@@ -226,33 +252,34 @@ use compiler::error::AxiomError;
 // // diagnostics
 // // =====================================================================
 
-fn analyze_for_errors(content: &String) -> Vec<AxiomError> {
-    let tokens = Lexer::new(content).parse().unwrap();
+fn analyze_for_errors(content: &String) -> (Vec<AxiomError>, Vec<AxiomDiagnostic>) {
+    let (tokens, mut errors) = Lexer::new(content).parse();
 
-    let mut ast = match Parser::new(tokens).parse() {
-        Ok(ast) => ast,
-        Err(error) => return vec![error]
-    };
+    let (mut ast, parser_errors) = Parser::new(tokens).parse();
+    errors.extend(parser_errors);
 
     let mut symbol_table = SymbolTable::new();
     symbol_table.add_build_in_types();
-
-    let mut errors = vec![];
+    symbol_table.add_build_in_functions();
 
     ast.analyze(&mut symbol_table, &mut errors);
-    errors
+    if !errors.is_empty() {
+        return (errors, vec![]);
+    }
+
+    (errors, LintRegistry::new().check(&ast))
 }
 
 fn send_diagnostic(conn: &Connection, uri: &Uri, files: &mut HashMap<Uri, String>) -> Result<()> {
     let file_content = files.get(uri).ok_or(anyhow!("File not found!"))?;
-    let errors = analyze_for_errors(file_content);
+    let (errors, lints) = analyze_for_errors(file_content);
 
-    let diagnostics: Vec<Diagnostic> = errors.iter().map(|error| {
+    let mut diagnostics: Vec<Diagnostic> = errors.iter().map(|error| {
         match error {
             AxiomError::UnexpectedEOF(position) => Diagnostic {
                 range: Range::new(Position::new(position.line as u32, position.column as u32), Position::new(position.line as u32, position.column as u32 + 1)),
                 severity: Some(DiagnosticSeverity::ERROR),
-                code: None,
+                code: Some(NumberOrString::String(error.code().to_string())),
                 code_description: None,
                 source: Some("Axiom".into()),
                 message: "Unexpected EOF".into(),
@@ -263,7 +290,7 @@ fn send_diagnostic(conn: &Connection, uri: &Uri, files: &mut HashMap<Uri, String
             AxiomError::SyntaxError(location, message) => Diagnostic {
                 range: location.clone().into(),
                 severity: Some(DiagnosticSeverity::ERROR),
-                code: None,
+                code: Some(NumberOrString::String(error.code().to_string())),
                 code_description: None,
                 source: Some("Axiom".into()),
                 message: format!("SyntaxError: {}", message),
@@ -271,21 +298,24 @@ fn send_diagnostic(conn: &Connection, uri: &Uri, files: &mut HashMap<Uri, String
                 tags: None,
                 data: None,
             },
-            AxiomError::DuplicatedIdentifier(location, identifier) => Diagnostic {
+            AxiomError::DuplicatedIdentifier(location, identifier, previous_location) => Diagnostic {
                 range: location.clone().into(),
                 severity: Some(DiagnosticSeverity::ERROR),
-                code: None,
+                code: Some(NumberOrString::String(error.code().to_string())),
                 code_description: None,
                 source: Some("Axiom".into()),
                 message: format!("Duplicated identifier: {}", identifier),
-                related_information: None,
+                related_information: previous_location.clone().map(|previous_location| vec![DiagnosticRelatedInformation {
+                    location: Location::new(uri.clone(), previous_location.into()),
+                    message: "first declared here".into(),
+                }]),
                 tags: None,
                 data: None,
             },
             AxiomError::IdentifierUsedBeforeDeclaration(location, identifier) => Diagnostic {
                 range: location.clone().into(),
                 severity: Some(DiagnosticSeverity::ERROR),
-                code: None,
+                code: Some(NumberOrString::String(error.code().to_string())),
                 code_description: None,
                 source: Some("Axiom".into()),
                 message: format!("Identifier used before declaration: {}", identifier),
@@ -296,7 +326,7 @@ fn send_diagnostic(conn: &Connection, uri: &Uri, files: &mut HashMap<Uri, String
             AxiomError::WrongDataType(location, expected, received) => Diagnostic {
                 range: location.clone().into(),
                 severity: Some(DiagnosticSeverity::ERROR),
-                code: None,
+                code: Some(NumberOrString::String(error.code().to_string())),
                 code_description: None,
                 source: Some("Axiom".into()),
                 message: format!("Expected DataType: {}, but found: {}", expected, received),
@@ -307,7 +337,7 @@ fn send_diagnostic(conn: &Connection, uri: &Uri, files: &mut HashMap<Uri, String
             AxiomError::NotAFunction(location, identifier) => Diagnostic {
                 range: location.clone().into(),
                 severity: Some(DiagnosticSeverity::ERROR),
-                code: None,
+                code: Some(NumberOrString::String(error.code().to_string())),
                 code_description: None,
                 source: Some("Axiom".into()),
                 message: format!("{} is not a function", identifier),
@@ -318,7 +348,7 @@ fn send_diagnostic(conn: &Connection, uri: &Uri, files: &mut HashMap<Uri, String
             AxiomError::MismatchedNumberOfParameters(location, identifier, function_parameter_count, call_parameter_count) => Diagnostic {
                 range: location.clone().into(),
                 severity: Some(DiagnosticSeverity::ERROR),
-                code: None,
+                code: Some(NumberOrString::String(error.code().to_string())),
                 code_description: None,
                 source: Some("Axiom".into()),
                 message: format!("Mismatched number of parameters, function {} takes {} parameters, but given {}", identifier, function_parameter_count, call_parameter_count),
@@ -329,7 +359,7 @@ fn send_diagnostic(conn: &Connection, uri: &Uri, files: &mut HashMap<Uri, String
             AxiomError::NotAType(location, identifier) => Diagnostic {
                 range: location.clone().into(),
                 severity: Some(DiagnosticSeverity::ERROR),
-                code: None,
+                code: Some(NumberOrString::String(error.code().to_string())),
                 code_description: None,
                 source: Some("Axiom".into()),
                 message: format!("{} is not a type", identifier),
@@ -337,9 +367,127 @@ fn send_diagnostic(conn: &Connection, uri: &Uri, files: &mut HashMap<Uri, String
                 tags: None,
                 data: None,
             },
+            AxiomError::LiteralOutOfRange(location, literal, data_type) => Diagnostic {
+                range: location.clone().into(),
+                severity: Some(DiagnosticSeverity::ERROR),
+                code: Some(NumberOrString::String(error.code().to_string())),
+                code_description: None,
+                source: Some("Axiom".into()),
+                message: format!("Literal {} is out of range for {}", literal, data_type),
+                related_information: None,
+                tags: None,
+                data: None,
+            },
+            AxiomError::UnexpectedCharacter(location, char) => Diagnostic {
+                range: location.clone().into(),
+                severity: Some(DiagnosticSeverity::ERROR),
+                code: Some(NumberOrString::String(error.code().to_string())),
+                code_description: None,
+                source: Some("Axiom".into()),
+                message: format!("Unexpected character {:?}", char),
+                related_information: None,
+                tags: None,
+                data: None,
+            },
+            AxiomError::UnterminatedLiteral(location, literal_kind) => Diagnostic {
+                range: location.clone().into(),
+                severity: Some(DiagnosticSeverity::ERROR),
+                code: Some(NumberOrString::String(error.code().to_string())),
+                code_description: None,
+                source: Some("Axiom".into()),
+                message: format!("Unterminated {} literal", literal_kind),
+                related_information: None,
+                tags: None,
+                data: None,
+            },
+            AxiomError::UnknownAttribute(location, attribute) => Diagnostic {
+                range: location.clone().into(),
+                severity: Some(DiagnosticSeverity::ERROR),
+                code: Some(NumberOrString::String(error.code().to_string())),
+                code_description: None,
+                source: Some("Axiom".into()),
+                message: format!("Unknown attribute {}", attribute),
+                related_information: None,
+                tags: None,
+                data: None,
+            },
+            AxiomError::UnknownLint(location, lint) => Diagnostic {
+                range: location.clone().into(),
+                severity: Some(DiagnosticSeverity::ERROR),
+                code: Some(NumberOrString::String(error.code().to_string())),
+                code_description: None,
+                source: Some("Axiom".into()),
+                message: format!("Unknown lint {}", lint),
+                related_information: None,
+                tags: None,
+                data: None,
+            },
+            AxiomError::InvalidMainSignature(location) => Diagnostic {
+                range: location.clone().into(),
+                severity: Some(DiagnosticSeverity::ERROR),
+                code: Some(NumberOrString::String(error.code().to_string())),
+                code_description: None,
+                source: Some("Axiom".into()),
+                message: "Invalid signature for main".to_string(),
+                related_information: None,
+                tags: None,
+                data: None,
+            },
+            AxiomError::InvalidTestFunction(location) => Diagnostic {
+                range: location.clone().into(),
+                severity: Some(DiagnosticSeverity::ERROR),
+                code: Some(NumberOrString::String(error.code().to_string())),
+                code_description: None,
+                source: Some("Axiom".into()),
+                message: "Invalid signature for test function".to_string(),
+                related_information: None,
+                tags: None,
+                data: None,
+            },
+            AxiomError::InvalidDestructuring(location, data_type, binding_count) => Diagnostic {
+                range: location.clone().into(),
+                severity: Some(DiagnosticSeverity::ERROR),
+                code: Some(NumberOrString::String(error.code().to_string())),
+                code_description: None,
+                source: Some("Axiom".into()),
+                message: format!("Cannot bind a value of type {} to {} name{}", data_type, binding_count, if *binding_count == 1 { "" } else { "s" }),
+                related_information: None,
+                tags: None,
+                data: None,
+            },
+            AxiomError::MalformedNumberLiteral(location, literal, reason) => Diagnostic {
+                range: location.clone().into(),
+                severity: Some(DiagnosticSeverity::ERROR),
+                code: Some(NumberOrString::String(error.code().to_string())),
+                code_description: None,
+                source: Some("Axiom".into()),
+                message: format!("Malformed number literal {}: {}", literal, reason),
+                related_information: None,
+                tags: None,
+                data: None,
+            },
         }
     }).collect();
 
+    diagnostics.extend(lints.iter().map(|lint| Diagnostic {
+        range: lint.location.clone().into(),
+        severity: Some(match lint.severity {
+            Severity::Error => DiagnosticSeverity::ERROR,
+            Severity::Warning => DiagnosticSeverity::WARNING,
+            Severity::Note => DiagnosticSeverity::INFORMATION,
+        }),
+        code: lint.lint.map(|lint| NumberOrString::String(lint.to_string())),
+        code_description: None,
+        source: Some("Axiom".into()),
+        message: lint.message.clone(),
+        related_information: Some(lint.secondary_labels.iter().map(|label| DiagnosticRelatedInformation {
+            location: Location::new(uri.clone(), label.location.clone().into()),
+            message: label.message.clone(),
+        }).collect()),
+        tags: None,
+        data: None,
+    }));
+
     log(format!("[Axiom LSP] - Diagnostics: {:?}", diagnostics).as_str()).unwrap();
 
     let params =
@@ -355,11 +503,12 @@ fn send_diagnostic(conn: &Connection, uri: &Uri, files: &mut HashMap<Uri, String
 // // helpers
 // // =====================================================================
 //
-// fn full_range(text: &str) -> Range {
-//     let last_line_idx = text.lines().count().saturating_sub(1) as u32;
-//     let last_col = text.lines().last().map_or(0, |l| l.chars().count()) as u32;
-//     Range::new(Position::new(0, 0), Position::new(last_line_idx, last_col))
-// }
+fn full_range(text: &str) -> Range {
+    let last_line = text.rsplit('\n').next().unwrap_or_default();
+    let end = Position::new(text.matches('\n').count() as u32, last_line.encode_utf16().count() as u32);
+
+    Range::new(Position::new(0, 0), end)
+}
 //
 fn send_ok<T: serde::Serialize>(conn: &Connection, id: RequestId, result: &T) -> Result<()> {
     let resp = Response { id, result: Some(serde_json::to_value(result)?), error: None };
@@ -395,4 +544,4 @@ fn send_ok<T: serde::Serialize>(conn: &Connection, id: RequestId, result: &T) ->
 //         let result = add(2, 2);
 //         assert_eq!(result, 4);
 //     }
-// }
+// }
\ No newline at end of file